@@ -85,10 +85,144 @@ fn tilted_grid_benchmark(instance: &mut Criterion) {
     group.finish();
 }
 
+fn repeated_drawing_benchmark(instance: &mut Criterion) {
+    let mut group = instance.benchmark_group("repeated_drawing");
+    group
+        .sample_size(40)
+        .measurement_time(Duration::from_secs(30));
+    for corners_count in [6, 12, 24] {
+        let mosaic = MosaicBuilder::default()
+            .set_shape(PolygonalStar::new(corners_count))
+            .set_image_size(1600, 1600)
+            .set_center(Vector::new(800.0, 800.0))
+            .set_uniform_scale(0.75)
+            .build_star()
+            .unwrap();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(corners_count),
+            &mosaic,
+            |bencher, mosaic| {
+                bencher.iter(|| mosaic.draw(LinSrgb::new(0.0f64, 0.25, 1.0)));
+            },
+        );
+    }
+    group.finish();
+}
+fn repeated_building_benchmark(instance: &mut Criterion) {
+    let builder = MosaicBuilder::default()
+        .set_shape(Grid::new(48, 48))
+        .set_image_size(1600, 1600)
+        .set_center(Vector::new(800.0, 800.0))
+        .set_uniform_scale(0.75);
+    let mut group = instance.benchmark_group("repeated_building");
+    group
+        .sample_size(40)
+        .measurement_time(Duration::from_secs(30));
+    group.bench_function("star", |bencher| {
+        bencher.iter(|| builder.clone().build_star().unwrap());
+    });
+    group.finish();
+}
+fn parallel_drawing_benchmark(instance: &mut Criterion) {
+    let mosaic = MosaicBuilder::default()
+        .set_shape(Grid::new(48, 48))
+        .set_image_size(1600, 1600)
+        .set_center(Vector::new(800.0, 800.0))
+        .set_uniform_scale(0.75)
+        .build_polygon()
+        .unwrap();
+    let color = LinSrgb::new(0.0f64, 0.25, 1.0);
+    let mut group = instance.benchmark_group("parallel_drawing");
+    group
+        .sample_size(40)
+        .measurement_time(Duration::from_secs(30));
+    group.bench_function("serial", |bencher| {
+        bencher.iter(|| mosaic.draw(color));
+    });
+    group.bench_function("parallel", |bencher| {
+        bencher.iter(|| mosaic.draw_parallel(color));
+    });
+    group.finish();
+}
+fn step_gradient_benchmark(instance: &mut Criterion) {
+    let mosaic = MosaicBuilder::default()
+        .set_shape(PolygonalStar::new(12))
+        .set_image_size(1600, 1600)
+        .set_center(Vector::new(800.0, 800.0))
+        .set_uniform_scale(0.75)
+        .build_star()
+        .unwrap();
+    let gradient = vec![
+        (0.0, LinSrgb::new(1.0f64, 0.0, 0.0)),
+        (0.5, LinSrgb::new(0.0f64, 1.0, 0.0)),
+        (1.0, LinSrgb::new(0.0f64, 0.0, 1.0)),
+    ];
+    let mut group = instance.benchmark_group("step_gradient");
+    group
+        .sample_size(40)
+        .measurement_time(Duration::from_secs(30));
+    group.bench_function("step", |bencher| {
+        bencher.iter(|| {
+            mosaic.draw(RadialGradient::new_simple_step(
+                gradient.clone(),
+                Vector::new(800.0, 800.0),
+                720.0,
+            ))
+        });
+    });
+    group.bench_function("smooth", |bencher| {
+        bencher.iter(|| {
+            mosaic.draw(RadialGradient::new_simple_smooth(
+                gradient.clone(),
+                Vector::new(800.0, 800.0),
+                720.0,
+            ))
+        });
+    });
+    group.finish();
+}
+fn recoloring_benchmark(instance: &mut Criterion) {
+    let mosaic = MosaicBuilder::default()
+        .set_shape(PolygonalStar::new(12))
+        .set_image_size(1600, 1600)
+        .set_center(Vector::new(800.0, 800.0))
+        .set_uniform_scale(0.75)
+        .build_star()
+        .unwrap();
+    let mut group = instance.benchmark_group("recoloring");
+    group
+        .sample_size(20)
+        .measurement_time(Duration::from_secs(60));
+    group.bench_function("starry_mosaic", |bencher| {
+        bencher.iter(|| {
+            for index in 0..10 {
+                let shift = index as f64 / 10.0;
+                let gradient = vec![
+                    (0.0, LinSrgb::new(1.0f64, shift, 0.0)),
+                    (0.5, LinSrgb::new(0.0f64, 1.0, shift)),
+                    (1.0, LinSrgb::new(shift, 0.0f64, 1.0)),
+                ];
+                mosaic.recolor(RadialGradient::new_simple(
+                    gradient,
+                    Vector::new(800.0, 800.0),
+                    720.0,
+                    0.25,
+                ));
+            }
+        });
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     regular_polygon_benchmark,
     polygonal_star_benchmark,
-    tilted_grid_benchmark
+    tilted_grid_benchmark,
+    repeated_drawing_benchmark,
+    repeated_building_benchmark,
+    parallel_drawing_benchmark,
+    step_gradient_benchmark,
+    recoloring_benchmark
 );
 criterion_main!(benches);
@@ -0,0 +1,161 @@
+use super::vector::Vector;
+
+/// Represents 2D axis-aligned bounding box.
+///
+/// # Examples
+///
+/// ```
+/// use starry_mosaic::{Aabb, Vector};
+///
+/// let points = [
+///     Vector::new(1.0, 4.0),
+///     Vector::new(-3.0, 2.0),
+///     Vector::new(5.0, -6.0),
+/// ];
+/// let bounding_box = Aabb::from_points(&points).unwrap();
+///
+/// assert_eq!(bounding_box.min, Vector::new(-3.0, -6.0));
+/// assert_eq!(bounding_box.max, Vector::new(5.0, 4.0));
+/// assert_eq!(bounding_box.center(), Vector::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Aabb {
+    /// Corner of bounding box with minimal coordinates.
+    pub min: Vector,
+
+    /// Corner of bounding box with maximal coordinates.
+    pub max: Vector,
+}
+
+impl Aabb {
+    /// Builds bounding box from its corners.
+    ///
+    /// Note that order of corners does *__not__* matter.
+    pub fn new(first_corner: Vector, second_corner: Vector) -> Self {
+        Self {
+            min: first_corner.min(second_corner),
+            max: first_corner.max(second_corner),
+        }
+    }
+
+    /// Builds smallest bounding box containing all specified points.
+    ///
+    /// # Arguments
+    ///
+    /// * `points`: points to be contained in bounding box.
+    ///
+    /// returns: `Option<Aabb>` - bounding box of points or `None` if there are no points.
+    ///
+    pub fn from_points(points: &[Vector]) -> Option<Self> {
+        let (first_point, points) = points.split_first()?;
+        Some(points.iter().fold(
+            Self::new(*first_point, *first_point),
+            |bounding_box, point| Self {
+                min: bounding_box.min.min(*point),
+                max: bounding_box.max.max(*point),
+            },
+        ))
+    }
+
+    /// Checks whether point lies inside bounding box or on its boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{Aabb, Vector};
+    ///
+    /// let bounding_box = Aabb::new(Vector::new(0.0, 0.0), Vector::new(4.0, 2.0));
+    ///
+    /// assert!(bounding_box.contains(Vector::new(1.0, 1.0)));
+    /// assert!(bounding_box.contains(Vector::new(4.0, 0.0)));
+    /// assert!(!bounding_box.contains(Vector::new(5.0, 1.0)));
+    /// ```
+    pub fn contains(&self, point: Vector) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+
+    /// Creates bounding box enlarged by margin on every side.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin`: distance by which every side of bounding box is moved outward;
+    ///   negative margin shrinks bounding box (but not below its center).
+    ///
+    /// returns: `Aabb` - expanded bounding box.
+    ///
+    pub fn expand(&self, margin: f64) -> Self {
+        let center = self.center();
+        Self {
+            min: Vector::new(
+                (self.min.x - margin).min(center.x),
+                (self.min.y - margin).min(center.y),
+            ),
+            max: Vector::new(
+                (self.max.x + margin).max(center.x),
+                (self.max.y + margin).max(center.y),
+            ),
+        }
+    }
+
+    /// Calculates center of bounding box.
+    #[inline(always)]
+    pub fn center(&self) -> Vector {
+        self.min.interpolate(self.max, 0.5)
+    }
+
+    /// Calculates width of bounding box.
+    #[inline(always)]
+    pub fn width(&self) -> f64 {
+        self.max.x - self.min.x
+    }
+
+    /// Calculates height of bounding box.
+    #[inline(always)]
+    pub fn height(&self) -> f64 {
+        self.max.y - self.min.y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_points() {
+        let points = [
+            Vector::new(3.0, 7.5),
+            Vector::new(-2.5, 1.0),
+            Vector::new(8.0, -4.0),
+            Vector::new(0.0, 0.0),
+            Vector::new(5.5, 9.0),
+        ];
+        let bounding_box = Aabb::from_points(&points).unwrap();
+        assert_eq!(bounding_box.min, Vector::new(-2.5, -4.0));
+        assert_eq!(bounding_box.max, Vector::new(8.0, 9.0));
+        assert_eq!(bounding_box.center(), Vector::new(2.75, 2.5));
+        assert_eq!(bounding_box.width(), 10.5);
+        assert_eq!(bounding_box.height(), 13.0);
+        assert!(points.iter().all(|point| bounding_box.contains(*point)));
+        assert!(!bounding_box.contains(Vector::new(8.5, 0.0)));
+        assert!(!bounding_box.contains(Vector::new(0.0, -4.5)));
+    }
+    #[test]
+    fn from_no_points() {
+        assert_eq!(Aabb::from_points(&[]), None);
+    }
+    #[test]
+    fn expand() {
+        let bounding_box = Aabb::new(Vector::new(4.0, 2.0), Vector::new(-4.0, -2.0));
+        let expanded_box = bounding_box.expand(1.5);
+        assert_eq!(expanded_box.min, Vector::new(-5.5, -3.5));
+        assert_eq!(expanded_box.max, Vector::new(5.5, 3.5));
+        assert!(expanded_box.contains(Vector::new(5.0, 3.0)));
+        let shrunk_box = bounding_box.expand(-3.0);
+        assert_eq!(shrunk_box.min, Vector::new(-1.0, 0.0));
+        assert_eq!(shrunk_box.max, Vector::new(1.0, 0.0));
+        assert_eq!(shrunk_box.center(), bounding_box.center());
+    }
+}
@@ -0,0 +1,142 @@
+use palette::Mix;
+
+use super::{ColoringMethod, Vector};
+
+/// Blends (crossfades) colors of two coloring methods with constant factor.
+///
+/// Both coloring methods should generate colors of the same type.
+#[derive(Clone, Debug)]
+pub struct Blend<First, Second> {
+    first: First,
+    second: Second,
+    factor: f64,
+}
+
+impl<First, Second> Blend<First, Second> {
+    /// Creates blend of two coloring methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `first`: coloring method which colors are used when `factor` is 0.0.
+    /// * `second`: coloring method which colors are used when `factor` is 1.0.
+    /// * `factor`: factor of blending ranging from 0.0 to 1.0.
+    ///
+    /// returns: [`Blend<First, Second>`] - blend of two coloring methods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{coloring_method::{Blend, ColoringMethod}, Vector};
+    ///
+    /// let blend = Blend::new(
+    ///     LinSrgb::new(1.0f64, 0.0, 0.0),
+    ///     LinSrgb::new(0.0f64, 0.0, 1.0),
+    ///     0.25,
+    /// );
+    ///
+    /// let point = Vector::new(100.0, 100.0);
+    /// assert_eq!(blend.interpolate(point, point), LinSrgb::new(0.75f64, 0.0, 0.25));
+    /// ```
+    pub fn new(first: First, second: Second, factor: f64) -> Self {
+        Self {
+            first,
+            second,
+            factor: factor.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Coloring method which colors are used when factor of blending is 0.0.
+    pub fn first(&self) -> &First {
+        &self.first
+    }
+
+    /// Coloring method which colors are used when factor of blending is 1.0.
+    pub fn second(&self) -> &Second {
+        &self.second
+    }
+
+    /// Factor of blending ranging from 0.0 to 1.0.
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Sets factor of blending (ranging from 0.0 to 1.0).
+    pub fn set_factor(&mut self, factor: f64) {
+        self.factor = factor.clamp(0.0, 1.0);
+    }
+}
+
+impl<Color, First, Second> ColoringMethod<Color> for Blend<First, Second>
+where
+    Color: Mix<Scalar = f64> + Clone,
+    First: ColoringMethod<Color>,
+    Second: ColoringMethod<Color>,
+{
+    fn interpolate(&self, point: Vector, key_point: Vector) -> Color {
+        self.first
+            .interpolate(point, key_point)
+            .mix(&self.second.interpolate(point, key_point), self.factor)
+    }
+
+    fn interpolate_indexed(&self, point: Vector, key_point: Vector, cell_index: usize) -> Color {
+        self.first
+            .interpolate_indexed(point, key_point, cell_index)
+            .mix(
+                &self
+                    .second
+                    .interpolate_indexed(point, key_point, cell_index),
+                self.factor,
+            )
+    }
+
+    #[inline(always)]
+    fn is_uniform_per_cell(&self) -> bool {
+        self.first.is_uniform_per_cell() && self.second.is_uniform_per_cell()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use palette::LinSrgb;
+
+    use super::{
+        super::{tests, ConicGradient, RadialGradient},
+        *,
+    };
+
+    #[test]
+    fn set_factor() {
+        let mut blend = Blend::new(
+            LinSrgb::new(1.0f64, 0.0, 0.0),
+            LinSrgb::new(0.0f64, 0.0, 1.0),
+            0.5,
+        );
+        blend.set_factor(1.5);
+        assert_eq!(blend.factor(), 1.0);
+        blend.set_factor(-0.5);
+        assert_eq!(blend.factor(), 0.0);
+    }
+    #[test]
+    fn interpolate() {
+        let radial_gradient = RadialGradient::new_simple_smooth(
+            tests::create_rgb_gradient(),
+            Vector::new(100.0, 100.0),
+            100.0,
+        );
+        let conic_gradient =
+            ConicGradient::new_smooth(tests::create_rgb_gradient(), Vector::new(100.0, 100.0), 0.0);
+        let (point, key_point) = (Vector::new(130.0, 60.0), Vector::new(110.0, 90.0));
+        let radial_color = radial_gradient.interpolate(point, key_point);
+        let conic_color = conic_gradient.interpolate(point, key_point);
+        let mut blend = Blend::new(radial_gradient, conic_gradient, 0.0);
+        assert_eq!(blend.interpolate(point, key_point), radial_color);
+        blend.set_factor(1.0);
+        assert_eq!(blend.interpolate(point, key_point), conic_color);
+        blend.set_factor(0.5);
+        assert_eq!(
+            blend.interpolate(point, key_point),
+            radial_color.mix(&conic_color, 0.5)
+        );
+    }
+}
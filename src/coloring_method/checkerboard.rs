@@ -0,0 +1,143 @@
+use palette::Mix;
+
+use super::{ColoringMethod, Vector};
+
+/// Defines checkerboard pattern for painting mosaic images.
+///
+/// Unlike gradients checkerboard ignores pattern of mosaic: color of every pixel depends only
+/// on square cell of checkerboard which contains it.
+#[derive(Clone, Debug)]
+pub struct Checkerboard<Color>
+where
+    Color: Mix<Scalar = f64> + Clone,
+{
+    primary: Color,
+    secondary: Color,
+    cell_size: f64,
+}
+
+impl<Color> Checkerboard<Color>
+where
+    Color: Mix<Scalar = f64> + Clone,
+{
+    /// Creates checkerboard pattern with given colors and size of its cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary`: color of cells with even sum of column and row indices
+    ///   (including cell at origin).
+    /// * `secondary`: color of cells with odd sum of column and row indices.
+    /// * `cell_size`: width and height of every cell of checkerboard, in pixels;
+    ///   should be positive.
+    ///
+    /// returns: Checkerboard<Color> - checkerboard pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{coloring_method::{Checkerboard, ColoringMethod}, Vector};
+    ///
+    /// let primary = LinSrgb::new(1.0f64, 1.0, 0.0);
+    /// let secondary = LinSrgb::new(0.0f64, 0.0, 1.0);
+    /// let checkerboard = Checkerboard::new(primary, secondary, 50.0);
+    ///
+    /// let key_point = Vector::new(100.0, 100.0);
+    /// assert_eq!(checkerboard.interpolate(Vector::new(20.0, 30.0), key_point), primary);
+    /// assert_eq!(checkerboard.interpolate(Vector::new(70.0, 30.0), key_point), secondary);
+    /// ```
+    pub fn new(primary: Color, secondary: Color, cell_size: f64) -> Self {
+        Self {
+            primary,
+            secondary,
+            cell_size: cell_size.max(f64::EPSILON),
+        }
+    }
+
+    /// Color of cells with even sum of column and row indices.
+    pub fn primary(&self) -> &Color {
+        &self.primary
+    }
+
+    /// Sets color of cells with even sum of column and row indices.
+    pub fn set_primary(&mut self, primary: Color) {
+        self.primary = primary;
+    }
+
+    /// Color of cells with odd sum of column and row indices.
+    pub fn secondary(&self) -> &Color {
+        &self.secondary
+    }
+
+    /// Sets color of cells with odd sum of column and row indices.
+    pub fn set_secondary(&mut self, secondary: Color) {
+        self.secondary = secondary;
+    }
+
+    /// Width and height of every cell of checkerboard, in pixels.
+    pub fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+
+    /// Sets width and height of every cell of checkerboard, in pixels.
+    pub fn set_cell_size(&mut self, cell_size: f64) {
+        self.cell_size = cell_size.max(f64::EPSILON);
+    }
+}
+
+impl<Color> ColoringMethod<Color> for Checkerboard<Color>
+where
+    Color: Mix<Scalar = f64> + Clone,
+{
+    fn interpolate(&self, point: Vector, _key_point: Vector) -> Color {
+        let column = (point.x / self.cell_size).floor();
+        let row = (point.y / self.cell_size).floor();
+        if (column + row).rem_euclid(2.0) < 1.0 {
+            self.primary.clone()
+        } else {
+            self.secondary.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use palette::LinSrgb;
+
+    use super::*;
+
+    #[test]
+    fn set_cell_size() {
+        let mut checkerboard = Checkerboard::new(
+            LinSrgb::new(1.0f64, 1.0, 1.0),
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            10.0,
+        );
+        checkerboard.set_cell_size(25.0);
+        assert_eq!(checkerboard.cell_size(), 25.0);
+        checkerboard.set_cell_size(-1.0);
+        assert!(checkerboard.cell_size() > 0.0);
+    }
+    #[test]
+    fn interpolate() {
+        let primary = LinSrgb::new(1.0f64, 1.0, 1.0);
+        let secondary = LinSrgb::new(0.0f64, 0.0, 0.0);
+        let checkerboard = Checkerboard::new(primary, secondary, 10.0);
+        let key_point = Vector::new(0.0, 0.0);
+        let expected_colors = [
+            ((0.0, 0.0), primary),
+            ((9.9, 9.9), primary),
+            ((10.0, 0.0), secondary),
+            ((5.0, 15.0), secondary),
+            ((15.0, 15.0), primary),
+            ((25.0, 5.0), primary),
+            ((-5.0, 5.0), secondary),
+            ((-5.0, -5.0), primary),
+            ((-15.0, 35.0), secondary),
+            ((-15.0, 45.0), primary),
+        ];
+        for (point, color) in expected_colors {
+            assert_eq!(checkerboard.interpolate(point.into(), key_point), color);
+        }
+    }
+}
@@ -14,6 +14,9 @@ where
     center: Vector,
     angle: f64,
     smoothness: f64,
+    easing: Option<fn(f64) -> f64>,
+    center_radius: f64,
+    center_color: Option<Color>,
 }
 
 impl<Color> ConicGradient<Color>
@@ -28,7 +31,7 @@ where
     /// * `center`: center point around which the gradient is drawn.
     /// * `angle`: angle at which to begin the gradient, in radians.
     /// * `smoothness`: smoothness of gradient ranging from 0.0 to 1.0;
-    /// see [`ConicGradient::smoothness`] for more information.
+    ///   see [`ConicGradient::smoothness`] for more information.
     ///
     /// returns: ConicGradient<Color> - conic gradient around center point.
     ///
@@ -75,6 +78,9 @@ where
             center,
             angle: angle % consts::TAU,
             smoothness: smoothness.clamp(0.0, 1.0),
+            easing: None,
+            center_radius: 0.0,
+            center_color: None,
         }
     }
 
@@ -246,6 +252,99 @@ where
     pub fn set_smoothness(&mut self, smoothness: f64) {
         self.smoothness = smoothness.clamp(0.0, 1.0);
     }
+
+    /// Sets easing function of conic gradient and returns this gradient.
+    ///
+    /// Easing function maps interpolation factor (clamped to range from 0.0 to 1.0) to position
+    /// in gradient, so ease-in or ease-out transitions of colors can be created.
+    ///
+    /// # Arguments
+    ///
+    /// * `easing`: easing function which should map range from 0.0 to 1.0 onto itself.
+    ///
+    /// returns: [`ConicGradient<Color>`] - conic gradient with given easing function.
+    ///
+    pub fn with_easing(mut self, easing: fn(f64) -> f64) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    /// Easing function of conic gradient, if any.
+    pub fn easing(&self) -> Option<fn(f64) -> f64> {
+        self.easing
+    }
+
+    /// Sets (or removes) easing function of conic gradient.
+    pub fn set_easing(&mut self, easing: Option<fn(f64) -> f64>) {
+        self.easing = easing;
+    }
+
+    /// Sets fade of conic gradient near its center and returns this gradient.
+    ///
+    /// Close to center of conic gradient tiny changes of position cause large changes of angle,
+    /// so colors of pixels there look noisy. Within `center_radius` of center gradient is
+    /// blended toward fixed color: center itself gets this color, and share of gradient grows
+    /// linearly up to `center_radius`.
+    ///
+    /// # Arguments
+    ///
+    /// * `center_radius`: radius of fade around center of gradient, in pixels;
+    ///   0.0 disables fade.
+    /// * `center_color`: color of center of gradient; if `None`, color at start of gradient
+    ///   is used.
+    ///
+    /// returns: [`ConicGradient<Color>`] - conic gradient with given fade near its center.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{coloring_method::{ColoringMethod, ConicGradient}, Vector};
+    ///
+    /// let gradient = vec![
+    ///     (0.0, LinSrgb::new(1.0f64, 0.0, 0.0)),
+    ///     (1.0, LinSrgb::new(0.0f64, 0.0, 1.0)),
+    /// ];
+    /// let center = Vector::new(100.0, 100.0);
+    /// let conic_gradient = ConicGradient::new_smooth(gradient, center, 0.0)
+    ///     .with_center_fade(10.0, Some(LinSrgb::new(1.0f64, 1.0, 1.0)));
+    ///
+    /// assert_eq!(conic_gradient.interpolate(center, center), LinSrgb::new(1.0, 1.0, 1.0));
+    /// assert_eq!(
+    ///     conic_gradient.interpolate(Vector::new(150.0, 100.0), center),
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    /// );
+    /// ```
+    pub fn with_center_fade(mut self, center_radius: f64, center_color: Option<Color>) -> Self {
+        self.set_center_fade(center_radius, center_color);
+        self
+    }
+
+    /// Radius of fade around center of conic gradient, in pixels.
+    ///
+    /// # See also
+    ///
+    /// * [`ConicGradient::with_center_fade`].
+    ///
+    pub fn center_radius(&self) -> f64 {
+        self.center_radius
+    }
+
+    /// Color toward which conic gradient is blended near its center, if set.
+    pub fn center_color(&self) -> Option<&Color> {
+        self.center_color.as_ref()
+    }
+
+    /// Sets (or removes) fade of conic gradient near its center.
+    ///
+    /// # See also
+    ///
+    /// * [`ConicGradient::with_center_fade`].
+    ///
+    pub fn set_center_fade(&mut self, center_radius: f64, center_color: Option<Color>) {
+        self.center_radius = center_radius.max(0.0);
+        self.center_color = center_color;
+    }
 }
 
 impl<Color> ColoringMethod<Color> for ConicGradient<Color>
@@ -256,13 +355,32 @@ where
         let smoothed_point = key_point.interpolate(point, self.smoothness);
         let point_vector = smoothed_point - self.center;
         let angle = point_vector.y.atan2(point_vector.x) - self.angle;
-        let clamped_angle = (angle + consts::TAU) % consts::TAU;
-        self.gradient.get(clamped_angle / consts::TAU)
+        let clamped_angle = angle.rem_euclid(consts::TAU);
+        let color = self.gradient.get(super::apply_easing(
+            self.easing,
+            clamped_angle / consts::TAU,
+        ));
+        let distance = point_vector.length();
+        if distance >= self.center_radius {
+            return color;
+        }
+        let center_color = match &self.center_color {
+            Some(center_color) => center_color.clone(),
+            None => self.gradient.get(self.gradient.domain().0),
+        };
+        center_color.mix(&color, distance / self.center_radius)
+    }
+
+    #[inline(always)]
+    fn is_uniform_per_cell(&self) -> bool {
+        self.smoothness == 0.0
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use palette::LinSrgb;
+
     use super::{super::tests, *};
 
     #[test]
@@ -364,6 +482,20 @@ mod tests {
         );
     }
     #[test]
+    fn interpolate_with_large_angle() {
+        let gradient = tests::create_rgb_gradient();
+        let conic_gradient = ConicGradient::new_smooth(
+            gradient.clone(),
+            Vector::new(100.0, 100.0),
+            consts::PI * 1.75,
+        );
+        let key_point = Vector::new(100.0, 100.0);
+        assert_eq!(
+            conic_gradient.interpolate(Vector::new(100.0, 50.0), key_point),
+            gradient.get(0.875)
+        );
+    }
+    #[test]
     fn interpolate_at_center() {
         let gradient = tests::create_lch_gradient();
         let conic_gradient =
@@ -373,4 +505,56 @@ mod tests {
             gradient.get(0.0)
         );
     }
+    #[test]
+    fn interpolate_with_center_fade() {
+        let gradient = tests::create_rgb_gradient();
+        let center = Vector::new(100.0, 100.0);
+        let center_color = LinSrgb::new(1.0f64, 1.0, 1.0);
+        let conic_gradient = ConicGradient::new_smooth(gradient.clone(), center, 0.0)
+            .with_center_fade(20.0, Some(center_color));
+        assert_eq!(conic_gradient.interpolate(center, center), center_color);
+        assert_eq!(
+            conic_gradient.interpolate(Vector::new(100.0, 110.0), center),
+            center_color.mix(&gradient.get(0.25), 0.5)
+        );
+        assert_eq!(
+            conic_gradient.interpolate(Vector::new(100.0, 120.0), center),
+            gradient.get(0.25)
+        );
+        assert_eq!(
+            conic_gradient.interpolate(Vector::new(50.0, 100.0), center),
+            gradient.get(0.5)
+        );
+    }
+    #[test]
+    fn interpolate_with_center_fade_to_gradient_start() {
+        let gradient = tests::create_rgb_gradient();
+        let center = Vector::new(0.0, 0.0);
+        let conic_gradient =
+            ConicGradient::new_smooth(gradient.clone(), center, 0.0).with_center_fade(10.0, None);
+        assert_eq!(
+            conic_gradient.interpolate(center, center),
+            gradient.get(0.1)
+        );
+        assert_eq!(
+            conic_gradient.interpolate(Vector::new(-5.0, 0.0), center),
+            gradient.get(0.1).mix(&gradient.get(0.5), 0.5)
+        );
+    }
+    #[test]
+    fn interpolate_with_easing() {
+        let gradient = tests::create_hsl_gradient();
+        let conic_gradient =
+            ConicGradient::new_smooth(gradient.clone(), Vector::new(0.0, 0.0), 0.0)
+                .with_easing(|factor| factor * factor);
+        let key_point = Vector::new(0.0, 0.0);
+        assert_eq!(
+            conic_gradient.interpolate(Vector::new(-100.0, 0.0), key_point),
+            gradient.get(0.25)
+        );
+        assert_eq!(
+            conic_gradient.interpolate(Vector::new(0.0, -100.0), key_point),
+            gradient.get(0.5625)
+        );
+    }
 }
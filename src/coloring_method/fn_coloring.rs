@@ -0,0 +1,76 @@
+use palette::Mix;
+
+use super::{ColoringMethod, Vector};
+
+/// Defines coloring method which paints mosaic images with closure (or function).
+///
+/// Closure takes position of pixel that is currently being drawn and position of
+/// the key point of mosaic fragment (exactly like [`ColoringMethod::interpolate`]) and returns
+/// color of this pixel, so one-off coloring methods can be created without implementing
+/// [`ColoringMethod`] trait.
+#[derive(Clone, Copy)]
+pub struct FnColoring<Function> {
+    function: Function,
+}
+
+impl<Function> FnColoring<Function> {
+    /// Creates coloring method from closure.
+    ///
+    /// # Arguments
+    ///
+    /// * `function`: closure which takes position of pixel and position of key point
+    ///   of mosaic fragment and returns color of pixel.
+    ///
+    /// returns: FnColoring<Function> - coloring method based on closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{coloring_method::FnColoring, Mosaic, MosaicBuilder, Vector};
+    ///
+    /// let starry_mosaic = MosaicBuilder::default().build_star().unwrap();
+    /// let mosaic_image = starry_mosaic.draw(FnColoring::new(|point: Vector, key_point| {
+    ///     let distance = point.distance_to(key_point);
+    ///     LinSrgb::new(1.0f64, 0.5, 0.0) * (1.0 / (1.0 + distance * 0.01))
+    /// }));
+    ///
+    /// assert_eq!(mosaic_image.dimensions(), starry_mosaic.image_size());
+    /// ```
+    pub fn new<Color>(function: Function) -> Self
+    where
+        Color: Mix<Scalar = f64> + Clone,
+        Function: Fn(Vector, Vector) -> Color,
+    {
+        Self { function }
+    }
+}
+
+impl<Color, Function> ColoringMethod<Color> for FnColoring<Function>
+where
+    Color: Mix<Scalar = f64> + Clone,
+    Function: Fn(Vector, Vector) -> Color,
+{
+    #[inline(always)]
+    fn interpolate(&self, point: Vector, key_point: Vector) -> Color {
+        (self.function)(point, key_point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use palette::LinSrgb;
+
+    use super::*;
+
+    #[test]
+    fn interpolate() {
+        let coloring = FnColoring::new(|point: Vector, key_point: Vector| {
+            LinSrgb::new(point.x / 100.0, key_point.y / 100.0, 0.5f64)
+        });
+        assert_eq!(
+            coloring.interpolate(Vector::new(25.0, 0.0), Vector::new(0.0, 75.0)),
+            LinSrgb::new(0.25, 0.75, 0.5)
+        );
+    }
+}
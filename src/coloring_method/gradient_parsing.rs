@@ -0,0 +1,198 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FormatResult},
+};
+
+use palette::{Gradient, LinSrgb};
+
+/// Parses gradient from compact CSS-like string.
+///
+/// String consists of color stops separated by commas; every stop is written as
+/// `position:#color`, where `position` is number and `color` is color in hexadecimal
+/// notation (`#rrggbb` or `#rgb`). Positions of stops should not decrease. Whitespace around
+/// stops, positions and colors is ignored.
+///
+/// Colors of stops are drawn exactly with their hexadecimal components
+/// (see [`Mosaic::draw`][`crate::Mosaic::draw`]).
+///
+/// # Arguments
+///
+/// * `string`: string to parse, like `"0.0:#ff0000, 0.5:#00ff00, 1.0:#0000ff"`.
+///
+/// returns: `Result<Gradient<LinSrgb<f64>>, ParseError>` - parsed gradient or reason why
+/// string can't be parsed.
+///
+/// # Examples
+///
+/// ```
+/// use palette::LinSrgb;
+/// use starry_mosaic::coloring_method::parse_gradient;
+///
+/// let gradient = parse_gradient("0.0:#ff0000, 0.5:#00ff00, 1.0:#00f").unwrap();
+///
+/// assert_eq!(gradient.get(0.0), LinSrgb::new(1.0, 0.0, 0.0));
+/// assert_eq!(gradient.get(0.5), LinSrgb::new(0.0, 1.0, 0.0));
+/// assert_eq!(gradient.get(1.0), LinSrgb::new(0.0, 0.0, 1.0));
+/// ```
+pub fn parse_gradient(string: &str) -> Result<Gradient<LinSrgb<f64>>, ParseError> {
+    if string.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut stops: Vec<(f64, LinSrgb<f64>)> = Vec::new();
+    for (index, stop) in string.split(',').enumerate() {
+        let (position, color) = stop
+            .split_once(':')
+            .ok_or(ParseError::MalformedStop(index))?;
+        let position: f64 = position
+            .trim()
+            .parse()
+            .ok()
+            .filter(|position: &f64| position.is_finite())
+            .ok_or(ParseError::InvalidPosition(index))?;
+        let color = parse_hex_color(color.trim()).ok_or(ParseError::InvalidColor(index))?;
+        if stops
+            .last()
+            .is_some_and(|&(previous_position, _)| position < previous_position)
+        {
+            return Err(ParseError::UnorderedStops(index));
+        }
+        stops.push((position, color.into_format()));
+    }
+    Ok(Gradient::with_domain(stops))
+}
+
+fn parse_hex_color(string: &str) -> Option<LinSrgb<u8>> {
+    let digits = string.strip_prefix('#')?;
+    if !digits.chars().all(|digit| digit.is_ascii_hexdigit()) {
+        return None;
+    }
+    let parse_channel = |channel: &str| u8::from_str_radix(channel, 16).ok();
+    match digits.len() {
+        6 => Some(LinSrgb::new(
+            parse_channel(&digits[0..2])?,
+            parse_channel(&digits[2..4])?,
+            parse_channel(&digits[4..6])?,
+        )),
+        3 => Some(LinSrgb::new(
+            parse_channel(&digits[0..1])? * 0x11,
+            parse_channel(&digits[1..2])? * 0x11,
+            parse_channel(&digits[2..3])? * 0x11,
+        )),
+        _ => None,
+    }
+}
+
+/// Describes why [`parse_gradient`] can't parse gradient.
+///
+/// Variants which refer to stop of gradient store its (zero-based) index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// String contains no stops.
+    Empty,
+
+    /// Stop isn't written as `position:#color`.
+    MalformedStop(usize),
+
+    /// Position of stop isn't finite number.
+    InvalidPosition(usize),
+
+    /// Color of stop isn't written as `#rrggbb` or `#rgb`.
+    InvalidColor(usize),
+
+    /// Position of stop is less than position of previous stop.
+    UnorderedStops(usize),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FormatResult {
+        match self {
+            Self::Empty => write!(formatter, "gradient has no stops"),
+            Self::MalformedStop(index) => write!(
+                formatter,
+                "stop {} of gradient should be written as `position:#color`",
+                index
+            ),
+            Self::InvalidPosition(index) => {
+                write!(formatter, "position of stop {} isn't finite number", index)
+            }
+            Self::InvalidColor(index) => write!(
+                formatter,
+                "color of stop {} should be written as `#rrggbb` or `#rgb`",
+                index
+            ),
+            Self::UnorderedStops(index) => write!(
+                formatter,
+                "position of stop {} is less than position of previous stop",
+                index
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use image::Rgb;
+
+    use super::{
+        super::{
+            super::{Mosaic, MosaicBuilder, Shading, Vector},
+            LinearGradient,
+        },
+        *,
+    };
+
+    #[test]
+    fn parse_valid_gradient() {
+        let gradient = parse_gradient(" 0.0:#ff0000,0.25 : #00FF00 , 1:#008 ").unwrap();
+        assert_eq!(gradient.domain(), (0.0, 1.0));
+        assert_eq!(gradient.get(0.0), LinSrgb::new(1.0, 0.0, 0.0));
+        assert_eq!(gradient.get(0.25), LinSrgb::new(0.0, 1.0, 0.0));
+        let expected: LinSrgb<f64> = LinSrgb::new(0u8, 0, 0x88).into_format();
+        assert_eq!(gradient.get(1.0), expected);
+    }
+    #[test]
+    fn draw_parsed_gradient() {
+        let mut mosaic = MosaicBuilder::default()
+            .set_image_size(64, 64)
+            .set_relative_center(0.5, 0.5)
+            .build_star()
+            .unwrap();
+        mosaic.set_shading(Shading::Flat);
+        let gradient = parse_gradient("0:#808080, 1:#808080").unwrap();
+        let image = mosaic.draw(LinearGradient::new_smooth(
+            gradient,
+            Vector::new(0.0, 0.0),
+            Vector::new(64.0, 0.0),
+        ));
+        assert!(image.pixels().all(|pixel| *pixel == Rgb([128, 128, 128])));
+    }
+    #[test]
+    fn parse_gradient_with_malformed_stop() {
+        assert_eq!(
+            parse_gradient("0.0:#ff0000, #00ff00").err(),
+            Some(ParseError::MalformedStop(1))
+        );
+        assert_eq!(
+            parse_gradient("0.0:#ff0000, half:#00ff00").err(),
+            Some(ParseError::InvalidPosition(1))
+        );
+        assert_eq!(
+            parse_gradient("0.0:#ff00, 1.0:#00ff00").err(),
+            Some(ParseError::InvalidColor(0))
+        );
+        assert_eq!(
+            parse_gradient("0.0:ff0000, 1.0:#00ff00").err(),
+            Some(ParseError::InvalidColor(0))
+        );
+        assert_eq!(parse_gradient("  ").err(), Some(ParseError::Empty));
+    }
+    #[test]
+    fn parse_gradient_with_unordered_stops() {
+        assert_eq!(
+            parse_gradient("0.0:#ff0000, 0.8:#00ff00, 0.5:#0000ff").err(),
+            Some(ParseError::UnorderedStops(2))
+        );
+    }
+}
@@ -13,6 +13,7 @@ where
     direction: Vector,
     direction_squared_length: f64,
     smoothness: f64,
+    easing: Option<fn(f64) -> f64>,
 }
 
 impl<Color> LinearGradient<Color>
@@ -27,7 +28,7 @@ where
     /// * `start_point`: starting point of line along which the gradient is drawn.
     /// * `end_point`: end point of line along which the gradient is drawn.
     /// * `smoothness`: smoothness of gradient ranging from 0.0 to 1.0;
-    /// see [`LinearGradient::smoothness`] for more information.
+    ///   see [`LinearGradient::smoothness`] for more information.
     ///
     /// returns: [`LinearGradient<Color>`] - linear gradient along the line connecting two points;
     /// if these points are equal returns horizontal step gradient.
@@ -72,6 +73,7 @@ where
             direction,
             direction_squared_length,
             smoothness: smoothness.clamp(0.0, 1.0),
+            easing: None,
         };
         linear_gradient.set_end_point(end_point);
         linear_gradient
@@ -248,6 +250,32 @@ where
         self.smoothness = smoothness.clamp(0.0, 1.0);
     }
 
+    /// Sets easing function of linear gradient and returns this gradient.
+    ///
+    /// Easing function maps interpolation factor (clamped to range from 0.0 to 1.0) to position
+    /// in gradient, so ease-in or ease-out transitions of colors can be created.
+    ///
+    /// # Arguments
+    ///
+    /// * `easing`: easing function which should map range from 0.0 to 1.0 onto itself.
+    ///
+    /// returns: [`LinearGradient<Color>`] - linear gradient with given easing function.
+    ///
+    pub fn with_easing(mut self, easing: fn(f64) -> f64) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    /// Easing function of linear gradient, if any.
+    pub fn easing(&self) -> Option<fn(f64) -> f64> {
+        self.easing
+    }
+
+    /// Sets (or removes) easing function of linear gradient.
+    pub fn set_easing(&mut self, easing: Option<fn(f64) -> f64>) {
+        self.easing = easing;
+    }
+
     #[inline(always)]
     fn set_direction(&mut self, end_point: Vector) {
         self.direction = if self.start_point != end_point {
@@ -267,7 +295,13 @@ where
         let smoothed_point = key_point.interpolate(point, self.smoothness);
         let interpolation_factor =
             (smoothed_point - self.start_point).dot(self.direction) / self.direction_squared_length;
-        self.gradient.get(interpolation_factor)
+        self.gradient
+            .get(super::apply_easing(self.easing, interpolation_factor))
+    }
+
+    #[inline(always)]
+    fn is_uniform_per_cell(&self) -> bool {
+        self.smoothness == 0.0
     }
 }
 
@@ -391,4 +425,30 @@ mod tests {
             linear_gradient.interpolate(end_point, end_point)
         );
     }
+    #[test]
+    fn interpolate_with_easing() {
+        let gradient = tests::create_lch_gradient();
+        let linear_gradient = LinearGradient::new_smooth(
+            gradient.clone(),
+            Vector::new(0.0, 0.0),
+            Vector::new(100.0, 0.0),
+        );
+        let eased_linear_gradient = linear_gradient
+            .clone()
+            .with_easing(|factor| factor * factor);
+        assert!(eased_linear_gradient.easing().is_some());
+        let (point, key_point) = (Vector::new(50.0, 30.0), Vector::new(0.0, 0.0));
+        assert_eq!(
+            linear_gradient.interpolate(point, key_point),
+            gradient.get(0.5)
+        );
+        assert_eq!(
+            eased_linear_gradient.interpolate(point, key_point),
+            gradient.get(0.25)
+        );
+        assert_eq!(
+            eased_linear_gradient.interpolate(Vector::new(-50.0, 0.0), key_point),
+            gradient.get(0.0)
+        );
+    }
 }
@@ -9,7 +9,7 @@
 
 use palette::Mix;
 
-use super::vector::Vector;
+use super::{segment::Segment, vector::Vector};
 
 /// Defines color of every pixel of every piece of mosaic image.
 ///
@@ -87,6 +87,47 @@ where
     /// * [`ColoringMethod`].
     ///
     fn interpolate(&self, point: Vector, key_point: Vector) -> Color;
+
+    /// Defines color of current pixel by interpolating between its position and
+    /// position of the key point of mosaic fragment with known index of this fragment.
+    ///
+    /// Default implementation ignores index of fragment and delegates to
+    /// [`ColoringMethod::interpolate`]. Override this method to paint fragments of mosaic
+    /// differently (for example, to pick color of palette per cell).
+    ///
+    /// # Arguments
+    ///
+    /// * `point`: position of pixel that is currently being drawn.
+    /// * `key_point`: position of key point of current mosaic fragment.
+    /// * `cell_index`: index of current mosaic fragment (for starry mosaic - index of site
+    ///   of Voronoi diagram).
+    ///
+    /// returns: `Color` - color of current pixel of mosaic image.
+    ///
+    /// # See also
+    ///
+    /// * [`ColoringMethod::interpolate`].
+    ///
+    #[inline(always)]
+    fn interpolate_indexed(&self, point: Vector, key_point: Vector, cell_index: usize) -> Color {
+        let _ = cell_index;
+        self.interpolate(point, key_point)
+    }
+
+    /// Checks whether color of pixel depends only on key point (and index)
+    /// of mosaic fragment.
+    ///
+    /// If this method returns `true`, every pixel of mosaic fragment gets the same color, so
+    /// mosaics may compute this color once per fragment (by interpolating at key point itself)
+    /// instead of once per pixel. Default implementation returns `false`; override it for
+    /// coloring methods which ignore position of pixel (like step gradients).
+    ///
+    /// returns: `bool` - `true` if color is the same for all pixels of mosaic fragment.
+    ///
+    #[inline(always)]
+    fn is_uniform_per_cell(&self) -> bool {
+        false
+    }
 }
 
 impl<Color> ColoringMethod<Color> for Color
@@ -97,15 +138,47 @@ where
     fn interpolate(&self, _point: Vector, _key_point: Vector) -> Color {
         self.clone()
     }
+
+    #[inline(always)]
+    fn is_uniform_per_cell(&self) -> bool {
+        true
+    }
+}
+
+#[inline(always)]
+fn apply_easing(easing: Option<fn(f64) -> f64>, interpolation_factor: f64) -> f64 {
+    match easing {
+        Some(easing) => easing(interpolation_factor.clamp(0.0, 1.0)),
+        None => interpolation_factor,
+    }
 }
 
+mod blend;
+mod checkerboard;
 mod conic_gradient;
+mod fn_coloring;
+mod gradient_parsing;
 mod linear_gradient;
+mod path_gradient;
+mod perceptual;
+pub mod presets;
 mod radial_gradient;
+mod segment_distance_coloring;
+mod stripes;
+mod vignette;
 
+pub use self::blend::Blend;
+pub use self::checkerboard::Checkerboard;
 pub use self::conic_gradient::ConicGradient;
+pub use self::fn_coloring::FnColoring;
+pub use self::gradient_parsing::{parse_gradient, ParseError};
 pub use self::linear_gradient::LinearGradient;
+pub use self::path_gradient::PathGradient;
+pub use self::perceptual::Perceptual;
 pub use self::radial_gradient::RadialGradient;
+pub use self::segment_distance_coloring::SegmentDistanceColoring;
+pub use self::stripes::Stripes;
+pub use self::vignette::Vignette;
 
 #[cfg(test)]
 mod tests {
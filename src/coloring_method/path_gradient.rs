@@ -0,0 +1,206 @@
+use palette::{Gradient, Mix};
+
+use super::{ColoringMethod, Segment, Vector};
+
+/// Defines gradient which follows path (for example, outline of mosaic shape) instead of
+/// straight line.
+///
+/// Every pixel is painted with color of gradient at position of nearest point of path,
+/// measured as fraction of path length from its first point. So gradient wraps around shape
+/// which outline path describes.
+#[derive(Clone, Debug)]
+pub struct PathGradient<Color>
+where
+    Color: Mix<Scalar = f64> + Clone,
+{
+    gradient: Gradient<Color>,
+    path: Vec<Vector>,
+    closed: bool,
+    segments: Vec<Segment>,
+    segment_offsets: Vec<f64>,
+    path_length: f64,
+}
+
+impl<Color> PathGradient<Color>
+where
+    Color: Mix<Scalar = f64> + Clone,
+{
+    /// Creates gradient which follows path.
+    ///
+    /// # Arguments
+    ///
+    /// * `gradient`: list of colors or colors stops of gradient.
+    /// * `path`: ordered points (in image space) of path along which gradient goes.
+    /// * `closed`: whether last point of path is connected with its first point.
+    ///
+    /// returns: [`PathGradient<Color>`] - gradient which follows given path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{coloring_method::{ColoringMethod, PathGradient}, Vector};
+    ///
+    /// let path_gradient = PathGradient::new(
+    ///     vec![
+    ///         (0.0, LinSrgb::new(1.0f64, 1.0, 1.0)),
+    ///         (1.0, LinSrgb::new(0.0f64, 0.0, 0.0)),
+    ///     ],
+    ///     vec![Vector::new(0.0, 100.0), Vector::new(200.0, 100.0)],
+    ///     false,
+    /// );
+    ///
+    /// let key_point = Vector::new(100.0, 100.0);
+    /// assert_eq!(
+    ///     path_gradient.interpolate(Vector::new(100.0, 150.0), key_point),
+    ///     LinSrgb::new(0.5f64, 0.5, 0.5),
+    /// );
+    /// ```
+    pub fn new<ColorGradient>(gradient: ColorGradient, path: Vec<Vector>, closed: bool) -> Self
+    where
+        ColorGradient: Into<Gradient<Color>>,
+    {
+        let mut path_gradient = Self {
+            gradient: gradient.into(),
+            path: vec![],
+            closed,
+            segments: vec![],
+            segment_offsets: vec![],
+            path_length: 0.0,
+        };
+        path_gradient.set_path(path);
+        path_gradient
+    }
+
+    /// Ordered points of path along which gradient goes.
+    pub fn path(&self) -> &Vec<Vector> {
+        &self.path
+    }
+
+    /// Sets ordered points of path along which gradient goes.
+    pub fn set_path(&mut self, path: Vec<Vector>) {
+        self.path = path;
+        self.split_path();
+    }
+
+    /// Whether last point of path is connected with its first point.
+    pub fn closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Sets whether last point of path is connected with its first point.
+    pub fn set_closed(&mut self, closed: bool) {
+        self.closed = closed;
+        self.split_path();
+    }
+
+    /// Calculates position of point along path as fraction of path length.
+    ///
+    /// # Arguments
+    ///
+    /// * `point`: point (in image space) which is projected onto path.
+    ///
+    /// returns: `f64` - position of nearest point of path ranging from 0.0 (first point
+    /// of path) to 1.0 (end of path).
+    ///
+    pub fn path_position(&self, point: Vector) -> f64 {
+        if self.path_length <= 0.0 {
+            return 0.0;
+        }
+        let (offset, _) = self
+            .segments
+            .iter()
+            .zip(&self.segment_offsets)
+            .map(|(segment, offset)| {
+                let closest_point = segment.closest_point(point);
+                (
+                    offset + segment.start.distance_to(closest_point),
+                    point.squared_distance_to(closest_point),
+                )
+            })
+            .fold((0.0, f64::INFINITY), |nearest, candidate| {
+                if candidate.1 < nearest.1 {
+                    candidate
+                } else {
+                    nearest
+                }
+            });
+        offset / self.path_length
+    }
+
+    fn split_path(&mut self) {
+        self.segments = self
+            .path
+            .windows(2)
+            .map(|points| Segment::new(points[0], points[1]))
+            .collect();
+        if self.closed && self.path.len() > 2 {
+            self.segments
+                .push(Segment::new(self.path[self.path.len() - 1], self.path[0]));
+        }
+        self.segment_offsets = Vec::with_capacity(self.segments.len());
+        self.path_length = 0.0;
+        for segment in &self.segments {
+            self.segment_offsets.push(self.path_length);
+            self.path_length += segment.length();
+        }
+    }
+}
+
+impl<Color> ColoringMethod<Color> for PathGradient<Color>
+where
+    Color: Mix<Scalar = f64> + Clone,
+{
+    fn interpolate(&self, point: Vector, _key_point: Vector) -> Color {
+        self.gradient.get(self.path_position(point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::tests, *};
+
+    fn create_square() -> Vec<Vector> {
+        vec![
+            Vector::new(0.0, 0.0),
+            Vector::new(100.0, 0.0),
+            Vector::new(100.0, 100.0),
+            Vector::new(0.0, 100.0),
+        ]
+    }
+
+    #[test]
+    fn path_position_of_square_corners() {
+        let gradient = tests::create_rgb_gradient();
+        let path_gradient = PathGradient::new(gradient, create_square(), true);
+        assert_eq!(path_gradient.path_length, 400.0);
+        for (index, corner) in create_square().iter().enumerate() {
+            assert_eq!(path_gradient.path_position(*corner), index as f64 * 0.25);
+        }
+        assert_eq!(path_gradient.path_position(Vector::new(-10.0, 50.0)), 0.875);
+    }
+    #[test]
+    fn interpolate_along_open_path() {
+        let gradient = tests::create_lch_gradient();
+        let path_gradient = PathGradient::new(gradient.clone(), create_square(), false);
+        assert_eq!(path_gradient.path_length, 300.0);
+        let key_point = Vector::new(50.0, 50.0);
+        assert_eq!(
+            path_gradient.interpolate(Vector::new(110.0, 50.0), key_point),
+            gradient.get(0.5)
+        );
+        assert_eq!(
+            path_gradient.interpolate(Vector::new(-10.0, 110.0), key_point),
+            gradient.get(1.0)
+        );
+    }
+    #[test]
+    fn interpolate_without_path() {
+        let gradient = tests::create_hsl_gradient();
+        let path_gradient = PathGradient::new(gradient.clone(), vec![], true);
+        assert_eq!(
+            path_gradient.interpolate(Vector::new(10.0, 0.0), Vector::new(0.0, 0.0)),
+            gradient.get(0.0)
+        );
+    }
+}
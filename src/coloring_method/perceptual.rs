@@ -0,0 +1,155 @@
+use palette::{convert::FromColorUnclamped, FromColor, IntoColor, LinSrgb, Mix, Oklab, Shade};
+
+/// Wraps color so it's mixed in perceptual Oklab color space.
+///
+/// Gradients mix their colors in color space of these colors. Mixing in RGB often produces
+/// dull (muddy) colors in the middle of color ramp. When stops of gradient are wrapped
+/// into `Perceptual`, colors are converted to Oklab, mixed there and converted back,
+/// so every gradient of this module produces perceptually uniform color ramps.
+///
+/// Lightening and darkening of wrapped color (used in shading of mosaic images)
+/// is performed in original color space.
+///
+/// # Examples
+///
+/// ```
+/// use palette::{Gradient, LinSrgb};
+/// use starry_mosaic::{
+///     coloring_method::{LinearGradient, Perceptual},
+///     Mosaic,
+///     MosaicBuilder,
+///     Vector,
+/// };
+///
+/// let gradient = Gradient::new(vec![
+///     Perceptual::new(LinSrgb::new(1.0f64, 0.0, 0.0)),
+///     Perceptual::new(LinSrgb::new(0.0f64, 1.0, 0.0)),
+/// ]);
+/// let mosaic = MosaicBuilder::default().build_star().unwrap();
+/// let mosaic_image = mosaic.draw(LinearGradient::new_smooth(
+///     gradient,
+///     Vector::new(0.0, 0.0),
+///     Vector::new(640.0, 640.0),
+/// ));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Perceptual<Color> {
+    color: Color,
+}
+
+impl<Color> Perceptual<Color> {
+    /// Wraps color so it's mixed in Oklab color space.
+    ///
+    /// # Arguments
+    ///
+    /// * `color`: wrapped color.
+    ///
+    /// returns: [`Perceptual<Color>`] - color mixed in Oklab color space.
+    ///
+    pub fn new(color: Color) -> Self {
+        Self { color }
+    }
+
+    /// Wrapped color.
+    #[inline(always)]
+    pub fn color(&self) -> &Color {
+        &self.color
+    }
+
+    /// Unwraps color.
+    #[inline(always)]
+    pub fn into_inner(self) -> Color {
+        self.color
+    }
+}
+
+impl<Color> From<Color> for Perceptual<Color> {
+    fn from(color: Color) -> Self {
+        Self::new(color)
+    }
+}
+
+impl<Color> Mix for Perceptual<Color>
+where
+    Color: IntoColor<Oklab<f64>> + FromColor<Oklab<f64>> + Clone,
+{
+    type Scalar = f64;
+
+    fn mix(&self, other: &Self, factor: f64) -> Self {
+        let first: Oklab<f64> = self.color.clone().into_color();
+        let second: Oklab<f64> = other.color.clone().into_color();
+        Self::new(Color::from_color(first.mix(&second, factor)))
+    }
+}
+
+impl<Color> Shade for Perceptual<Color>
+where
+    Color: Shade<Scalar = f64>,
+{
+    type Scalar = f64;
+
+    fn lighten(&self, factor: f64) -> Self {
+        Self::new(self.color.lighten(factor))
+    }
+
+    fn lighten_fixed(&self, amount: f64) -> Self {
+        Self::new(self.color.lighten_fixed(amount))
+    }
+}
+
+impl<Color> FromColorUnclamped<Perceptual<Color>> for LinSrgb<f64>
+where
+    Color: IntoColor<LinSrgb<f64>>,
+{
+    fn from_color_unclamped(perceptual: Perceptual<Color>) -> Self {
+        perceptual.color.into_color()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use palette::Gradient;
+
+    use super::{
+        super::{ColoringMethod, LinearGradient, Vector},
+        *,
+    };
+
+    #[test]
+    fn mix_in_oklab() {
+        let (red, green) = (
+            LinSrgb::new(1.0f64, 0.0, 0.0),
+            LinSrgb::new(0.0f64, 1.0, 0.0),
+        );
+        let rgb_middle = red.mix(&green, 0.5);
+        let perceptual_middle = Perceptual::new(red)
+            .mix(&Perceptual::new(green), 0.5)
+            .into_inner();
+        let oklab_middle: Oklab<f64> = Oklab::from_color(red).mix(&Oklab::from_color(green), 0.5);
+        assert_eq!(perceptual_middle, LinSrgb::from_color(oklab_middle));
+        assert_ne!(perceptual_middle, rgb_middle);
+    }
+    #[test]
+    fn interpolate_gradient() {
+        let (red, green) = (
+            LinSrgb::new(1.0f64, 0.0, 0.0),
+            LinSrgb::new(0.0f64, 1.0, 0.0),
+        );
+        let gradient = LinearGradient::new_smooth(
+            Gradient::new(vec![Perceptual::new(red), Perceptual::new(green)]),
+            Vector::new(0.0, 0.0),
+            Vector::new(100.0, 0.0),
+        );
+        let point = Vector::new(50.0, 0.0);
+        assert_eq!(
+            gradient.interpolate(point, point),
+            Perceptual::new(red).mix(&Perceptual::new(green), 0.5)
+        );
+        assert_eq!(
+            gradient
+                .interpolate(Vector::new(0.0, 0.0), point)
+                .into_inner(),
+            red
+        );
+    }
+}
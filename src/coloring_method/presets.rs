@@ -0,0 +1,140 @@
+//! This module provides ready-made gradients which can be used with any gradient coloring method
+//! (like [`LinearGradient`][`super::LinearGradient`] or [`RadialGradient`][`super::RadialGradient`]).
+//!
+//! Colors of stops of every preset are specified by 8-bit components and evenly distributed
+//! over domain `[0.0; 1.0]` of gradient; stops are drawn exactly with these components
+//! (see [`Mosaic::draw`][`crate::Mosaic::draw`]).
+
+use palette::{Gradient, LinSrgb};
+
+/// Creates gradient of colors of rainbow.
+///
+/// Gradient goes through red, yellow, green, cyan, blue and ends with magenta.
+///
+/// returns: `Gradient<LinSrgb<f64>>` - rainbow gradient.
+///
+/// # Examples
+///
+/// ```
+/// use palette::LinSrgb;
+/// use starry_mosaic::coloring_method::presets;
+///
+/// let rainbow = presets::rainbow();
+///
+/// assert_eq!(rainbow.get(0.0), LinSrgb::new(1.0, 0.0, 0.0));
+/// assert_eq!(rainbow.get(1.0), LinSrgb::new(1.0, 0.0, 1.0));
+/// ```
+pub fn rainbow() -> Gradient<LinSrgb<f64>> {
+    create_gradient(&[
+        (255, 0, 0),
+        (255, 255, 0),
+        (0, 255, 0),
+        (0, 255, 255),
+        (0, 0, 255),
+        (255, 0, 255),
+    ])
+}
+
+/// Creates heatmap gradient.
+///
+/// Gradient goes from black through red and yellow to white.
+///
+/// returns: `Gradient<LinSrgb<f64>>` - heatmap gradient.
+///
+pub fn heatmap() -> Gradient<LinSrgb<f64>> {
+    create_gradient(&[(0, 0, 0), (255, 0, 0), (255, 255, 0), (255, 255, 255)])
+}
+
+/// Creates gradient which approximates perceptually uniform "viridis" color map.
+///
+/// Gradient goes from dark purple (`#440154`) through blue and green to yellow (`#fde725`).
+///
+/// returns: `Gradient<LinSrgb<f64>>` - viridis gradient.
+///
+pub fn viridis() -> Gradient<LinSrgb<f64>> {
+    create_gradient(&[
+        (0x44, 0x01, 0x54),
+        (0x3b, 0x52, 0x8b),
+        (0x21, 0x91, 0x8c),
+        (0x5e, 0xc9, 0x62),
+        (0xfd, 0xe7, 0x25),
+    ])
+}
+
+/// Creates gradient from black to white.
+///
+/// returns: `Gradient<LinSrgb<f64>>` - grayscale gradient.
+///
+pub fn grayscale() -> Gradient<LinSrgb<f64>> {
+    create_gradient(&[(0, 0, 0), (255, 255, 255)])
+}
+
+fn create_gradient(colors: &[(u8, u8, u8)]) -> Gradient<LinSrgb<f64>> {
+    Gradient::new(
+        colors
+            .iter()
+            .map(|&(red, green, blue)| LinSrgb::new(red, green, blue).into_format())
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{
+            super::{Mosaic, MosaicBuilder, Shading, Vector},
+            LinearGradient,
+        },
+        *,
+    };
+
+    fn assert_endpoints(gradient: Gradient<LinSrgb<f64>>, start: (u8, u8, u8), end: (u8, u8, u8)) {
+        let to_rgb = |color: LinSrgb<f64>| {
+            let color: LinSrgb<u8> = color.into_format();
+            (color.red, color.green, color.blue)
+        };
+        assert_eq!(to_rgb(gradient.get(0.0)), start);
+        assert_eq!(to_rgb(gradient.get(1.0)), end);
+    }
+
+    #[test]
+    fn rainbow() {
+        assert_endpoints(super::rainbow(), (255, 0, 0), (255, 0, 255));
+        let middle: LinSrgb<u8> = super::rainbow().get(0.4).into_format();
+        assert_eq!(middle, LinSrgb::new(0, 255, 0));
+    }
+    #[test]
+    fn heatmap() {
+        assert_endpoints(super::heatmap(), (0, 0, 0), (255, 255, 255));
+    }
+    #[test]
+    fn viridis() {
+        assert_endpoints(super::viridis(), (0x44, 0x01, 0x54), (0xfd, 0xe7, 0x25));
+    }
+    #[test]
+    fn grayscale() {
+        assert_endpoints(super::grayscale(), (0, 0, 0), (255, 255, 255));
+        let middle = super::grayscale().get(0.5);
+        assert_eq!(middle.red, middle.green);
+        assert_eq!(middle.green, middle.blue);
+    }
+    #[test]
+    fn draw_grayscale() {
+        let mut mosaic = MosaicBuilder::default()
+            .set_image_size(256, 16)
+            .set_relative_center(0.5, 0.5)
+            .build_star()
+            .unwrap();
+        mosaic.set_shading(Shading::Flat);
+        let image = mosaic.draw(LinearGradient::new_smooth(
+            super::grayscale(),
+            Vector::new(0.0, 0.0),
+            Vector::new(255.0, 0.0),
+        ));
+        for (x, _, pixel) in image.enumerate_pixels() {
+            assert!((pixel[0] as i32 - x as i32).abs() <= 1);
+            assert_eq!(pixel[0], pixel[1]);
+            assert_eq!(pixel[1], pixel[2]);
+        }
+    }
+}
@@ -15,6 +15,8 @@ where
     inner_radius: f64,
     radius_difference: f64,
     smoothness: f64,
+    easing: Option<fn(f64) -> f64>,
+    axes: (f64, f64),
 }
 
 impl<Color> RadialGradient<Color>
@@ -30,10 +32,10 @@ where
     /// * `inner_radius`: radius of inner circle; must be non-negative.
     /// * `outer_center`: center of outer circle.
     /// * `outer_radius`: radius of outer circle; must be non-negative.
-    /// If the inner circle is not inside the outer circle then radius of the outer circle
-    /// will be increased automatically.
+    ///   If the inner circle is not inside the outer circle then radius of the outer circle
+    ///   will be increased automatically.
     /// * `smoothness`: smoothness of gradient ranging from 0.0 to 1.0;
-    /// see [`RadialGradient::smoothness`] for more information.
+    ///   see [`RadialGradient::smoothness`] for more information.
     ///
     /// returns: [`RadialGradient<Color>`] - radial gradient initialized with two specified
     /// circles; if these circles are equal returns radial step gradient.
@@ -87,6 +89,8 @@ where
             inner_radius: inner_radius.max(0.0),
             radius_difference: outer_radius.max(0.0) - inner_radius.max(0.0),
             smoothness: smoothness.clamp(0.0, 1.0),
+            easing: None,
+            axes: (1.0, 1.0),
         };
         radial_gradient.fit_inner_circle_into_outer();
         radial_gradient
@@ -101,8 +105,8 @@ where
     /// * `inner_radius`: radius of inner circle; must be non-negative.
     /// * `outer_center`: center of outer circle.
     /// * `outer_radius`: radius of outer circle; must be non-negative.
-    /// If the inner circle is not inside the outer circle then radius of the outer circle
-    /// will be increased automatically.
+    ///   If the inner circle is not inside the outer circle then radius of the outer circle
+    ///   will be increased automatically.
     ///
     /// returns: [`RadialGradient<Color>`] - radial smooth gradient initialized with two specified
     /// circles; if these circles are equal returns radial step gradient.
@@ -169,8 +173,8 @@ where
     /// * `inner_radius`: radius of inner circle; must be non-negative.
     /// * `outer_center`: center of outer circle.
     /// * `outer_radius`: radius of outer circle; must be non-negative.
-    /// If the inner circle is not inside the outer circle then radius of the outer circle
-    /// will be increased automatically.
+    ///   If the inner circle is not inside the outer circle then radius of the outer circle
+    ///   will be increased automatically.
     ///
     /// returns: [`RadialGradient<Color>`] - radial step gradient initialized with two specified
     /// circles.
@@ -239,7 +243,7 @@ where
     /// * `center`: center of circle that bounds radial gradient.
     /// * `radius`: radius of circle; must be non-negative.
     /// * `smoothness`: smoothness of gradient ranging from 0.0 to 1.0;
-    /// see [`RadialGradient::smoothness`] for more information.
+    ///   see [`RadialGradient::smoothness`] for more information.
     ///
     /// returns: [`RadialGradient<Color>`] - radial simple gradient initialized with single circle.
     ///
@@ -400,6 +404,130 @@ where
         Self::new_simple(gradient, center, radius, 0.0)
     }
 
+    /// Creates radial elliptical gradient using size and position of ellipse that bounds it.
+    ///
+    /// Elliptical gradient is a simple gradient which bands follow ellipse with independent
+    /// horizontal and vertical radii instead of circle.
+    ///
+    /// # Arguments
+    ///
+    /// * `gradient`: list of colors or colors stops of gradient.
+    /// * `center`: center of ellipse.
+    /// * `horizontal_radius`: horizontal radius of ellipse; must be positive.
+    /// * `vertical_radius`: vertical radius of ellipse; must be positive.
+    /// * `smoothness`: smoothness of gradient ranging from 0.0 to 1.0;
+    ///   see [`RadialGradient::smoothness`] for more information.
+    ///
+    /// returns: [`RadialGradient<Color>`] - radial elliptical gradient initialized with
+    /// single ellipse.
+    ///
+    /// # See also
+    ///
+    /// * [`RadialGradient::new_simple`].
+    /// * [`RadialGradient::axes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{coloring_method::{ColoringMethod, RadialGradient}, Vector};
+    ///
+    /// let gradient = vec![
+    ///     (0.0, LinSrgb::new(1.0f64, 0.0, 0.0)),
+    ///     (1.0, LinSrgb::new(0.0f64, 0.0, 1.0)),
+    /// ];
+    /// let radial_elliptical_gradient = RadialGradient::new_elliptical(
+    ///     gradient,
+    ///     Vector::new(200.0, 200.0),
+    ///     200.0,
+    ///     100.0,
+    ///     1.0,
+    /// );
+    ///
+    /// let key_point = Vector::new(200.0, 200.0);
+    /// assert_eq!(
+    ///     radial_elliptical_gradient.interpolate(Vector::new(400.0, 200.0), key_point),
+    ///     radial_elliptical_gradient.interpolate(Vector::new(200.0, 300.0), key_point),
+    /// );
+    /// ```
+    pub fn new_elliptical<ColorGradient>(
+        gradient: ColorGradient,
+        center: Vector,
+        horizontal_radius: f64,
+        vertical_radius: f64,
+        smoothness: f64,
+    ) -> Self
+    where
+        ColorGradient: Into<Gradient<Color>>,
+    {
+        let mut radial_gradient = Self::new_simple(gradient, center, 1.0, smoothness);
+        radial_gradient.set_axes(horizontal_radius, vertical_radius);
+        radial_gradient
+    }
+
+    /// Creates radial focal gradient using focal point and circle that bounds gradient.
+    ///
+    /// Focal gradient emanates from focal point (its first color is at focal point) and ends
+    /// at bounding circle, like SVG `radialGradient` with `fx` and `fy` attributes. It's
+    /// equivalent to two circles gradient with inner circle of zero radius at focal point.
+    ///
+    /// # Arguments
+    ///
+    /// * `gradient`: list of colors or colors stops of gradient.
+    /// * `focus`: focal point from which gradient emanates; should lie inside bounding circle.
+    /// * `center`: center of bounding circle.
+    /// * `radius`: radius of bounding circle; must be positive.
+    /// * `smoothness`: smoothness of gradient ranging from 0.0 to 1.0;
+    ///   see [`RadialGradient::smoothness`] for more information.
+    ///
+    /// returns: [`RadialGradient<Color>`] - radial focal gradient.
+    ///
+    /// # See also
+    ///
+    /// * [`RadialGradient::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{coloring_method::{ColoringMethod, RadialGradient}, Vector};
+    ///
+    /// let gradient = vec![
+    ///     (0.0, LinSrgb::new(1.0f64, 0.0, 0.0)),
+    ///     (1.0, LinSrgb::new(0.0f64, 0.0, 1.0)),
+    /// ];
+    /// let radial_focal_gradient = RadialGradient::new_focal(
+    ///     gradient,
+    ///     Vector::new(150.0, 200.0),
+    ///     Vector::new(200.0, 200.0),
+    ///     200.0,
+    ///     1.0,
+    /// );
+    ///
+    /// let key_point = Vector::new(200.0, 200.0);
+    /// assert_eq!(
+    ///     radial_focal_gradient.interpolate(Vector::new(150.0, 200.0), key_point),
+    ///     LinSrgb::new(1.0f64, 0.0, 0.0),
+    /// );
+    /// assert_eq!(
+    ///     radial_focal_gradient.interpolate(Vector::new(400.0, 200.0), key_point),
+    ///     LinSrgb::new(0.0f64, 0.0, 1.0),
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn new_focal<ColorGradient>(
+        gradient: ColorGradient,
+        focus: Vector,
+        center: Vector,
+        radius: f64,
+        smoothness: f64,
+    ) -> Self
+    where
+        ColorGradient: Into<Gradient<Color>>,
+    {
+        Self::new(gradient, focus, 0.0, center, radius, smoothness)
+    }
+
     /// Center of inner circle of radial gradient.
     pub fn inner_center(&self) -> Vector {
         self.inner_center
@@ -526,6 +654,55 @@ where
         self.smoothness = smoothness.clamp(0.0, 1.0);
     }
 
+    /// Sets easing function of radial gradient and returns this gradient.
+    ///
+    /// Easing function maps interpolation factor (clamped to range from 0.0 to 1.0) to position
+    /// in gradient, so ease-in or ease-out transitions of colors can be created.
+    ///
+    /// # Arguments
+    ///
+    /// * `easing`: easing function which should map range from 0.0 to 1.0 onto itself.
+    ///
+    /// returns: [`RadialGradient<Color>`] - radial gradient with given easing function.
+    ///
+    pub fn with_easing(mut self, easing: fn(f64) -> f64) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    /// Easing function of radial gradient, if any.
+    pub fn easing(&self) -> Option<fn(f64) -> f64> {
+        self.easing
+    }
+
+    /// Sets (or removes) easing function of radial gradient.
+    pub fn set_easing(&mut self, easing: Option<fn(f64) -> f64>) {
+        self.easing = easing;
+    }
+
+    /// Horizontal and vertical scales of space in which circles of radial gradient are defined.
+    ///
+    /// Before calculating gradient every point is scaled around inner center by
+    /// `(1.0 / horizontal, 1.0 / vertical)`, so circles of gradient become ellipses
+    /// in mosaic image. Default axes `(1.0, 1.0)` keep circles as is.
+    pub fn axes(&self) -> (f64, f64) {
+        self.axes
+    }
+
+    /// Sets horizontal and vertical scales of space in which circles of radial gradient
+    /// are defined.
+    ///
+    /// # Arguments
+    ///
+    /// * `horizontal`: horizontal scale; must be positive.
+    /// * `vertical`: vertical scale; must be positive.
+    pub fn set_axes(&mut self, horizontal: f64, vertical: f64) {
+        self.axes = (
+            horizontal.max(utility::EPSILON),
+            vertical.max(utility::EPSILON),
+        );
+    }
+
     #[inline(always)]
     fn fit_inner_circle_into_outer(&mut self) {
         self.radius_difference = self
@@ -540,13 +717,19 @@ where
 {
     fn interpolate(&self, point: Vector, key_point: Vector) -> Color {
         let smoothed_point = key_point.interpolate(point, self.smoothness);
-        let point_vector = smoothed_point - self.inner_center;
+        let point_vector = (smoothed_point - self.inner_center) / self.axes;
         let alpha = self.direction_squared_length - self.radius_difference.powi(2);
         let beta = point_vector.dot(self.direction) + self.inner_radius * self.radius_difference;
         let gamma = point_vector.squared_length() - self.inner_radius.powi(2);
         let discriminant = beta * beta - alpha * gamma;
         let interpolation_factor = (beta - discriminant.sqrt()) / alpha;
-        self.gradient.get(interpolation_factor)
+        self.gradient
+            .get(super::apply_easing(self.easing, interpolation_factor))
+    }
+
+    #[inline(always)]
+    fn is_uniform_per_cell(&self) -> bool {
+        self.smoothness == 0.0
     }
 }
 
@@ -639,6 +822,56 @@ mod tests {
         assert!(radial_gradient.outer_radius() > 150.0);
     }
     #[test]
+    fn set_axes() {
+        let gradient = tests::create_rgb_gradient();
+        let mut radial_gradient =
+            RadialGradient::new_simple_smooth(gradient, Vector::new(250.0, 250.0), 200.0);
+        assert_eq!(radial_gradient.axes(), (1.0, 1.0));
+        radial_gradient.set_axes(2.0, -1.0);
+        assert_eq!(radial_gradient.axes.0, 2.0);
+        assert!(radial_gradient.axes.1 > 0.0);
+    }
+    #[test]
+    fn interpolate_focal() {
+        let gradient = tests::create_rgb_gradient();
+        let focus = Vector::new(180.0, 220.0);
+        let center = Vector::new(250.0, 250.0);
+        let focal_gradient = RadialGradient::new_focal(gradient.clone(), focus, center, 200.0, 0.5);
+        let two_circles_gradient =
+            RadialGradient::new(gradient.clone(), focus, 0.0, center, 200.0, 0.5);
+        let key_point = Vector::new(200.0, 300.0);
+        for point in [
+            Vector::new(180.0, 220.0),
+            Vector::new(50.0, 250.0),
+            Vector::new(300.0, 120.0),
+            Vector::new(420.0, 390.0),
+        ] {
+            assert_eq!(
+                focal_gradient.interpolate(point, key_point),
+                two_circles_gradient.interpolate(point, key_point)
+            );
+        }
+        assert_eq!(focal_gradient.inner_radius(), 0.0);
+        assert_eq!(focal_gradient.interpolate(focus, focus), gradient.get(0.0));
+    }
+    #[test]
+    fn interpolate_elliptical() {
+        let gradient = tests::create_rgb_gradient();
+        let radial_gradient = RadialGradient::new_elliptical(
+            gradient.clone(),
+            Vector::new(250.0, 250.0),
+            200.0,
+            100.0,
+            1.0,
+        );
+        let key_point = Vector::new(250.0, 250.0);
+        let horizontal_color = radial_gradient.interpolate(Vector::new(330.0, 250.0), key_point);
+        let vertical_color = radial_gradient.interpolate(Vector::new(250.0, 330.0), key_point);
+        assert_ne!(horizontal_color, vertical_color);
+        assert_eq!(horizontal_color, gradient.get(0.4));
+        assert_eq!(vertical_color, gradient.get(0.8));
+    }
+    #[test]
     fn interpolate_smooth() {
         let gradient = tests::create_rgb_gradient();
         let radial_gradient = RadialGradient::new_smooth(
@@ -772,4 +1005,25 @@ mod tests {
             gradient.get(1.0)
         );
     }
+    #[test]
+    fn interpolate_with_easing() {
+        let gradient = tests::create_rgb_gradient();
+        let mut radial_gradient =
+            RadialGradient::new_simple_smooth(gradient.clone(), Vector::new(0.0, 0.0), 100.0);
+        let (point, key_point) = (Vector::new(0.0, 50.0), Vector::new(0.0, 0.0));
+        assert_eq!(
+            radial_gradient.interpolate(point, key_point),
+            gradient.get(0.5)
+        );
+        radial_gradient.set_easing(Some(|factor| factor * factor));
+        assert_eq!(
+            radial_gradient.interpolate(point, key_point),
+            gradient.get(0.25)
+        );
+        radial_gradient.set_easing(None);
+        assert_eq!(
+            radial_gradient.interpolate(point, key_point),
+            gradient.get(0.5)
+        );
+    }
 }
@@ -0,0 +1,159 @@
+use palette::{Gradient, Mix};
+
+use super::{ColoringMethod, Segment, Vector};
+
+/// Defines coloring method which paints mosaic images depending on distance from every pixel
+/// to nearest line segment.
+///
+/// Using line segments of mosaic shape this coloring method highlights its "skeleton".
+#[derive(Clone, Debug)]
+pub struct SegmentDistanceColoring<Color>
+where
+    Color: Mix<Scalar = f64> + Clone,
+{
+    gradient: Gradient<Color>,
+    segments: Vec<Segment>,
+    maximum_distance: f64,
+}
+
+impl<Color> SegmentDistanceColoring<Color>
+where
+    Color: Mix<Scalar = f64> + Clone,
+{
+    /// Creates coloring method which maps distance to nearest line segment into gradient.
+    ///
+    /// # Arguments
+    ///
+    /// * `gradient`: list of colors or colors stops of gradient.
+    /// * `segments`: line segments (in image space) distance to which defines color of pixel.
+    /// * `maximum_distance`: distance from line segments at which gradient reaches its end;
+    ///   should be positive.
+    ///
+    /// returns: SegmentDistanceColoring<Color> - coloring method based on distance
+    /// to line segments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{
+    ///     coloring_method::{ColoringMethod, SegmentDistanceColoring},
+    ///     Segment,
+    ///     Vector,
+    /// };
+    ///
+    /// let coloring_method = SegmentDistanceColoring::new(
+    ///     vec![
+    ///         (0.0, LinSrgb::new(1.0f64, 1.0, 1.0)),
+    ///         (1.0, LinSrgb::new(0.0f64, 0.0, 0.0)),
+    ///     ],
+    ///     vec![Segment::new(Vector::new(0.0, 100.0), Vector::new(200.0, 100.0))],
+    ///     50.0,
+    /// );
+    ///
+    /// let key_point = Vector::new(100.0, 100.0);
+    /// assert_eq!(
+    ///     coloring_method.interpolate(Vector::new(100.0, 75.0), key_point),
+    ///     LinSrgb::new(0.5f64, 0.5, 0.5),
+    /// );
+    /// ```
+    pub fn new<ColorGradient>(
+        gradient: ColorGradient,
+        segments: Vec<Segment>,
+        maximum_distance: f64,
+    ) -> Self
+    where
+        ColorGradient: Into<Gradient<Color>>,
+    {
+        Self {
+            gradient: gradient.into(),
+            segments,
+            maximum_distance: maximum_distance.max(f64::EPSILON),
+        }
+    }
+
+    /// Line segments distance to which defines color of pixel.
+    pub fn segments(&self) -> &Vec<Segment> {
+        &self.segments
+    }
+
+    /// Sets line segments distance to which defines color of pixel.
+    pub fn set_segments(&mut self, segments: Vec<Segment>) {
+        self.segments = segments;
+    }
+
+    /// Distance from line segments at which gradient reaches its end.
+    pub fn maximum_distance(&self) -> f64 {
+        self.maximum_distance
+    }
+
+    /// Sets distance from line segments at which gradient reaches its end.
+    pub fn set_maximum_distance(&mut self, maximum_distance: f64) {
+        self.maximum_distance = maximum_distance.max(f64::EPSILON);
+    }
+}
+
+impl<Color> ColoringMethod<Color> for SegmentDistanceColoring<Color>
+where
+    Color: Mix<Scalar = f64> + Clone,
+{
+    fn interpolate(&self, point: Vector, _key_point: Vector) -> Color {
+        let distance = self
+            .segments
+            .iter()
+            .map(|segment| segment.distance_to(point))
+            .fold(f64::INFINITY, f64::min);
+        self.gradient
+            .get((distance / self.maximum_distance).min(1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::tests, *};
+
+    fn create_segments() -> Vec<Segment> {
+        vec![
+            Segment::from(((0.0, 0.0), (200.0, 200.0))),
+            Segment::from(((0.0, 200.0), (200.0, 0.0))),
+        ]
+    }
+
+    #[test]
+    fn interpolate_on_segment() {
+        let gradient = tests::create_lch_gradient();
+        let coloring_method =
+            SegmentDistanceColoring::new(gradient.clone(), create_segments(), 50.0);
+        let key_point = Vector::new(100.0, 100.0);
+        for point in [(25.0, 25.0), (100.0, 100.0), (150.0, 50.0)] {
+            assert_eq!(
+                coloring_method.interpolate(point.into(), key_point),
+                gradient.get(0.0)
+            );
+        }
+    }
+    #[test]
+    fn interpolate_far_from_segments() {
+        let gradient = tests::create_rgb_gradient();
+        let coloring_method =
+            SegmentDistanceColoring::new(gradient.clone(), create_segments(), 50.0);
+        let key_point = Vector::new(100.0, 100.0);
+        assert_eq!(
+            coloring_method.interpolate(Vector::new(100.0, 0.0), key_point),
+            gradient.get(1.0)
+        );
+        assert_eq!(
+            coloring_method.interpolate(Vector::new(10.0, 0.0), key_point),
+            gradient.get(50.0f64.sqrt() / 50.0)
+        );
+    }
+    #[test]
+    fn interpolate_without_segments() {
+        let gradient = tests::create_hsl_gradient();
+        let coloring_method = SegmentDistanceColoring::new(gradient.clone(), vec![], 50.0);
+        assert_eq!(
+            coloring_method.interpolate(Vector::new(10.0, 0.0), Vector::new(0.0, 0.0)),
+            gradient.get(1.0)
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use palette::Mix;
+
+use super::{super::utility, ColoringMethod, Vector};
+
+/// Defines stripes (hatching) for painting mosaic images.
+///
+/// Stripes alternate two colors along given direction, so every stripe is perpendicular
+/// to this direction.
+#[derive(Clone, Debug)]
+pub struct Stripes<Color>
+where
+    Color: Mix<Scalar = f64> + Clone,
+{
+    primary: Color,
+    secondary: Color,
+    direction: Vector,
+    period: f64,
+    smoothness: f64,
+}
+
+impl<Color> Stripes<Color>
+where
+    Color: Mix<Scalar = f64> + Clone,
+{
+    /// Creates stripes alternating along given direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary`: color of stripes with even index (including stripe which starts at origin).
+    /// * `secondary`: color of stripes with odd index.
+    /// * `direction`: direction along which stripes alternate.
+    /// * `period`: width of every stripe, in pixels; should be positive.
+    /// * `smoothness`: smoothness of stripes ranging from 0.0 to 1.0;
+    ///   see [`Stripes::smoothness`] for more information.
+    ///
+    /// returns: [`Stripes<Color>`] - stripes along given direction; if direction is zero vector
+    /// returns vertical stripes (alternating along X axis).
+    ///
+    /// # Examples
+    ///
+    /// Next example creates horizontal stripes with width of 10 pixels.
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{coloring_method::{ColoringMethod, Stripes}, Vector};
+    ///
+    /// let primary = LinSrgb::new(1.0f64, 1.0, 1.0);
+    /// let secondary = LinSrgb::new(0.0f64, 0.0, 0.0);
+    /// let stripes = Stripes::new(primary, secondary, Vector::new(0.0, 1.0), 10.0, 1.0);
+    ///
+    /// let key_point = Vector::new(50.0, 50.0);
+    /// assert_eq!(stripes.interpolate(Vector::new(20.0, 5.0), key_point), primary);
+    /// assert_eq!(stripes.interpolate(Vector::new(20.0, 15.0), key_point), secondary);
+    /// ```
+    pub fn new(
+        primary: Color,
+        secondary: Color,
+        direction: Vector,
+        period: f64,
+        smoothness: f64,
+    ) -> Self {
+        Self {
+            primary,
+            secondary,
+            direction: Self::normalize_direction(direction),
+            period: period.max(f64::EPSILON),
+            smoothness: smoothness.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Color of stripes with even index.
+    pub fn primary(&self) -> &Color {
+        &self.primary
+    }
+
+    /// Sets color of stripes with even index.
+    pub fn set_primary(&mut self, primary: Color) {
+        self.primary = primary;
+    }
+
+    /// Color of stripes with odd index.
+    pub fn secondary(&self) -> &Color {
+        &self.secondary
+    }
+
+    /// Sets color of stripes with odd index.
+    pub fn set_secondary(&mut self, secondary: Color) {
+        self.secondary = secondary;
+    }
+
+    /// Normalized direction along which stripes alternate.
+    pub fn direction(&self) -> Vector {
+        self.direction
+    }
+
+    /// Sets direction along which stripes alternate.
+    pub fn set_direction(&mut self, direction: Vector) {
+        self.direction = Self::normalize_direction(direction);
+    }
+
+    /// Width of every stripe, in pixels.
+    pub fn period(&self) -> f64 {
+        self.period
+    }
+
+    /// Sets width of every stripe, in pixels.
+    pub fn set_period(&mut self, period: f64) {
+        self.period = period.max(f64::EPSILON);
+    }
+
+    /// Smoothness of stripes ranging from 0.0 to 1.0.
+    ///
+    /// Completely smooth stripes (with `smoothness` = 1.0) *ignore* pattern of mosaic.
+    ///
+    /// In contrast, step stripes (with `smoothness` = 0.0) choose color of whole mosaic
+    /// fragment by position of its key point.
+    pub fn smoothness(&self) -> f64 {
+        self.smoothness
+    }
+
+    /// Sets smoothness of stripes (ranging from 0.0 to 1.0).
+    pub fn set_smoothness(&mut self, smoothness: f64) {
+        self.smoothness = smoothness.clamp(0.0, 1.0);
+    }
+
+    fn normalize_direction(direction: Vector) -> Vector {
+        if utility::approx_eq(direction.squared_length(), 0.0) {
+            Vector::new(1.0, 0.0)
+        } else {
+            direction.get_normalized()
+        }
+    }
+}
+
+impl<Color> ColoringMethod<Color> for Stripes<Color>
+where
+    Color: Mix<Scalar = f64> + Clone,
+{
+    fn interpolate(&self, point: Vector, key_point: Vector) -> Color {
+        let smoothed_point = key_point.interpolate(point, self.smoothness);
+        let stripe_index = (smoothed_point.dot(self.direction) / self.period).floor();
+        if stripe_index.rem_euclid(2.0) < 1.0 {
+            self.primary.clone()
+        } else {
+            self.secondary.clone()
+        }
+    }
+
+    #[inline(always)]
+    fn is_uniform_per_cell(&self) -> bool {
+        self.smoothness == 0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use palette::LinSrgb;
+
+    use super::*;
+
+    fn create_colors() -> (LinSrgb<f64>, LinSrgb<f64>) {
+        (
+            LinSrgb::new(1.0f64, 1.0, 1.0),
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn new_with_zero_direction() {
+        let (primary, secondary) = create_colors();
+        let stripes = Stripes::new(primary, secondary, Vector::default(), 10.0, 1.0);
+        assert_eq!(stripes.direction(), Vector::new(1.0, 0.0));
+    }
+    #[test]
+    fn interpolate_horizontal() {
+        let (primary, secondary) = create_colors();
+        let stripes = Stripes::new(primary, secondary, Vector::new(0.0, 3.0), 10.0, 1.0);
+        let key_point = Vector::new(0.0, 0.0);
+        for y in 0..60 {
+            let expected_color = if (y / 10) % 2 == 0 {
+                primary
+            } else {
+                secondary
+            };
+            for x in [-40.0, 0.0, 75.0] {
+                let point = Vector::new(x, y as f64 + 0.5);
+                assert_eq!(stripes.interpolate(point, key_point), expected_color);
+            }
+        }
+    }
+    #[test]
+    fn interpolate_diagonal() {
+        let (primary, secondary) = create_colors();
+        let period = 10.0;
+        let stripes = Stripes::new(primary, secondary, Vector::new(1.0, 1.0), period, 1.0);
+        let key_point = Vector::new(0.0, 0.0);
+        let direction = Vector::new(1.0, 1.0).get_normalized();
+        let normal = Vector::new(-1.0, 1.0).get_normalized();
+        for index in 0..6 {
+            let expected_color = if index % 2 == 0 { primary } else { secondary };
+            let center = direction * (period * (index as f64 + 0.5));
+            for shift in [-30.0, 0.0, 30.0] {
+                let point = center + normal * shift;
+                assert_eq!(stripes.interpolate(point, key_point), expected_color);
+            }
+        }
+    }
+    #[test]
+    fn interpolate_step() {
+        let (primary, secondary) = create_colors();
+        let stripes = Stripes::new(primary, secondary, Vector::new(0.0, 1.0), 10.0, 0.0);
+        let key_point = Vector::new(0.0, 15.0);
+        assert_eq!(
+            stripes.interpolate(Vector::new(0.0, 5.0), key_point),
+            secondary
+        );
+    }
+}
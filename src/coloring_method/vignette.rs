@@ -0,0 +1,194 @@
+use palette::{FromColor, IntoColor, LinSrgb, Mix, Shade};
+
+use super::{ColoringMethod, Vector};
+
+/// Darkens mosaic image painted with another coloring method towards its edges.
+///
+/// By default darkening is applied in linear color space, so falloff of vignette
+/// is physically plausible regardless of color space of wrapped coloring method.
+#[derive(Clone, Debug)]
+pub struct Vignette<Method> {
+    coloring_method: Method,
+    center: Vector,
+    radius: f64,
+    strength: f64,
+    linear: bool,
+}
+
+impl<Method> Vignette<Method> {
+    /// Creates vignette around given point which darkens colors of another coloring method.
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: coloring method which colors are darkened.
+    /// * `center`: center point of vignette; colors at this point are not darkened.
+    /// * `radius`: distance from center at which darkening reaches its maximum; should be positive.
+    /// * `strength`: maximum darkening ranging from 0.0 (no darkening) to 1.0 (black edges).
+    ///
+    /// returns: [`Vignette<Method>`] - vignette which darkens colors in linear color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{coloring_method::{ColoringMethod, Vignette}, Vector};
+    ///
+    /// let vignette = Vignette::new(
+    ///     LinSrgb::new(1.0f64, 1.0, 1.0),
+    ///     Vector::new(100.0, 100.0),
+    ///     100.0,
+    ///     0.5,
+    /// );
+    ///
+    /// let key_point = Vector::new(100.0, 100.0);
+    /// assert_eq!(
+    ///     vignette.interpolate(Vector::new(100.0, 100.0), key_point),
+    ///     LinSrgb::new(1.0f64, 1.0, 1.0)
+    /// );
+    /// assert_eq!(
+    ///     vignette.interpolate(Vector::new(200.0, 100.0), key_point),
+    ///     LinSrgb::new(0.5f64, 0.5, 0.5)
+    /// );
+    /// ```
+    pub fn new(coloring_method: Method, center: Vector, radius: f64, strength: f64) -> Self {
+        Self {
+            coloring_method,
+            center,
+            radius: radius.max(f64::EPSILON),
+            strength: strength.clamp(0.0, 1.0),
+            linear: true,
+        }
+    }
+
+    /// Coloring method which colors are darkened by vignette.
+    pub fn coloring_method(&self) -> &Method {
+        &self.coloring_method
+    }
+
+    /// Center point of vignette.
+    pub fn center(&self) -> Vector {
+        self.center
+    }
+
+    /// Sets center point of vignette.
+    pub fn set_center(&mut self, center: Vector) {
+        self.center = center;
+    }
+
+    /// Distance from center at which darkening of vignette reaches its maximum.
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Sets distance from center at which darkening of vignette reaches its maximum.
+    pub fn set_radius(&mut self, radius: f64) {
+        self.radius = radius.max(f64::EPSILON);
+    }
+
+    /// Maximum darkening of vignette ranging from 0.0 to 1.0.
+    pub fn strength(&self) -> f64 {
+        self.strength
+    }
+
+    /// Sets maximum darkening of vignette (ranging from 0.0 to 1.0).
+    pub fn set_strength(&mut self, strength: f64) {
+        self.strength = strength.clamp(0.0, 1.0);
+    }
+
+    /// Whether vignette darkens colors in linear color space.
+    ///
+    /// If `false` colors are darkened in their own color space using [`Shade::darken`],
+    /// which is faster but makes darkening inconsistent across color spaces.
+    pub fn is_linear(&self) -> bool {
+        self.linear
+    }
+
+    /// Sets whether vignette darkens colors in linear color space.
+    pub fn set_linear(&mut self, linear: bool) {
+        self.linear = linear;
+    }
+
+    fn calculate_darkening(&self, point: Vector) -> f64 {
+        let distance_ratio = (point.distance_to(self.center) / self.radius).min(1.0);
+        self.strength * distance_ratio * distance_ratio
+    }
+
+    fn apply<Color>(&self, color: Color, point: Vector) -> Color
+    where
+        Color: IntoColor<LinSrgb<f64>> + FromColor<LinSrgb<f64>> + Shade<Scalar = f64>,
+    {
+        let darkening = self.calculate_darkening(point);
+        if self.linear {
+            let linear_color: LinSrgb<f64> = color.into_color();
+            Color::from_color(linear_color * (1.0 - darkening))
+        } else {
+            color.darken(darkening)
+        }
+    }
+}
+
+impl<Color, Method> ColoringMethod<Color> for Vignette<Method>
+where
+    Color: IntoColor<LinSrgb<f64>>
+        + FromColor<LinSrgb<f64>>
+        + Mix<Scalar = f64>
+        + Shade<Scalar = f64>
+        + Clone,
+    Method: ColoringMethod<Color>,
+{
+    fn interpolate(&self, point: Vector, key_point: Vector) -> Color {
+        let color = self.coloring_method.interpolate(point, key_point);
+        self.apply(color, point)
+    }
+
+    fn interpolate_indexed(&self, point: Vector, key_point: Vector, cell_index: usize) -> Color {
+        let color = self
+            .coloring_method
+            .interpolate_indexed(point, key_point, cell_index);
+        self.apply(color, point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use palette::{white_point::D65, Lch};
+
+    use super::*;
+
+    #[test]
+    fn set_strength() {
+        let mut vignette = Vignette::new(
+            LinSrgb::new(0.5f64, 0.5, 0.5),
+            Vector::new(100.0, 100.0),
+            100.0,
+            0.5,
+        );
+        vignette.set_strength(2.0);
+        assert_eq!(vignette.strength(), 1.0);
+        vignette.set_strength(-1.0);
+        assert_eq!(vignette.strength(), 0.0);
+    }
+    #[test]
+    fn interpolate_at_center() {
+        let color: Lch<D65, f64> = Lch::new(50.0, 0.0, 0.0);
+        let vignette = Vignette::new(color, Vector::new(100.0, 100.0), 100.0, 0.75);
+        let center = vignette.center();
+        let center_color: Lch<D65, f64> = vignette.interpolate(center, center);
+        assert!((center_color.l - color.l).abs() < 1e-9);
+    }
+    #[test]
+    fn interpolate_linear_and_naive() {
+        let color: Lch<D65, f64> = Lch::new(50.0, 0.0, 0.0);
+        let mut vignette = Vignette::new(color, Vector::new(100.0, 100.0), 100.0, 0.75);
+        let point = Vector::new(150.0, 100.0);
+        let darkening = 0.75 * 0.5 * 0.5;
+        let linear_color: Lch<D65, f64> = vignette.interpolate(point, point);
+        let manual_color: LinSrgb<f64> = color.into_color();
+        let manual_color = Lch::from_color(manual_color * (1.0 - darkening));
+        assert!((linear_color.l - manual_color.l).abs() < 1e-9);
+        vignette.set_linear(false);
+        let naive_color: Lch<D65, f64> = vignette.interpolate(point, point);
+        assert!((naive_color.l - 50.0 * (1.0 - darkening)).abs() < 1e-9);
+        assert!((linear_color.l - naive_color.l).abs() > 1.0);
+    }
+}
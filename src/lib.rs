@@ -13,7 +13,8 @@
 //!
 //! In code whole process looks like this:
 //!
-//! ```rust
+#![cfg_attr(feature = "rendering", doc = "```rust")]
+#![cfg_attr(not(feature = "rendering"), doc = "```ignore")]
 //! use palette::LinSrgb;
 //! use starry_mosaic::{Mosaic, MosaicBuilder, Vector};
 //!
@@ -39,31 +40,56 @@
 //! let save_result = polygonal_mosaic_image.save("target/polygonal_mosaic_image.png");
 //! assert!(save_result.is_ok());
 //! ```
+//!
+//! # Features
+//!
+//! Rendering of mosaics (and dependencies it requires) is enabled by default feature
+//! `rendering`. Without this feature (with `default-features = false`) only geometry core
+//! of this crate is compiled: [`Vector`], [`Segment`], [`Aabb`], [`transform`] and
+//! [`mosaic_shape`] (except shapes which read images).
 
+#[cfg(feature = "rendering")]
+mod rasterization;
+#[cfg_attr(not(feature = "rendering"), allow(dead_code))]
 mod utility;
 
 mod vector;
-pub use self::vector::Vector;
+pub use self::vector::{Vector, VectorKey};
 
 mod segment;
 pub use self::segment::Segment;
 
+mod aabb;
+pub use self::aabb::Aabb;
+
 pub mod transform;
 
+#[cfg(feature = "rendering")]
 pub mod coloring_method;
 
+#[cfg(feature = "rendering")]
+pub mod sampling;
+
 pub mod mosaic_shape;
 
+#[cfg(feature = "rendering")]
 mod mosaic;
-pub use self::mosaic::Mosaic;
 #[cfg(feature = "mosaic_with_preset_coloring")]
 pub use self::mosaic::MosaicWithPresetColoring;
+#[cfg(feature = "rendering")]
+pub use self::mosaic::{Mosaic, Shading};
 
+#[cfg(feature = "rendering")]
 mod mosaic_builder;
-pub use self::mosaic_builder::MosaicBuilder;
+#[cfg(feature = "rendering")]
+pub use self::mosaic_builder::{BuildSettings, MosaicBuildError, MosaicBuilder};
 
+#[cfg(feature = "rendering")]
 mod polygonal_mosaic;
+#[cfg(feature = "rendering")]
 pub use self::polygonal_mosaic::PolygonalMosaic;
 
+#[cfg(feature = "rendering")]
 mod starry_mosaic;
-pub use self::starry_mosaic::StarryMosaic;
+#[cfg(feature = "rendering")]
+pub use self::starry_mosaic::{DistanceMetric, RenderStats, StarryMosaic};
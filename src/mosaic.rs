@@ -1,9 +1,13 @@
-use image::RgbImage;
-use palette::{IntoColor, LinSrgb, Mix, Shade};
+use std::{cmp::Ordering, f64::consts};
+
+use image::{GrayImage, Luma, Rgb, RgbImage};
+use palette::{Gradient, IntoColor, LinSrgb, Mix, Pixel, Shade};
 
 use super::{
     coloring_method::*,
+    mosaic_builder::{BuildSettings, MosaicBuilder},
     mosaic_shape::MosaicShape,
+    sampling::{self, Averaging},
     transform::{Transformation, TryToTransform},
     vector::Vector,
 };
@@ -149,10 +153,16 @@ pub trait Mosaic: TryToTransform {
     /// This method transforms abstract [mosaic shape][`MosaicShape`] (with its key points)
     /// to concrete pixels using given coloring method.
     ///
+    /// Colors are mixed in linear color space ([`LinSrgb`]) and their components are written
+    /// into image as is, without gamma encoding: component 0.5 is drawn as 128, and color
+    /// specified by 8-bit components is drawn exactly with these components. So values of pixels
+    /// can be averaged directly (see [`sampling`]); to get gamma encoded sRGB values use
+    /// [`sampling::linear_to_srgb8`].
+    ///
     /// # Arguments
     ///
     /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
-    /// of mosaic shape in image.
+    ///   of mosaic shape in image.
     ///
     /// returns: `RgbImage` - painted mosaic image containing mosaic shape (pattern).
     ///
@@ -165,6 +175,228 @@ pub trait Mosaic: TryToTransform {
         Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
         Method: ColoringMethod<Color>;
 
+    /// Creates mosaic image painted with specified coloring method as raw RGBA bytes.
+    ///
+    /// Pixels are colored exactly like [`Mosaic::draw`] does; every pixel is fully opaque.
+    /// Bytes are tightly packed row by row, so they can be passed directly to APIs like
+    /// `putImageData` of HTML canvas (e.g. when mosaic is drawn in WebAssembly).
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image.
+    ///
+    /// returns: `(u32, u32, Vec<u8>)` - width and height of mosaic image and its pixels
+    /// as `width * height * 4` bytes in RGBA order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{Mosaic, MosaicBuilder};
+    ///
+    /// let starry_mosaic = MosaicBuilder::default().build_star().unwrap();
+    /// let (width, height, bytes) = starry_mosaic.draw_raw(LinSrgb::new(1.0f64, 0.5, 0.0));
+    ///
+    /// assert_eq!(bytes.len(), (width * height * 4) as usize);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    ///
+    fn draw_raw<Color, Method>(&self, coloring_method: Method) -> (u32, u32, Vec<u8>)
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+    {
+        let mosaic_image = self.draw(coloring_method);
+        let (width, height) = mosaic_image.dimensions();
+        let bytes = mosaic_image
+            .pixels()
+            .flat_map(|pixel| [pixel[0], pixel[1], pixel[2], u8::MAX])
+            .collect();
+        (width, height, bytes)
+    }
+
+    /// Creates mosaic image painted with specified coloring method and limited to fixed palette.
+    ///
+    /// Every pixel of mosaic image is replaced with nearest (by Euclidean distance between
+    /// components written into image) color of palette; colors of palette are written into image
+    /// like colors drawn by [`Mosaic::draw`]. This gives limited-palette (indexed color) look
+    /// suitable for GIF images or retro styles.
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image.
+    /// * `palette`: colors allowed in mosaic image; if palette is empty,
+    ///   mosaic image is not quantized.
+    ///
+    /// returns: `RgbImage` - painted mosaic image which contains colors of palette only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use image::Rgb;
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{Mosaic, MosaicBuilder};
+    ///
+    /// let starry_mosaic = MosaicBuilder::default().build_star().unwrap();
+    /// let palette = [LinSrgb::new(1.0f64, 0.0, 0.0), LinSrgb::new(0.0f64, 0.0, 1.0)];
+    /// let mosaic_image = starry_mosaic.draw_quantized(LinSrgb::new(0.8f64, 0.1, 0.3), &palette);
+    ///
+    /// assert!(mosaic_image
+    ///     .pixels()
+    ///     .all(|pixel| *pixel == Rgb([255, 0, 0]) || *pixel == Rgb([0, 0, 255])));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    ///
+    fn draw_quantized<Color, Method>(
+        &self,
+        coloring_method: Method,
+        palette: &[LinSrgb<f64>],
+    ) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+    {
+        let mut mosaic_image = self.draw(coloring_method);
+        if palette.is_empty() {
+            return mosaic_image;
+        }
+        let palette_pixels: Vec<Rgb<u8>> = palette
+            .iter()
+            .map(|&color| Rgb(color.into_format().into_raw()))
+            .collect();
+        for pixel in mosaic_image.pixels_mut() {
+            let distance = |palette_pixel: &&Rgb<u8>| {
+                (0..3)
+                    .map(|channel| (palette_pixel[channel] as i32 - pixel[channel] as i32).pow(2))
+                    .sum::<i32>()
+            };
+            *pixel = *palette_pixels.iter().min_by_key(distance).unwrap();
+        }
+        mosaic_image
+    }
+
+    /// Creates grayscale heightmap of mosaic.
+    ///
+    /// Value of every pixel of heightmap encodes lightening of this pixel in mosaic image
+    /// (which depends on distance to center of mosaic fragment) ignoring its color.
+    /// Such heightmap can drive displacement (relief) maps.
+    ///
+    /// Note that heightmap follows shading of mosaic, so mosaic with flat shading creates
+    /// black heightmap.
+    ///
+    /// Default implementation reads lightening from red channel of mosaic image painted black,
+    /// so mosaics which dither their images should override it.
+    /// [StarryMosaic][`super::StarryMosaic`] and [PolygonalMosaic][`super::PolygonalMosaic`]
+    /// compute heights of pixels directly.
+    ///
+    /// returns: `GrayImage` - heightmap of mosaic with values from 0 (lowest) to 255 (highest).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{Mosaic, MosaicBuilder};
+    ///
+    /// let starry_mosaic = MosaicBuilder::default().build_star().unwrap();
+    /// let heightmap = starry_mosaic.draw_heightmap();
+    ///
+    /// assert_eq!(heightmap.dimensions(), starry_mosaic.image_size());
+    /// ```
+    fn draw_heightmap(&self) -> GrayImage {
+        let mosaic_image = self.draw(LinSrgb::new(0.0f64, 0.0, 0.0));
+        GrayImage::from_fn(mosaic_image.width(), mosaic_image.height(), |x, y| {
+            Luma([mosaic_image.get_pixel(x, y)[0]])
+        })
+    }
+
+    /// Creates thumbnail of mosaic image painted with specified coloring method.
+    ///
+    /// Mosaic image is drawn at full size of mosaic and then downsampled (with averaging
+    /// of pixels in linear color space) so its longest side equals `max_dimension`.
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image.
+    /// * `max_dimension`: size of longest side of thumbnail; should be at least 1.
+    ///
+    /// returns: `RgbImage` - thumbnail of painted mosaic image with aspect ratio of mosaic;
+    /// mosaic smaller than `max_dimension` is drawn at its own size.
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    /// * [`sampling::downsample_to_fit`].
+    ///
+    fn draw_thumbnail<Color, Method>(&self, coloring_method: Method, max_dimension: u32) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+        Self: Sized,
+    {
+        let mosaic_image = self.draw(coloring_method);
+        sampling::downsample_to_fit(&mosaic_image, max_dimension, Averaging::Linear)
+    }
+
+    /// Creates mosaic image painted with conic gradient which colors are cycled by phase.
+    ///
+    /// Conic gradient is drawn around [center][`Mosaic::center`] of mosaic and its color stops
+    /// are rotated by `phase` (as fraction of full turn). Drawing of series of images with
+    /// increasing phase creates looping animation of colors which reuses already built mosaic.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_gradient`: list of colors or colors stops of gradient; gradient should start
+    ///   and end with same color to make animation seamless.
+    /// * `phase`: phase of color cycle ranging from 0.0 to 1.0; phases which differ
+    ///   by whole number create same images.
+    ///
+    /// returns: `RgbImage` - painted mosaic image containing mosaic shape (pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{Mosaic, MosaicBuilder};
+    ///
+    /// let starry_mosaic = MosaicBuilder::default().build_star().unwrap();
+    /// let gradient = vec![
+    ///     (0.0, LinSrgb::new(1.0f64, 0.0, 0.0)),
+    ///     (0.5, LinSrgb::new(0.0f64, 0.0, 1.0)),
+    ///     (1.0, LinSrgb::new(1.0f64, 0.0, 0.0)),
+    /// ];
+    ///
+    /// let frames: Vec<_> = (0..4)
+    ///     .map(|frame| starry_mosaic.draw_color_cycled(gradient.clone(), frame as f64 / 4.0))
+    ///     .collect();
+    ///
+    /// assert_ne!(frames[0], frames[1]);
+    /// ```
+    fn draw_color_cycled<Color, ColorGradient>(
+        &self,
+        base_gradient: ColorGradient,
+        phase: f64,
+    ) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        ColorGradient: Into<Gradient<Color>>,
+        Self: Sized,
+    {
+        let angle = phase.rem_euclid(1.0) * consts::TAU;
+        self.draw(ConicGradient::new_smooth(
+            base_gradient,
+            self.center(),
+            angle,
+        ))
+    }
+
     /// Width and height of mosaic and mosaic image it creates.
     fn image_size(&self) -> (u32, u32);
 
@@ -178,11 +410,127 @@ pub trait Mosaic: TryToTransform {
     }
 
     /// Shape (pattern) of mosaic.
+    #[allow(clippy::borrowed_box)]
     fn shape(&self) -> &Box<dyn MosaicShape>;
+
+    /// Settings with which key points of mosaic are constructed (besides its shape, size
+    /// and transformation).
+    ///
+    /// These settings are restored by [builder created from mosaic][`MosaicBuilder::from`],
+    /// so mosaic is transformed (and its key points are reconstructed) the same way
+    /// it was built.
+    ///
+    /// Default implementation returns default settings of [`MosaicBuilder`]; mosaics built
+    /// with other settings should store and return them.
+    fn build_settings(&self) -> BuildSettings {
+        BuildSettings::default()
+    }
+
+    /// Checks whether given point lies inside convex hull of key points of mosaic.
+    ///
+    /// Default implementation constructs key points of mosaic (with its
+    /// [build settings][`Mosaic::build_settings`]) on every call; mosaics which store
+    /// their key points should override it.
+    ///
+    /// # Arguments
+    ///
+    /// * `point`: point in image space (in pixels).
+    ///
+    /// returns: `bool` - `true` if point lies inside (or on boundary of) convex hull of
+    /// key points of mosaic, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{Mosaic, MosaicBuilder, Vector};
+    ///
+    /// let polygonal_mosaic = MosaicBuilder::default()
+    ///     .set_regular_polygon_shape(6)
+    ///     .set_image_size(400, 400)
+    ///     .set_center(Vector::new(200.0, 200.0))
+    ///     .build_polygon()
+    ///     .unwrap();
+    ///
+    /// assert!(polygonal_mosaic.contains(Vector::new(200.0, 200.0)));
+    /// assert!(!polygonal_mosaic.contains(Vector::new(0.0, 0.0)));
+    /// ```
+    fn contains(&self, point: Vector) -> bool
+    where
+        Self: Sized,
+    {
+        let hull = convex_hull(MosaicBuilder::from(self).key_points());
+        convex_polygon_contains(&hull, point)
+    }
 }
 
-#[cfg(feature = "mosaic_with_preset_coloring")]
-use palette::Gradient;
+/// Converts black color lightened by shading of mosaic to pixel of heightmap.
+///
+/// Lightening of black color gives color which components equal lightening itself, so
+/// lightening becomes height without quantization (and dithering) of mosaic image.
+pub(crate) fn lightened_black_to_height(color: LinSrgb<f64>) -> Luma<u8> {
+    Luma([(color.red * 255.0).round().clamp(0.0, 255.0) as u8])
+}
+
+/// Builds convex hull of points (Andrew's monotone chain algorithm).
+///
+/// Vertices of hull are listed in counter-clockwise order (relative to axes of image space).
+pub(crate) fn convex_hull(mut points: Vec<Vector>) -> Vec<Vector> {
+    points.sort_by(|left, right| left.partial_cmp(right).unwrap_or(Ordering::Equal));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+    let mut hull: Vec<Vector> = Vec::with_capacity(points.len() * 2);
+    for pass in 0..2 {
+        let chain_start = hull.len();
+        let ordered_points: Box<dyn Iterator<Item = &Vector>> = if pass == 0 {
+            Box::new(points.iter())
+        } else {
+            Box::new(points.iter().rev())
+        };
+        for point in ordered_points {
+            while hull.len() >= chain_start + 2
+                && robust::orient2d(
+                    hull[hull.len() - 2].into(),
+                    hull[hull.len() - 1].into(),
+                    (*point).into(),
+                ) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(*point);
+        }
+        hull.pop();
+    }
+    hull
+}
+
+/// Checks whether point lies inside (or on boundary of) convex polygon with vertices
+/// listed in any order of traversal.
+pub(crate) fn convex_polygon_contains(polygon: &[Vector], point: Vector) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+    let (mut has_positive, mut has_negative) = (false, false);
+    for (index, start) in polygon.iter().enumerate() {
+        let end = polygon[(index + 1) % polygon.len()];
+        let orientation = robust::orient2d((*start).into(), end.into(), point.into());
+        has_positive |= orientation > 0.0;
+        has_negative |= orientation < 0.0;
+    }
+    !(has_positive && has_negative)
+}
+
+/// Defines how cells of mosaic are shaded while drawing mosaic image.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Shading {
+    /// Every cell is filled with interpolated colors as is, without any additional lightening.
+    Flat,
+
+    /// Colors of every cell are lightened towards its center, so cell looks like glowing star.
+    #[default]
+    Glow,
+}
 
 /// Provides preset methods to create painted mosaic images.
 ///
@@ -202,6 +550,20 @@ pub trait MosaicWithPresetColoring: Mosaic {
         self.draw(color)
     }
 
+    /// Paints mosaic image using checkerboard pattern.
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    /// * [`Checkerboard::new`].
+    ///
+    fn draw_checkerboard<Color>(&self, primary: Color, secondary: Color, cell_size: f64) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+    {
+        self.draw(Checkerboard::new(primary, secondary, cell_size))
+    }
+
     /// Paints mosaic image using linear gradient.
     ///
     /// # See also
@@ -417,6 +779,27 @@ pub trait MosaicWithPresetColoring: Mosaic {
         self.draw(RadialGradient::new_simple_step(gradient, center, radius))
     }
 
+    /// Paints mosaic image using radial simple gradient centered at
+    /// [center][`Mosaic::center`] of mosaic shape.
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    /// * [`RadialGradient::new_simple`].
+    ///
+    fn draw_radial_simple_gradient_centered<Color, ColorGradient>(
+        &self,
+        gradient: ColorGradient,
+        radius: f64,
+        smoothness: f64,
+    ) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        ColorGradient: Into<Gradient<Color>>,
+    {
+        self.draw_radial_simple_gradient(gradient, self.center(), radius, smoothness)
+    }
+
     /// Paints mosaic image using conic gradient.
     ///
     /// # See also
@@ -477,7 +860,208 @@ pub trait MosaicWithPresetColoring: Mosaic {
     {
         self.draw(ConicGradient::new_step(gradient, center, angle))
     }
+
+    /// Paints mosaic image using conic gradient centered at [center][`Mosaic::center`]
+    /// of mosaic shape.
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    /// * [`ConicGradient::new`].
+    ///
+    fn draw_conic_gradient_centered<Color, ColorGradient>(
+        &self,
+        gradient: ColorGradient,
+        angle: f64,
+        smoothness: f64,
+    ) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        ColorGradient: Into<Gradient<Color>>,
+    {
+        self.draw_conic_gradient(gradient, self.center(), angle, smoothness)
+    }
 }
 
 #[cfg(feature = "mosaic_with_preset_coloring")]
 impl<MosaicImage> MosaicWithPresetColoring for MosaicImage where MosaicImage: Mosaic {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convex_hull() {
+        let points = vec![
+            Vector::new(0.0, 0.0),
+            Vector::new(4.0, 0.0),
+            Vector::new(2.0, 1.0),
+            Vector::new(4.0, 4.0),
+            Vector::new(2.0, 0.0),
+            Vector::new(0.0, 4.0),
+            Vector::new(1.0, 3.0),
+        ];
+        let hull = super::convex_hull(points);
+        assert_eq!(hull.len(), 4);
+        for corner in [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)] {
+            assert!(hull.contains(&corner.into()));
+        }
+    }
+    #[test]
+    fn draw_quantized() {
+        let mosaic = MosaicBuilder::default().build_star().unwrap();
+        let gradient = LinearGradient::new(
+            vec![
+                (0.0, LinSrgb::new(0.0f64, 0.0, 0.0)),
+                (1.0, LinSrgb::new(1.0f64, 1.0, 1.0)),
+            ],
+            Vector::new(0.0, 0.0),
+            Vector::new(640.0, 640.0),
+            1.0,
+        );
+        let palette = [
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0f64, 1.0, 1.0),
+        ];
+        let mosaic_image = mosaic.draw_quantized(gradient, &palette);
+        assert!(mosaic_image
+            .pixels()
+            .all(|pixel| *pixel == Rgb([0, 0, 0]) || *pixel == Rgb([255, 255, 255])));
+        assert!(mosaic_image.pixels().any(|pixel| *pixel == Rgb([0, 0, 0])));
+        assert!(mosaic_image
+            .pixels()
+            .any(|pixel| *pixel == Rgb([255, 255, 255])));
+    }
+    #[test]
+    fn draw_quantized_mid_grey() {
+        let mut mosaic = MosaicBuilder::default().build_star().unwrap();
+        mosaic.set_shading(Shading::Flat);
+        let grey = LinSrgb::new(0.5f64, 0.5, 0.5);
+        let palette = [
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            grey,
+            LinSrgb::new(1.0f64, 1.0, 1.0),
+        ];
+        let mosaic_image = mosaic.draw_quantized(grey, &palette);
+        assert!(mosaic_image
+            .pixels()
+            .all(|pixel| *pixel == Rgb([128, 128, 128])));
+        assert_eq!(mosaic_image, mosaic.draw(grey));
+    }
+    #[test]
+    fn draw_thumbnail() {
+        let mosaic = MosaicBuilder::default()
+            .set_image_size(1600, 800)
+            .set_relative_center(0.5, 0.5)
+            .build_star()
+            .unwrap();
+        let color = LinSrgb::new(1.0f64, 0.5, 0.0);
+        let thumbnail = mosaic.draw_thumbnail(color, 200);
+        assert_eq!(thumbnail.dimensions(), (200, 100));
+        let mut small_mosaic = MosaicBuilder::from(&mosaic)
+            .set_image_size(200, 100)
+            .set_relative_center(0.5, 0.5)
+            .build_star()
+            .unwrap();
+        let small_image = small_mosaic.draw(color);
+        let mean_color = |image: &RgbImage| {
+            let mut sum = [0.0; 3];
+            for pixel in image.pixels() {
+                for (channel_sum, channel) in sum.iter_mut().zip(pixel.0) {
+                    *channel_sum += channel as f64;
+                }
+            }
+            sum.map(|channel_sum| channel_sum / (image.width() * image.height()) as f64)
+        };
+        for (thumbnail_channel, small_channel) in mean_color(&thumbnail)
+            .into_iter()
+            .zip(mean_color(&small_image))
+        {
+            assert!((thumbnail_channel - small_channel).abs() < 0.5);
+        }
+        let mut flat_mosaic = mosaic;
+        flat_mosaic.set_shading(Shading::Flat);
+        small_mosaic.set_shading(Shading::Flat);
+        assert_eq!(
+            flat_mosaic.draw_thumbnail(color, 200),
+            small_mosaic.draw(color)
+        );
+    }
+    #[test]
+    fn draw_color_cycled() {
+        let mosaic = MosaicBuilder::default()
+            .set_image_size(200, 200)
+            .set_relative_center(0.5, 0.5)
+            .build_polygon()
+            .unwrap();
+        let gradient = vec![
+            (0.0, LinSrgb::new(1.0f64, 0.0, 0.0)),
+            (0.3, LinSrgb::new(0.0f64, 1.0, 0.0)),
+            (0.6, LinSrgb::new(0.0f64, 0.0, 1.0)),
+            (1.0, LinSrgb::new(1.0f64, 0.0, 0.0)),
+        ];
+        let first_image = mosaic.draw_color_cycled(gradient.clone(), 0.0);
+        assert_eq!(mosaic.draw_color_cycled(gradient.clone(), 1.0), first_image);
+        assert_eq!(
+            mosaic.draw_color_cycled(gradient.clone(), -2.0),
+            first_image
+        );
+        for phase in [0.25, 0.5, 0.75] {
+            assert_ne!(
+                mosaic.draw_color_cycled(gradient.clone(), phase),
+                first_image
+            );
+        }
+    }
+    #[cfg(feature = "mosaic_with_preset_coloring")]
+    #[test]
+    fn draw_gradients_centered() {
+        let mosaic = MosaicBuilder::default()
+            .set_image_size(200, 200)
+            .set_center(Vector::new(80.0, 120.0))
+            .build_star()
+            .unwrap();
+        let gradient = Gradient::new(vec![
+            LinSrgb::new(1.0f64, 0.0, 0.0),
+            LinSrgb::new(0.0f64, 0.0, 1.0),
+        ]);
+        assert_eq!(
+            mosaic.draw_conic_gradient_centered(gradient.clone(), 0.5, 0.5),
+            mosaic.draw_conic_gradient(gradient.clone(), mosaic.center(), 0.5, 0.5)
+        );
+        assert_eq!(
+            mosaic.draw_radial_simple_gradient_centered(gradient.clone(), 100.0, 0.5),
+            mosaic.draw_radial_simple_gradient(gradient, mosaic.center(), 100.0, 0.5)
+        );
+    }
+    #[test]
+    fn convex_polygon_contains() {
+        let polygon = [
+            Vector::new(0.0, 0.0),
+            Vector::new(4.0, 0.0),
+            Vector::new(4.0, 4.0),
+            Vector::new(0.0, 4.0),
+        ];
+        assert!(super::convex_polygon_contains(
+            &polygon,
+            Vector::new(2.0, 3.0)
+        ));
+        assert!(super::convex_polygon_contains(
+            &polygon,
+            Vector::new(4.0, 2.0)
+        ));
+        assert!(!super::convex_polygon_contains(
+            &polygon,
+            Vector::new(5.0, 2.0)
+        ));
+        let reversed_polygon: Vec<Vector> = polygon.iter().rev().copied().collect();
+        assert!(super::convex_polygon_contains(
+            &reversed_polygon,
+            Vector::new(1.0, 1.0)
+        ));
+        assert!(!super::convex_polygon_contains(
+            &polygon[..2],
+            Vector::new(1.0, 0.0)
+        ));
+    }
+}
@@ -1,13 +1,25 @@
-use std::cmp::Ordering;
+use std::{
+    collections::HashMap,
+    error::Error,
+    f64::consts,
+    fmt::{Display, Formatter, Result as FormatResult},
+};
 
+use image::{Rgb, RgbImage};
+use palette::{IntoColor, LinSrgb, Mix, Pixel, Shade};
+use rayon::prelude::*;
 use voronoice::{BoundingBox, Point, Voronoi, VoronoiBuilder};
 
 use super::{
+    coloring_method::ColoringMethod,
     mosaic::Mosaic,
     mosaic_shape::*,
     polygonal_mosaic::PolygonalMosaic,
+    rasterization,
+    segment::Segment,
     starry_mosaic::StarryMosaic,
     transform::{Scale, Transform, Transformation},
+    utility,
     vector::Vector,
 };
 
@@ -48,9 +60,34 @@ pub struct MosaicBuilder {
     shape: Box<dyn MosaicShape>,
     image_size: (u32, u32),
     transformation: Transformation,
+    settings: BuildSettings,
 }
 
 impl MosaicBuilder {
+    /// Creates builder configured to create classic starry mosaic.
+    ///
+    /// Builder has shape of [regular polygon][`RegularPolygon`] with 12 corners scaled
+    /// by 0.6 and placed at center of image of default size (640 by 640 pixels). Any property
+    /// of builder can be changed afterwards.
+    ///
+    /// returns: [`MosaicBuilder`] - builder configured to create classic starry mosaic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{Mosaic, MosaicBuilder, Vector};
+    ///
+    /// let starry_mosaic = MosaicBuilder::starry_preset().build_star().unwrap();
+    ///
+    /// assert_eq!(starry_mosaic.center(), Vector::new(320.0, 320.0));
+    /// ```
+    pub fn starry_preset() -> Self {
+        Self::default()
+            .set_regular_polygon_shape(12)
+            .set_relative_center(0.5, 0.5)
+            .set_uniform_scale(0.6)
+    }
+
     /// Sets shape of mosaic to [regular polygon][`RegularPolygon`].
     ///
     /// # Arguments
@@ -106,6 +143,63 @@ impl MosaicBuilder {
         self
     }
 
+    /// Sets shape of mosaic to [flower][`Flower`].
+    ///
+    /// # Arguments
+    ///
+    /// * `petal_corners_count`: number of corners of central polygon and every petal;
+    ///   should be at least 3.
+    /// * `petals_count`: number of petals surrounding central polygon; should be at least 1.
+    /// * `ring_radius_fraction`: radius of ring of petal centers as fraction of radius
+    ///   of mosaic shape; should be in range from 0.0 to 1.0.
+    ///
+    /// returns: [`MosaicBuilder`] - builder with mosaic shape set to flower.
+    ///
+    /// # See Also
+    ///
+    /// * [`MosaicBuilder::set_shape`].
+    /// * [`Flower::new`].
+    ///
+    pub fn set_flower_shape(
+        mut self,
+        petal_corners_count: u32,
+        petals_count: u32,
+        ring_radius_fraction: f64,
+    ) -> Self {
+        self.shape = Box::new(Flower::new(
+            petal_corners_count,
+            petals_count,
+            ring_radius_fraction,
+        ));
+        self
+    }
+
+    /// Sets shape of mosaic to [scattered points][`ScatteredPoints`].
+    ///
+    /// # Arguments
+    ///
+    /// * `count`: maximum number of scattered points; should be at least 1.
+    /// * `minimum_distance`: minimum distance between any two points, in pixels; should be
+    ///   at least 1.0.
+    /// * `seed`: seed of pseudo-random generator of points.
+    ///
+    /// returns: [`MosaicBuilder`] - builder with mosaic shape set to scattered points.
+    ///
+    /// # See Also
+    ///
+    /// * [`MosaicBuilder::set_shape`].
+    /// * [`ScatteredPoints::new`].
+    ///
+    pub fn set_scattered_points_shape(
+        mut self,
+        count: u32,
+        minimum_distance: f64,
+        seed: u64,
+    ) -> Self {
+        self.shape = Box::new(ScatteredPoints::new(count, minimum_distance, seed));
+        self
+    }
+
     /// Sets mosaic shape with which mosaic will be created.
     ///
     /// # Arguments
@@ -122,6 +216,44 @@ impl MosaicBuilder {
         self
     }
 
+    /// Creates copy of this builder with different mosaic shape.
+    ///
+    /// Copy keeps image size, transformation and all other settings of this builder,
+    /// so same layout can be reused to create mosaics with different shapes.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: [mosaic shape][`MosaicShape`] which will be drawn in mosaic image.
+    ///
+    /// returns: [`MosaicBuilder`] - copy of this builder with configured mosaic shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{mosaic_shape::Grid, Mosaic, MosaicBuilder};
+    ///
+    /// let builder = MosaicBuilder::default()
+    ///     .set_image_size(800, 600)
+    ///     .set_relative_center(0.5, 0.5)
+    ///     .set_uniform_scale(0.5);
+    /// let grid_mosaic = builder.with_shape(Grid::new(6, 6)).build_star().unwrap();
+    /// let star_mosaic = builder.build_star().unwrap();
+    ///
+    /// assert_eq!(grid_mosaic.image_size(), star_mosaic.image_size());
+    /// assert_eq!(grid_mosaic.transformation(), star_mosaic.transformation());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::set_shape`].
+    ///
+    pub fn with_shape<Shape>(&self, shape: Shape) -> Self
+    where
+        Shape: 'static + MosaicShape,
+    {
+        self.clone().set_shape(shape)
+    }
+
     /// Sets width and height of mosaic (and mosaic images one creates).
     ///
     /// # Arguments
@@ -141,7 +273,7 @@ impl MosaicBuilder {
     /// # Arguments
     ///
     /// * `center`: position of center of mosaic shape in created mosaic; should be within bounds
-    /// of mosaic.
+    ///   of mosaic.
     ///
     /// returns: [`MosaicBuilder`] - builder with configured center of mosaic shape.
     ///
@@ -157,6 +289,42 @@ impl MosaicBuilder {
         self
     }
 
+    /// Sets center (pivot) point of shape of mosaic relative to image size of mosaic.
+    ///
+    /// Unlike [`MosaicBuilder::set_center`] this method makes configuration of mosaic
+    /// independent of its resolution. Note that image size of mosaic should be set before
+    /// calling this method.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_fraction`: horizontal position of center as fraction of width of mosaic;
+    ///   should be in range from 0.0 to 1.0.
+    /// * `y_fraction`: vertical position of center as fraction of height of mosaic;
+    ///   should be in range from 0.0 to 1.0.
+    ///
+    /// returns: [`MosaicBuilder`] - builder with configured center of mosaic shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{Mosaic, MosaicBuilder, Vector};
+    ///
+    /// let starry_mosaic = MosaicBuilder::default()
+    ///     .set_image_size(1000, 500)
+    ///     .set_relative_center(0.25, 0.5)
+    ///     .build_star()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(starry_mosaic.center(), Vector::new(250.0, 250.0));
+    /// ```
+    pub fn set_relative_center(self, x_fraction: f64, y_fraction: f64) -> Self {
+        let center = Vector::new(
+            x_fraction * self.image_size.0 as f64,
+            y_fraction * self.image_size.1 as f64,
+        );
+        self.set_center(center)
+    }
+
     /// Sets rotation angle of shape of mosaic.
     ///
     /// # Arguments
@@ -174,14 +342,43 @@ impl MosaicBuilder {
         self
     }
 
+    /// Sets rotation angle of shape of mosaic in degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `rotation_degrees`: rotation angle of mosaic shape, in degrees.
+    ///
+    /// returns: [`MosaicBuilder`] - builder with configured rotation of mosaic shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{Mosaic, MosaicBuilder};
+    ///
+    /// let starry_mosaic = MosaicBuilder::default()
+    ///     .set_rotation_degrees(45.0)
+    ///     .build_star()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(starry_mosaic.transformation().rotation_angle, 45.0f64.to_radians());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::set_rotation_angle`].
+    ///
+    pub fn set_rotation_degrees(self, rotation_degrees: f64) -> Self {
+        self.set_rotation_angle(rotation_degrees.to_radians())
+    }
+
     /// Sets scale of shape of mosaic.
     ///
     /// # Arguments
     ///
     /// * `horizontal_scale`: horizontal scale of mosaic shape in created images; should be
-    /// at least 0.001 and at most 1000.0.
+    ///   at least 0.001 and at most 1000.0.
     /// * `vertical_scale`: vertical scale of mosaic shape in created images; should be
-    /// at least 0.001 and at most 1000.0.
+    ///   at least 0.001 and at most 1000.0.
     ///
     /// returns: [`MosaicBuilder`] - builder with configured scale of mosaic shape.
     ///
@@ -196,12 +393,31 @@ impl MosaicBuilder {
         self
     }
 
+    /// Sets scale of shape of mosaic from existing scale value.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale`: horizontal and vertical scale of mosaic shape in created images; both
+    ///   components should be at least 0.001 and at most 1000.0.
+    ///
+    /// returns: [`MosaicBuilder`] - builder with configured scale of mosaic shape.
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::set_scale`].
+    /// * [`MosaicBuilder::set_transformation`].
+    ///
+    pub fn set_scale_value(mut self, scale: Scale) -> Self {
+        self.transformation.scale = scale.clamp(0.001, 1000.0);
+        self
+    }
+
     /// Sets scale of shape of mosaic.
     ///
     /// # Arguments
     ///
     /// * `scale`: uniform horizontal and vertical scale of mosaic shape in created images;
-    /// should be at least 0.001 and at most 1000.0.
+    ///   should be at least 0.001 and at most 1000.0.
     ///
     /// returns: [`MosaicBuilder`] - builder with configured scale of mosaic shape.
     ///
@@ -215,6 +431,126 @@ impl MosaicBuilder {
         self
     }
 
+    /// Sets uniform scale of shape of mosaic so that bounding diameter of shape equals given
+    /// size in created images.
+    ///
+    /// Bounding diameter is measured by [`MosaicShape::bounding_radius`] of shape, so it doesn't
+    /// depend on rotation of shape. Note that shape and image size of mosaic should be set
+    /// before calling this method.
+    ///
+    /// # Arguments
+    ///
+    /// * `diameter`: bounding diameter of mosaic shape in created images, in pixels;
+    ///   resulting scale is clamped as in [`MosaicBuilder::set_uniform_scale`].
+    ///
+    /// returns: [`MosaicBuilder`] - builder with configured scale of mosaic shape.
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::set_uniform_scale`].
+    /// * [`MosaicBuilder::set_auto_fit`].
+    ///
+    pub fn set_shape_diameter(self, diameter: f64) -> Self {
+        let radius = self
+            .shape
+            .bounding_radius(self.image_size.0, self.image_size.1);
+        if utility::approx_eq(radius, 0.0) {
+            return self;
+        }
+        self.set_uniform_scale(diameter / (2.0 * radius))
+    }
+
+    /// Sets uniform scale of shape of mosaic so that shape fills mosaic (minus margin).
+    ///
+    /// Extent of shape is measured from its center (pivot) with current rotation and shear
+    /// applied, so shape placed at center of mosaic fits within its bounds. This method is
+    /// helpful for custom shapes of unknown extent. Note that shape, image size, rotation and
+    /// shear of mosaic should be set before calling this method.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin`: minimal distance between shape and bounds of mosaic, in pixels;
+    ///   should be non-negative.
+    ///
+    /// returns: [`MosaicBuilder`] - builder with configured scale of mosaic shape.
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::set_uniform_scale`].
+    ///
+    pub fn set_auto_fit(self, margin: f64) -> Self {
+        let unit_builder = Self {
+            shape: self.shape.clone(),
+            image_size: self.image_size,
+            transformation: Transformation {
+                translation: Vector::default(),
+                scale: Scale::new_uniform(1.0),
+                ..self.transformation.clone()
+            },
+            settings: BuildSettings {
+                tileable: false,
+                ..self.settings
+            },
+        };
+        let (half_width, half_height) = unit_builder.construct_shape().iter().fold(
+            (0.0f64, 0.0f64),
+            |(half_width, half_height), point| {
+                (
+                    half_width.max(point.x.abs()),
+                    half_height.max(point.y.abs()),
+                )
+            },
+        );
+        if utility::approx_eq(half_width, 0.0) && utility::approx_eq(half_height, 0.0) {
+            return self;
+        }
+        let margin = margin.max(0.0);
+        let available_width = (self.image_size.0 as f64 - 2.0 * margin).max(0.0);
+        let available_height = (self.image_size.1 as f64 - 2.0 * margin).max(0.0);
+        let scale = f64::min(
+            available_width / (2.0 * half_width),
+            available_height / (2.0 * half_height),
+        );
+        self.set_uniform_scale(scale)
+    }
+
+    /// Places shape of mosaic at center of mosaic and scales it so that shape fills mosaic
+    /// (minus margin).
+    ///
+    /// Note that shape, image size, rotation and shear of mosaic should be set before calling
+    /// this method.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin`: minimal distance between shape and bounds of mosaic, in pixels;
+    ///   should be non-negative.
+    ///
+    /// returns: [`MosaicBuilder`] - builder with configured center and scale of mosaic shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{Mosaic, MosaicBuilder, Vector};
+    ///
+    /// let starry_mosaic = MosaicBuilder::default()
+    ///     .set_polygonal_star_shape(7)
+    ///     .set_image_size(800, 600)
+    ///     .center_and_fit(20.0)
+    ///     .build_star()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(starry_mosaic.center(), Vector::new(400.0, 300.0));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::set_relative_center`].
+    /// * [`MosaicBuilder::set_auto_fit`].
+    ///
+    pub fn center_and_fit(self, margin: f64) -> Self {
+        self.set_relative_center(0.5, 0.5).set_auto_fit(margin)
+    }
+
     /// Sets shear (skew) of shape of mosaic.
     ///
     /// # Arguments
@@ -233,6 +569,28 @@ impl MosaicBuilder {
         self
     }
 
+    /// Sets whether shear of mosaic shape is applied after its rotation.
+    ///
+    /// By default shape is sheared before it's scaled and rotated.
+    ///
+    /// # Arguments
+    ///
+    /// * `shear_after_rotation`: if `true`, shape is scaled and rotated first and sheared next;
+    ///   if `false`, shape is sheared first and scaled and rotated next.
+    ///
+    /// returns: [`MosaicBuilder`] - builder with configured order of shear and rotation.
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::set_rotation_angle`].
+    /// * [`MosaicBuilder::set_shear`].
+    /// * [`MosaicBuilder::set_transformation`].
+    ///
+    pub fn set_shear_after_rotation(mut self, shear_after_rotation: bool) -> Self {
+        self.transformation.shear_after_rotation = shear_after_rotation;
+        self
+    }
+
     /// Sets transformation (position, rotation, scale and shear) of shape of mosaic.
     ///
     /// # Arguments
@@ -252,142 +610,1039 @@ impl MosaicBuilder {
         self.transformation.rotation_angle = transformation.rotation_angle;
         self.transformation.scale = transformation.scale.clamp(0.001, 1000.0);
         self.transformation.shear = transformation.shear;
+        self.transformation.shear_after_rotation = transformation.shear_after_rotation;
         self.set_center(transformation.translation)
     }
 
-    /// Builds [starry mosaic][`StarryMosaic`] with current configuration of builder.
+    /// Sets aspect ratio (width divided by height) of pixels of medium on which mosaic
+    /// images are shown or printed.
     ///
-    /// `StarryMosaic` is based on Voronoi diagram. Due to the fact that not every mosaic shape
-    /// can provide valid set of key points for Voronoi diagram this method returns
-    /// `Option<StarryMosaic>` instead of `StarryMosaic`.
+    /// Mosaic shape is stretched vertically around [center][`MosaicBuilder::set_center`]
+    /// by pixel aspect after all other transformations, so shape keeps its proportions
+    /// on medium with non-square pixels (like print with different horizontal and vertical DPI).
     ///
-    /// # See also
+    /// # Arguments
     ///
-    /// * [`MosaicBuilder::build_from_voronoi`].
+    /// * `pixel_aspect`: width of pixel divided by its height; should be positive.
+    ///   Default value is 1.0 (square pixels).
     ///
-    pub fn build_star(self) -> Option<StarryMosaic> {
-        self.build_from_voronoi(StarryMosaic::new)
+    /// returns: [`MosaicBuilder`] - builder with configured pixel aspect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{mosaic_shape::Grid, MosaicBuilder, Vector};
+    ///
+    /// let builder = MosaicBuilder::default()
+    ///     .set_shape(Grid::new(2, 2))
+    ///     .set_image_size(400, 400)
+    ///     .set_relative_center(0.5, 0.5)
+    ///     .set_uniform_scale(0.5)
+    ///     .set_pixel_aspect(1.5);
+    /// let key_points = builder.key_points();
+    ///
+    /// assert!(key_points.contains(&Vector::new(100.0, 50.0)));
+    /// assert!(key_points.contains(&Vector::new(300.0, 350.0)));
+    /// ```
+    pub fn set_pixel_aspect(mut self, pixel_aspect: f64) -> Self {
+        self.settings.pixel_aspect = pixel_aspect.clamp(0.001, 1000.0);
+        self
     }
 
-    /// Builds [polygonal mosaic][`PolygonalMosaic`] with current configuration of builder.
+    /// Sets whether mosaic tiles seamlessly (for example, as wallpaper).
     ///
-    /// `PolygonalMosaic` is based on Delaunay triangulation. Due to the fact that not every
-    /// mosaic shape can provide valid set of key points for Delaunay triangulation this method
-    /// returns `Option<PolygonalMosaic>` instead of `PolygonalMosaic`.
+    /// Key points of tileable mosaic are wrapped toroidally into bounds of mosaic and
+    /// surrounded by shifted copies, so cells of mosaic match across opposite edges of
+    /// mosaic image.
     ///
-    /// # See also
+    /// # Arguments
     ///
-    /// * [`MosaicBuilder::build_from_voronoi`].
+    /// * `tileable`: whether mosaic tiles seamlessly.
     ///
-    pub fn build_polygon(self) -> Option<PolygonalMosaic> {
-        self.build_from_voronoi(PolygonalMosaic::new)
+    /// returns: [`MosaicBuilder`] - builder with configured tiling of mosaic.
+    ///
+    pub fn set_tileable(mut self, tileable: bool) -> Self {
+        self.settings.tileable = tileable;
+        self
     }
 
-    /// Builds mosaic based on Voronoi diagram with current configuration of builder
-    /// using constructor function.
+    /// Sets padding of bounds of Voronoi diagram on which mosaic is based.
     ///
-    /// **_Note_**: this method is intended for building custom implementations of [`Mosaic`] trait.
-    /// For existing implementations use other `build` methods.
+    /// By default Voronoi diagram is bounded exactly by mosaic image, so cells at edges
+    /// of image are clipped. Padding enlarges these bounds beyond image, so edge cells
+    /// extend fully (and are shaded as whole cells) before image crops them.
     ///
     /// # Arguments
     ///
-    /// * `constructor`: constructor function of mosaic; this function takes next arguments:
-    ///     * instance of [Voronoi diagram][`Voronoi`],
-    ///     * width and height of mosaic (and created images),
-    ///     * transformation (position, rotation, scale and shear) of mosaic shape,
-    ///     * mosaic shape with which mosaic images will be created.
+    /// * `padding`: padding of bounds on every side of image as fraction of image size;
+    ///   should be at least 0.0.
     ///
-    /// returns: `Option<MosaicImplementation>` - configured mosaic based on Voronoi diagram.
-    /// Due to the fact that not every mosaic shape can provide valid set of key points
-    /// for Voronoi diagram this method returns `Option<MosaicImplementation>` instead of
-    /// `MosaicImplementation`.
+    /// returns: [`MosaicBuilder`] - builder with configured padding of Voronoi diagram bounds.
     ///
-    pub fn build_from_voronoi<MosaicImplementation, Constructor>(
-        self,
-        constructor: Constructor,
-    ) -> Option<MosaicImplementation>
-    where
-        MosaicImplementation: Mosaic,
-        Constructor: FnOnce(
-            Voronoi,
-            (u32, u32),
-            Transformation,
-            Box<dyn MosaicShape>,
-        ) -> MosaicImplementation,
-    {
-        let points = self
-            .construct_shape()
-            .iter()
-            .map(|point| (*point).into())
-            .collect();
-        let (image_width, image_height) = (self.image_size.0 as f64, self.image_size.1 as f64);
-        let center = Point {
-            x: image_width / 2.0,
-            y: image_height / 2.0,
-        };
-        let voronoi = VoronoiBuilder::default()
-            .set_bounding_box(BoundingBox::new(center, image_width, image_height))
-            .set_sites(points)
-            .build();
-        match voronoi {
-            Some(voronoi) => Some(constructor(
-                voronoi,
-                self.image_size,
-                self.transformation,
-                self.shape,
-            )),
-            None => None,
-        }
+    pub fn set_bounding_padding(mut self, padding: f64) -> Self {
+        self.settings.bounding_padding = padding.max(0.0);
+        self
     }
 
-    /// Builds mosaic based on set of key points of mosaic shape with current configuration
-    /// of builder using constructor function.
+    /// Sets whether points of intersection of segments of mosaic shape are used as key points
+    /// of mosaic.
     ///
-    /// **_Note_**: this method is intended for building custom implementations of [`Mosaic`] trait.
-    /// For existing implementations use other `build` methods.
+    /// By default key points of mosaic include initial points of shape and points of
+    /// intersection of its segments. Without intersections mosaic is built faster
+    /// and has fewer (and larger) cells.
     ///
     /// # Arguments
     ///
-    /// * `constructor`: constructor function of mosaic; this function takes next arguments:
-    ///     * set of key points calculated by constructing mosaic shape,
-    ///     * width and height of mosaic (and created images),
-    ///     * transformation (position, rotation, scale and shear) of mosaic shape,
-    ///     * mosaic shape with which mosaic images will be created.
+    /// * `use_intersections`: whether points of intersection of shape segments are key points.
     ///
-    /// returns: `Option<MosaicImplementation>` - configured mosaic based on set of key point
-    /// of constructed mosaic shape.
+    /// returns: [`MosaicBuilder`] - builder with configured usage of intersection points.
     ///
-    pub fn build_from_key_points<MosaicImplementation, Constructor>(
-        self,
-        constructor: Constructor,
-    ) -> MosaicImplementation
-    where
-        MosaicImplementation: Mosaic,
-        Constructor: FnOnce(
-            Vec<Vector>,
-            (u32, u32),
-            Transformation,
-            Box<dyn MosaicShape>,
-        ) -> MosaicImplementation,
-    {
-        let points = self.construct_shape();
-        constructor(points, self.image_size, self.transformation, self.shape)
+    pub fn set_use_intersections(mut self, use_intersections: bool) -> Self {
+        self.settings.use_intersections = use_intersections;
+        self
     }
 
-    fn construct_shape(&self) -> Vec<Vector> {
-        let mut initial_points = self
-            .shape
-            .set_up_points(self.image_size.0, self.image_size.1);
-        let shape_segments = self.shape.connect_points(&initial_points);
-        let mut shape_points = self.shape.intersect_segments(&shape_segments);
-        shape_points.append(&mut initial_points);
-        shape_points
-            .iter_mut()
-            .for_each(|point| *point = point.transform(&self.transformation).round_to_epsilon());
-        shape_points.sort_by(|left, right| left.partial_cmp(right).unwrap_or(Ordering::Equal));
-        shape_points.dedup();
-        shape_points
-    }
+    /// Sets tolerance used to merge nearly coincident key points of mosaic shape.
+    ///
+    /// Key points which differ by no more than this tolerance in both coordinates are treated
+    /// as the same point. Looser tolerance removes tiny cells that appear where several
+    /// segments of high-resolution shape almost meet.
+    ///
+    /// # Arguments
+    ///
+    /// * `epsilon`: tolerance of comparison of key points; should be positive. Tolerance can't
+    ///   be finer than precision of coordinates of key points, so it's at least `f64::EPSILON`
+    ///   multiplied by the largest dimension of mosaic.
+    ///
+    /// returns: [`MosaicBuilder`] - builder with configured tolerance of key points.
+    ///
+    pub fn set_epsilon(mut self, epsilon: f64) -> Self {
+        self.settings.epsilon = epsilon.max(self.minimum_epsilon());
+        self
+    }
+
+    /// Sets maximum number of key points (sites of Voronoi diagram) of mosaic.
+    ///
+    /// Some mosaic shapes (especially with intersections of their segments) produce thousands
+    /// of key points, which makes building and drawing of mosaics slow. If mosaic shape
+    /// provides more key points than this limit, they are decimated by farthest point sampling:
+    /// key points are picked one by one, every time the one farthest from already picked ones,
+    /// so remaining key points stay evenly distributed over shape.
+    ///
+    /// Limit of tileable mosaic applies to key points of single tile.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_sites`: maximum number of key points of mosaic; should be at least 3.
+    ///
+    /// returns: [`MosaicBuilder`] - builder with configured maximum number of key points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{mosaic_shape::PolygonalStar, MosaicBuilder};
+    ///
+    /// let builder = MosaicBuilder::default().set_shape(PolygonalStar::new(24));
+    ///
+    /// assert!(builder.key_points().len() > 100);
+    /// assert_eq!(builder.set_max_sites(100).key_points().len(), 100);
+    /// ```
+    pub fn set_max_sites(mut self, max_sites: usize) -> Self {
+        self.settings.max_sites = max_sites.max(3);
+        self
+    }
+
+    /// Settings of builder which affect key points of mosaic besides its shape, size
+    /// and transformation.
+    ///
+    /// Mosaics built by builder store these settings, so builder created from existing mosaic
+    /// constructs the same key points.
+    ///
+    /// returns: [`BuildSettings`] - current settings of builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{Mosaic, MosaicBuilder};
+    ///
+    /// let builder = MosaicBuilder::default()
+    ///     .set_use_intersections(false)
+    ///     .set_max_sites(100);
+    /// let starry_mosaic = builder.clone().build_star().unwrap();
+    ///
+    /// assert_eq!(starry_mosaic.build_settings(), builder.build_settings());
+    /// assert_eq!(MosaicBuilder::from(&starry_mosaic).build_settings().max_sites(), 100);
+    /// ```
+    #[inline(always)]
+    pub fn build_settings(&self) -> BuildSettings {
+        self.settings
+    }
+
+    /// Builds [starry mosaic][`StarryMosaic`] with current configuration of builder.
+    ///
+    /// `StarryMosaic` is based on Voronoi diagram. Due to the fact that not every mosaic shape
+    /// can provide valid set of key points for Voronoi diagram this method returns
+    /// `Option<StarryMosaic>` instead of `StarryMosaic`.
+    ///
+    /// When mosaic shape [weighs its key points][`MosaicShape::set_up_weights`] (for example,
+    /// [`WeightedPoints`]), mosaic is drawn as weighted Voronoi diagram (power diagram).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{mosaic_shape::WeightedPoints, MosaicBuilder, Vector};
+    ///
+    /// let mosaic = MosaicBuilder::default()
+    ///     .set_shape(WeightedPoints::new(&[
+    ///         (Vector::new(-100.0, 0.0), 2500.0),
+    ///         (Vector::new(100.0, 0.0), 0.0),
+    ///         (Vector::new(0.0, 100.0), 0.0),
+    ///     ]))
+    ///     .build_star()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mosaic.site_weights().map(|weights| weights.len()), Some(3));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::build_from_voronoi`].
+    /// * [StarryMosaic::set_site_weights][`super::starry_mosaic::StarryMosaic::set_site_weights`].
+    ///
+    pub fn build_star(self) -> Option<StarryMosaic> {
+        self.try_build_star().ok()
+    }
+
+    /// Builds [starry mosaic][`StarryMosaic`] with current configuration of builder
+    /// and reports why it can't be built.
+    ///
+    /// returns: `Result<StarryMosaic, MosaicBuildError>` - built mosaic or reason why
+    /// key points of mosaic shape don't form valid Voronoi diagram.
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::build_star`].
+    /// * [`MosaicBuilder::try_build_from_voronoi`].
+    ///
+    pub fn try_build_star(self) -> Result<StarryMosaic, MosaicBuildError> {
+        let (points, site_weights, bounding_box) = self.construct_sites();
+        self.try_build_star_from_sites(points, site_weights, bounding_box)
+    }
+
+    /// Builds [starry mosaics][`StarryMosaic`] which differ only in uniform scale of their shape.
+    ///
+    /// Every mosaic is built with current configuration of builder and one of given scales.
+    /// Scales with which mosaic can't be built are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `scales`: uniform scales of mosaic shape of created mosaics; every scale
+    ///   is clamped as in [`MosaicBuilder::set_uniform_scale`].
+    ///
+    /// returns: `Vec<StarryMosaic>` - mosaics built with given scales (in the same order).
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::build_star`].
+    /// * [`MosaicBuilder::set_uniform_scale`].
+    ///
+    pub fn build_star_variants(self, scales: &[f64]) -> Vec<StarryMosaic> {
+        scales
+            .iter()
+            .filter_map(|&scale| self.clone().set_uniform_scale(scale).build_star())
+            .collect()
+    }
+
+    /// Builds [starry mosaics][`StarryMosaic`] which differ in shape and uniform scale
+    /// of their shape.
+    ///
+    /// Every mosaic is built with current configuration of builder and one of given
+    /// configurations. Mosaics are built in parallel using all available threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `configurations`: pairs of mosaic shape and its uniform scale; every scale
+    ///   is clamped as in [`MosaicBuilder::set_uniform_scale`].
+    ///
+    /// returns: `Vec<Option<StarryMosaic>>` - mosaics built with given configurations
+    /// (in the same order); `None` for configurations with which mosaic can't be built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{
+    ///     mosaic_shape::{Grid, MosaicShape, PolygonalStar, RegularPolygon},
+    ///     Mosaic,
+    ///     MosaicBuilder,
+    /// };
+    ///
+    /// let configurations: Vec<(Box<dyn MosaicShape>, f64)> = vec![
+    ///     (Box::new(RegularPolygon::new(8)), 0.5),
+    ///     (Box::new(PolygonalStar::new(7)), 0.75),
+    ///     (Box::new(Grid::new(6, 6)), 1.0),
+    /// ];
+    /// let mosaics = MosaicBuilder::default()
+    ///     .set_relative_center(0.5, 0.5)
+    ///     .build_star_grid(&configurations);
+    ///
+    /// assert_eq!(mosaics.len(), 3);
+    /// assert!(mosaics.iter().all(|mosaic| mosaic.is_some()));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::build_star`].
+    /// * [`MosaicBuilder::build_star_variants`].
+    ///
+    pub fn build_star_grid(
+        self,
+        configurations: &[(Box<dyn MosaicShape>, f64)],
+    ) -> Vec<Option<StarryMosaic>> {
+        configurations
+            .par_iter()
+            .map(|(shape, scale)| {
+                Self {
+                    shape: shape.clone(),
+                    ..self.clone()
+                }
+                .set_uniform_scale(*scale)
+                .build_star()
+            })
+            .collect()
+    }
+
+    /// Builds [polygonal mosaic][`PolygonalMosaic`] with current configuration of builder.
+    ///
+    /// `PolygonalMosaic` is based on Delaunay triangulation. Due to the fact that not every
+    /// mosaic shape can provide valid set of key points for Delaunay triangulation this method
+    /// returns `Option<PolygonalMosaic>` instead of `PolygonalMosaic`.
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::build_from_voronoi`].
+    ///
+    pub fn build_polygon(self) -> Option<PolygonalMosaic> {
+        self.try_build_polygon().ok()
+    }
+
+    /// Builds [polygonal mosaic][`PolygonalMosaic`] with current configuration of builder
+    /// and reports why it can't be built.
+    ///
+    /// returns: `Result<PolygonalMosaic, MosaicBuildError>` - built mosaic or reason why
+    /// key points of mosaic shape don't form valid Delaunay triangulation.
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::build_polygon`].
+    /// * [`MosaicBuilder::try_build_from_voronoi`].
+    ///
+    pub fn try_build_polygon(self) -> Result<PolygonalMosaic, MosaicBuildError> {
+        let settings = self.settings;
+        self.try_build_from_voronoi(|voronoi, image_size, transformation, shape| {
+            PolygonalMosaic::new(voronoi, image_size, transformation, shape, settings)
+        })
+    }
+
+    /// Builds mosaic based on Voronoi diagram with current configuration of builder
+    /// using constructor function.
+    ///
+    /// **_Note_**: this method is intended for building custom implementations of [`Mosaic`] trait.
+    /// For existing implementations use other `build` methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `constructor`: constructor function of mosaic; this function takes next arguments:
+    ///     * instance of [Voronoi diagram][`Voronoi`],
+    ///     * width and height of mosaic (and created images),
+    ///     * transformation (position, rotation, scale and shear) of mosaic shape,
+    ///     * mosaic shape with which mosaic images will be created.
+    ///
+    /// returns: `Option<MosaicImplementation>` - configured mosaic based on Voronoi diagram.
+    /// Due to the fact that not every mosaic shape can provide valid set of key points
+    /// for Voronoi diagram this method returns `Option<MosaicImplementation>` instead of
+    /// `MosaicImplementation`.
+    ///
+    pub fn build_from_voronoi<MosaicImplementation, Constructor>(
+        self,
+        constructor: Constructor,
+    ) -> Option<MosaicImplementation>
+    where
+        MosaicImplementation: Mosaic,
+        Constructor: FnOnce(
+            Voronoi,
+            (u32, u32),
+            Transformation,
+            Box<dyn MosaicShape>,
+        ) -> MosaicImplementation,
+    {
+        self.try_build_from_voronoi(constructor).ok()
+    }
+
+    /// Builds mosaic based on Voronoi diagram with current configuration of builder
+    /// using constructor function and reports why it can't be built.
+    ///
+    /// Voronoi diagram requires at least 3 distinct key points which don't lie on single line.
+    ///
+    /// # Arguments
+    ///
+    /// * `constructor`: constructor function of mosaic; see
+    ///   [`MosaicBuilder::build_from_voronoi`] for its arguments.
+    ///
+    /// returns: `Result<MosaicImplementation, MosaicBuildError>` - configured mosaic based
+    /// on Voronoi diagram or reason why key points of mosaic shape don't form valid diagram.
+    ///
+    pub fn try_build_from_voronoi<MosaicImplementation, Constructor>(
+        self,
+        constructor: Constructor,
+    ) -> Result<MosaicImplementation, MosaicBuildError>
+    where
+        MosaicImplementation: Mosaic,
+        Constructor: FnOnce(
+            Voronoi,
+            (u32, u32),
+            Transformation,
+            Box<dyn MosaicShape>,
+        ) -> MosaicImplementation,
+    {
+        let (points, _, bounding_box) = self.construct_sites();
+        let voronoi = build_voronoi(points, bounding_box)?;
+        Ok(constructor(
+            voronoi,
+            self.image_size,
+            self.transformation,
+            self.shape,
+        ))
+    }
+
+    fn try_build_star_from_sites(
+        self,
+        points: Vec<Vector>,
+        site_weights: Option<Vec<f64>>,
+        bounding_box: BoundingBox,
+    ) -> Result<StarryMosaic, MosaicBuildError> {
+        let (points, site_weights) = retain_sites_inside(points, site_weights, &bounding_box);
+        let voronoi = build_voronoi(points, bounding_box)?;
+        let mosaic = StarryMosaic::new(
+            voronoi,
+            self.image_size,
+            self.transformation,
+            self.shape,
+            self.settings,
+        );
+        Ok(weigh_sites(mosaic, site_weights))
+    }
+
+    fn construct_sites(&self) -> (Vec<Vector>, Option<Vec<f64>>, BoundingBox) {
+        let (image_width, image_height) = (self.image_size.0 as f64, self.image_size.1 as f64);
+        let center = Point {
+            x: image_width / 2.0,
+            y: image_height / 2.0,
+        };
+        let padding_factor = 1.0 + 2.0 * self.settings.bounding_padding;
+        let (points, weights, size_factor) = if self.settings.tileable {
+            let (points, weights) = self.construct_tileable_shape();
+            (points, weights, 3.0 * padding_factor)
+        } else {
+            let (points, weights) = self.construct_weighted_shape();
+            (points, weights, padding_factor)
+        };
+        (
+            points,
+            weights.map(|weights| {
+                weights
+                    .into_iter()
+                    .map(|weight| weight.unwrap_or(0.0))
+                    .collect()
+            }),
+            BoundingBox::new(
+                center,
+                image_width * size_factor,
+                image_height * size_factor,
+            ),
+        )
+    }
+
+    /// Builds mosaic based on set of key points of mosaic shape with current configuration
+    /// of builder using constructor function.
+    ///
+    /// **_Note_**: this method is intended for building custom implementations of [`Mosaic`] trait.
+    /// For existing implementations use other `build` methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `constructor`: constructor function of mosaic; this function takes next arguments:
+    ///     * set of key points calculated by constructing mosaic shape,
+    ///     * width and height of mosaic (and created images),
+    ///     * transformation (position, rotation, scale and shear) of mosaic shape,
+    ///     * mosaic shape with which mosaic images will be created.
+    ///
+    /// returns: `Option<MosaicImplementation>` - configured mosaic based on set of key point
+    /// of constructed mosaic shape.
+    ///
+    pub fn build_from_key_points<MosaicImplementation, Constructor>(
+        self,
+        constructor: Constructor,
+    ) -> MosaicImplementation
+    where
+        MosaicImplementation: Mosaic,
+        Constructor: FnOnce(
+            Vec<Vector>,
+            (u32, u32),
+            Transformation,
+            Box<dyn MosaicShape>,
+        ) -> MosaicImplementation,
+    {
+        let points = self.construct_shape();
+        constructor(points, self.image_size, self.transformation, self.shape)
+    }
+
+    /// Builds sequence of images of [starry mosaics][`StarryMosaic`] with shape rotated
+    /// incrementally from frame to frame.
+    ///
+    /// Every frame rotates mosaic shape by `TAU / frames` relative to previous one, starting
+    /// with current rotation angle of builder. Since rotation changes key points of mosaic shape
+    /// starry mosaic is rebuilt for every frame; frames for which mosaic can't be built
+    /// are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames`: number of frames in full turn of mosaic shape.
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to paint every frame.
+    ///
+    /// returns: `Vec<RgbImage>` - painted mosaic images, one per successfully built frame.
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::build_star`].
+    ///
+    pub fn build_rotation_sequence<Color, Method>(
+        &self,
+        frames: u32,
+        coloring_method: Method,
+    ) -> Vec<RgbImage>
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color> + Clone,
+    {
+        let rotation_step = consts::TAU / frames.max(1) as f64;
+        (0..frames)
+            .filter_map(|frame| {
+                self.clone()
+                    .set_rotation_angle(
+                        self.transformation.rotation_angle + rotation_step * frame as f64,
+                    )
+                    .build_star()
+            })
+            .map(|mosaic| mosaic.draw(coloring_method.clone()))
+            .collect()
+    }
+
+    /// Builds [starry mosaic][`StarryMosaic`] with current configuration of builder, draws it
+    /// and marks every key point of mosaic shape with filled circle.
+    ///
+    /// Key points are constructed once and marked exactly where they become sites of mosaic
+    /// (so key points of tileable mosaic are marked where they are wrapped into image).
+    ///
+    /// **_Note_**: this method is intended for debugging placement of mosaic shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `color`: color with which starry mosaic is painted.
+    /// * `marker`: color of circles marking key points of mosaic shape.
+    /// * `radius`: radius of circles marking key points, in pixels.
+    ///
+    /// returns: `Option<RgbImage>` - painted mosaic image with marked key points; `None`
+    /// if starry mosaic can't be built with current configuration of builder.
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::build_star`].
+    /// * [`MosaicBuilder::key_points`].
+    ///
+    pub fn draw_with_key_points<Color>(
+        self,
+        color: Color,
+        marker: Color,
+        radius: u32,
+    ) -> Option<RgbImage>
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+    {
+        let (key_points, site_weights, bounding_box) = self.construct_sites();
+        let mut mosaic_image = self
+            .try_build_star_from_sites(key_points.clone(), site_weights, bounding_box)
+            .ok()?
+            .draw(color);
+        let marker: LinSrgb<f64> = marker.into_color();
+        let marker = Rgb(marker.into_format().into_raw());
+        let (image_width, image_height) = mosaic_image.dimensions();
+        let radius = radius as i64;
+        for key_point in key_points {
+            let (center_x, center_y) = (key_point.x.round() as i64, key_point.y.round() as i64);
+            for y in center_y - radius..=center_y + radius {
+                for x in center_x - radius..=center_x + radius {
+                    if x < 0 || y < 0 || x >= image_width as i64 || y >= image_height as i64 {
+                        continue;
+                    }
+                    let (x_shift, y_shift) = (x - center_x, y - center_y);
+                    if x_shift * x_shift + y_shift * y_shift <= radius * radius {
+                        mosaic_image.put_pixel(x as u32, y as u32, marker);
+                    }
+                }
+            }
+        }
+        Some(mosaic_image)
+    }
+
+    /// Draws line segments of mosaic shape (its construction geometry) with current
+    /// configuration of builder without building mosaic.
+    ///
+    /// Drawing of wireframe doesn't require Voronoi diagram, so it's much faster than building
+    /// and drawing mosaic and can be used for previews of mosaic shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `color`: color of line segments of mosaic shape.
+    /// * `background`: color of background of image.
+    /// * `thickness`: thickness of line segments, in pixels; should be at least 1.0.
+    ///
+    /// returns: `RgbImage` - image containing line segments of mosaic shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::MosaicBuilder;
+    ///
+    /// let builder = MosaicBuilder::default().set_image_size(400, 300);
+    /// let wireframe_image = builder.draw_wireframe(
+    ///     LinSrgb::new(1.0f64, 1.0, 1.0),
+    ///     LinSrgb::new(0.0f64, 0.0, 0.0),
+    ///     1.5,
+    /// );
+    ///
+    /// assert_eq!(wireframe_image.dimensions(), (400, 300));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::shape_segments`].
+    /// * [`StarryMosaic::draw_wireframe`].
+    ///
+    pub fn draw_wireframe<Color>(&self, color: Color, background: Color, thickness: f64) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>>,
+    {
+        let color: LinSrgb<f64> = color.into_color();
+        let background: LinSrgb<f64> = background.into_color();
+        let mut wireframe_image = RgbImage::from_pixel(
+            self.image_size.0,
+            self.image_size.1,
+            Rgb(background.into_format().into_raw()),
+        );
+        let pixel = Rgb(color.into_format().into_raw());
+        for segment in self.shape_segments() {
+            rasterization::draw_segment(&mut wireframe_image, &segment, pixel, thickness);
+        }
+        wireframe_image
+    }
+
+    /// Calculates key points of mosaic shape with current configuration of builder.
+    ///
+    /// returns: `Vec<`[`Vector`]`>` - list of unique key points of mosaic shape
+    /// transformed to coordinate space of mosaic image. Points keep order in which
+    /// they are produced by mosaic shape.
+    ///
+    pub fn key_points(&self) -> Vec<Vector> {
+        self.construct_shape()
+    }
+
+    /// Calculates line segments connecting basic key points of mosaic shape with current
+    /// configuration of builder.
+    ///
+    /// These segments form construction geometry of mosaic shape (their intersections become
+    /// additional key points), so they can be used to overlay wireframe of shape on mosaic image.
+    ///
+    /// returns: `Vec<`[`Segment`]`>` - list of line segments of mosaic shape transformed
+    /// to coordinate space of mosaic image.
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicBuilder::key_points`].
+    /// * [`MosaicShape::connect_points`].
+    ///
+    pub fn shape_segments(&self) -> Vec<Segment> {
+        let initial_points = self
+            .shape
+            .set_up_points(self.image_size.0, self.image_size.1);
+        self.shape
+            .connect_points(&initial_points)
+            .iter()
+            .map(|segment| {
+                Segment::new(
+                    self.transform_point(segment.start),
+                    self.transform_point(segment.end),
+                )
+            })
+            .collect()
+    }
+
+    fn construct_tileable_shape(&self) -> (Vec<Vector>, Option<Vec<Option<f64>>>) {
+        let (shape_points, shape_weights) = self.construct_weighted_shape();
+        let shape_points: Vec<Vector> = shape_points
+            .into_iter()
+            .map(|point| self.wrap_point(point))
+            .collect();
+        let (shape_points, representatives) =
+            find_unique_points(&shape_points, self.key_point_epsilon());
+        let shape_weights = shape_weights
+            .map(|weights| gather_weights(shape_points.len(), &representatives, weights));
+        let mut tileable_points = Vec::with_capacity(shape_points.len() * 9);
+        for shift in self.tile_shifts() {
+            tileable_points.extend(shape_points.iter().map(|point| *point + shift));
+        }
+        let tileable_weights = shape_weights.map(|weights| weights.repeat(9));
+        (tileable_points, tileable_weights)
+    }
+
+    fn wrap_point(&self, point: Vector) -> Vector {
+        let wrap = |coordinate: f64, size: u32| {
+            let size = size as f64;
+            let wrapped_coordinate = utility::round_to_epsilon(coordinate.rem_euclid(size));
+            if utility::approx_eq(wrapped_coordinate, size) {
+                0.0
+            } else {
+                wrapped_coordinate
+            }
+        };
+        Vector::new(
+            wrap(point.x, self.image_size.0),
+            wrap(point.y, self.image_size.1),
+        )
+    }
+
+    fn tile_shifts(&self) -> impl Iterator<Item = Vector> {
+        let (image_width, image_height) = (self.image_size.0 as f64, self.image_size.1 as f64);
+        [0.0, -image_width, image_width]
+            .into_iter()
+            .flat_map(move |x_shift| {
+                [0.0, -image_height, image_height]
+                    .into_iter()
+                    .map(move |y_shift| Vector::new(x_shift, y_shift))
+            })
+    }
+
+    fn construct_shape(&self) -> Vec<Vector> {
+        self.construct_weighted_shape().0
+    }
+
+    fn construct_weighted_shape(&self) -> (Vec<Vector>, Option<Vec<Option<f64>>>) {
+        let mut initial_points = self
+            .shape
+            .set_up_points(self.image_size.0, self.image_size.1);
+        let initial_weights = self
+            .shape
+            .set_up_weights(self.image_size.0, self.image_size.1);
+        let mut shape_points = if self.settings.use_intersections {
+            let shape_segments = self.shape.connect_points(&initial_points);
+            self.shape.intersect_segments(&shape_segments)
+        } else {
+            Vec::with_capacity(initial_points.len())
+        };
+        // Intersections of segments have no weights, so only primary key points
+        // (which follow intersections) provide weights of sites.
+        let intersections_count = shape_points.len();
+        shape_points.append(&mut initial_points);
+        shape_points
+            .iter_mut()
+            .for_each(|point| *point = self.transform_point(*point).round_to_epsilon());
+        let (unique_points, representatives) =
+            find_unique_points(&shape_points, self.key_point_epsilon());
+        let unique_weights = initial_weights.map(|weights| {
+            gather_weights(
+                unique_points.len(),
+                &representatives[intersections_count..],
+                weights.into_iter().map(Some),
+            )
+        });
+        let is_picked = decimate_points(&unique_points, self.settings.max_sites);
+        (
+            retain_picked(unique_points, &is_picked),
+            unique_weights.map(|weights| retain_picked(weights, &is_picked)),
+        )
+    }
+
+    fn minimum_epsilon(&self) -> f64 {
+        f64::EPSILON * self.image_size.0.max(self.image_size.1).max(1) as f64
+    }
+
+    fn key_point_epsilon(&self) -> f64 {
+        self.settings.epsilon.max(self.minimum_epsilon())
+    }
+
+    fn transform_point(&self, point: Vector) -> Vector {
+        let point = point.transform(&self.transformation);
+        let center = self.transformation.translation;
+        Vector::new(
+            point.x,
+            center.y + (point.y - center.y) * self.settings.pixel_aspect,
+        )
+    }
+}
+
+fn build_voronoi(
+    points: Vec<Vector>,
+    bounding_box: BoundingBox,
+) -> Result<Voronoi, MosaicBuildError> {
+    validate_key_points(&points)?;
+    // Points are converted by value, so (since `Vector` and `Point` have the same size)
+    // allocation of key points is reused for sites of diagram instead of allocating new one.
+    VoronoiBuilder::default()
+        .set_bounding_box(bounding_box)
+        .set_sites(points.into_iter().map(Point::from).collect())
+        .build()
+        .ok_or(MosaicBuildError::InvalidDiagram)
+}
+
+fn retain_sites_inside(
+    points: Vec<Vector>,
+    site_weights: Option<Vec<f64>>,
+    bounding_box: &BoundingBox,
+) -> (Vec<Vector>, Option<Vec<f64>>) {
+    // Voronoi diagram drops sites outside its bounding box, so they are dropped beforehand
+    // to keep weights of sites in the same order as sites of diagram.
+    let is_inside: Vec<bool> = points
+        .iter()
+        .map(|point| bounding_box.is_inside(&Point::from(*point)))
+        .collect();
+    (
+        retain_picked(points, &is_inside),
+        site_weights.map(|weights| retain_picked(weights, &is_inside)),
+    )
+}
+
+fn weigh_sites(mut mosaic: StarryMosaic, site_weights: Option<Vec<f64>>) -> StarryMosaic {
+    if let Some(site_weights) = site_weights {
+        mosaic.set_site_weight_list(site_weights);
+    }
+    mosaic
+}
+
+#[cfg(test)]
+fn deduplicate_points(points: Vec<Vector>, epsilon: f64) -> Vec<Vector> {
+    find_unique_points(&points, epsilon).0
+}
+
+fn find_unique_points(points: &[Vector], epsilon: f64) -> (Vec<Vector>, Vec<usize>) {
+    let mut buckets: HashMap<(i64, i64), Vec<usize>> = HashMap::with_capacity(points.len());
+    let mut unique_points = Vec::with_capacity(points.len());
+    let mut representatives = Vec::with_capacity(points.len());
+    let is_same_point = |left: &Vector, right: &Vector| {
+        utility::approx_eq_eps(left.x, right.x, epsilon)
+            && utility::approx_eq_eps(left.y, right.y, epsilon)
+    };
+    for point in points {
+        let bucket = (
+            utility::quantize(point.x, epsilon),
+            utility::quantize(point.y, epsilon),
+        );
+        let representative = (-1..=1).find_map(|x_shift| {
+            (-1..=1).find_map(|y_shift| {
+                buckets
+                    .get(&(
+                        bucket.0.saturating_add(x_shift),
+                        bucket.1.saturating_add(y_shift),
+                    ))?
+                    .iter()
+                    .copied()
+                    .find(|index| is_same_point(&unique_points[*index], point))
+            })
+        });
+        let representative = representative.unwrap_or_else(|| {
+            buckets.entry(bucket).or_default().push(unique_points.len());
+            unique_points.push(*point);
+            unique_points.len() - 1
+        });
+        representatives.push(representative);
+    }
+    (unique_points, representatives)
+}
+
+fn gather_weights<Weights>(
+    unique_count: usize,
+    representatives: &[usize],
+    weights: Weights,
+) -> Vec<Option<f64>>
+where
+    Weights: IntoIterator<Item = Option<f64>>,
+{
+    let mut unique_weights = vec![None; unique_count];
+    for (representative, weight) in representatives.iter().zip(weights) {
+        if let Some(weight) = weight {
+            unique_weights[*representative].get_or_insert(weight);
+        }
+    }
+    unique_weights
+}
+
+fn retain_picked<Item>(items: Vec<Item>, is_picked: &[bool]) -> Vec<Item> {
+    items
+        .into_iter()
+        .zip(is_picked)
+        .filter_map(|(item, is_picked)| is_picked.then_some(item))
+        .collect()
+}
+
+fn decimate_points(points: &[Vector], max_count: usize) -> Vec<bool> {
+    if points.len() <= max_count {
+        return vec![true; points.len()];
+    }
+    let mut distances = vec![f64::INFINITY; points.len()];
+    let mut is_picked = vec![false; points.len()];
+    let mut picked_index = 0;
+    for _ in 0..max_count {
+        is_picked[picked_index] = true;
+        let picked_point = points[picked_index];
+        let mut farthest_distance = f64::NEG_INFINITY;
+        for (index, point) in points.iter().enumerate() {
+            if is_picked[index] {
+                continue;
+            }
+            distances[index] = distances[index].min(point.squared_distance_to(picked_point));
+            if distances[index] > farthest_distance {
+                farthest_distance = distances[index];
+                picked_index = index;
+            }
+        }
+    }
+    is_picked
+}
+
+fn validate_key_points(points: &[Vector]) -> Result<(), MosaicBuildError> {
+    if points.len() < 3 {
+        return Err(MosaicBuildError::NotEnoughKeyPoints(points.len()));
+    }
+    let origin = points[0];
+    let direction = points[1] - origin;
+    let is_collinear = points[2..]
+        .iter()
+        .all(|point| utility::approx_eq(direction.cross(*point - origin), 0.0));
+    if is_collinear {
+        return Err(MosaicBuildError::DegenerateKeyPoints);
+    }
+    Ok(())
+}
+
+/// Describes why [`MosaicBuilder`] can't build mosaic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MosaicBuildError {
+    /// Mosaic shape provides fewer distinct key points (stored in variant) than 3.
+    NotEnoughKeyPoints(usize),
+
+    /// All key points of mosaic shape lie on single line.
+    DegenerateKeyPoints,
+
+    /// Voronoi diagram can't be built from key points of mosaic shape.
+    InvalidDiagram,
+}
+
+impl Display for MosaicBuildError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FormatResult {
+        match self {
+            Self::NotEnoughKeyPoints(count) => write!(
+                formatter,
+                "mosaic shape has {} distinct key points, but at least 3 are required",
+                count
+            ),
+            Self::DegenerateKeyPoints => {
+                write!(
+                    formatter,
+                    "key points of mosaic shape are degenerate (collinear)"
+                )
+            }
+            Self::InvalidDiagram => {
+                write!(formatter, "Voronoi diagram can't be built from key points")
+            }
+        }
+    }
+}
+
+impl Error for MosaicBuildError {}
+
+/// Settings of [`MosaicBuilder`] which affect key points of mosaic besides its shape, size
+/// and transformation.
+///
+/// Mosaics store settings they are built with, so [builder created from mosaic][`MosaicBuilder::from`]
+/// (and so transformed mosaic) constructs the same key points as original mosaic.
+///
+/// # See also
+///
+/// * [`MosaicBuilder::build_settings`].
+/// * [`Mosaic::build_settings`].
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BuildSettings {
+    tileable: bool,
+    bounding_padding: f64,
+    use_intersections: bool,
+    epsilon: f64,
+    pixel_aspect: f64,
+    max_sites: usize,
+}
+
+impl BuildSettings {
+    /// Whether mosaic tiles seamlessly; see [`MosaicBuilder::set_tileable`].
+    #[inline(always)]
+    pub fn tileable(&self) -> bool {
+        self.tileable
+    }
+
+    /// Padding of bounds of Voronoi diagram of mosaic; see
+    /// [`MosaicBuilder::set_bounding_padding`].
+    #[inline(always)]
+    pub fn bounding_padding(&self) -> f64 {
+        self.bounding_padding
+    }
+
+    /// Whether points of intersection of segments of mosaic shape are key points of mosaic;
+    /// see [`MosaicBuilder::set_use_intersections`].
+    #[inline(always)]
+    pub fn use_intersections(&self) -> bool {
+        self.use_intersections
+    }
+
+    /// Tolerance used to merge nearly coincident key points; see
+    /// [`MosaicBuilder::set_epsilon`].
+    #[inline(always)]
+    pub fn epsilon(&self) -> f64 {
+        self.epsilon
+    }
+
+    /// Aspect ratio of pixels of medium on which mosaic images are shown; see
+    /// [`MosaicBuilder::set_pixel_aspect`].
+    #[inline(always)]
+    pub fn pixel_aspect(&self) -> f64 {
+        self.pixel_aspect
+    }
+
+    /// Maximum number of key points of mosaic; see [`MosaicBuilder::set_max_sites`].
+    #[inline(always)]
+    pub fn max_sites(&self) -> usize {
+        self.max_sites
+    }
+}
+
+impl Default for BuildSettings {
+    fn default() -> Self {
+        Self {
+            tileable: false,
+            bounding_padding: 0.0,
+            use_intersections: true,
+            epsilon: utility::EPSILON,
+            pixel_aspect: 1.0,
+            max_sites: usize::MAX,
+        }
+    }
 }
 
 impl Default for MosaicBuilder {
@@ -400,7 +1655,9 @@ impl Default for MosaicBuilder {
                 rotation_angle: 0.0,
                 scale: Scale::default(),
                 shear: Vector::default(),
+                ..Default::default()
             },
+            settings: BuildSettings::default(),
         }
     }
 }
@@ -414,6 +1671,7 @@ where
             shape: mosaic.shape().clone(),
             image_size: mosaic.image_size(),
             transformation: mosaic.transformation().clone(),
+            settings: mosaic.build_settings(),
         }
     }
 }
@@ -453,6 +1711,105 @@ mod tests {
         );
     }
     #[test]
+    fn set_relative_center() {
+        let builder = MosaicBuilder::default()
+            .set_image_size(1000, 500)
+            .set_relative_center(0.5, 0.5);
+        assert_eq!(
+            builder.transformation.translation,
+            Vector::new(500.0, 250.0)
+        );
+        let builder = builder.set_relative_center(1.5, -0.5);
+        assert_eq!(builder.transformation.translation, Vector::new(1000.0, 0.0));
+    }
+    #[test]
+    fn with_shape() {
+        let builder = MosaicBuilder::default()
+            .set_regular_polygon_shape(6)
+            .set_image_size(800, 600)
+            .set_center(Vector::new(300.0, 200.0))
+            .set_rotation_angle(0.5)
+            .set_uniform_scale(0.75);
+        let grid_builder = builder.with_shape(Grid::new(5, 5));
+        assert_eq!(grid_builder.image_size, builder.image_size);
+        assert_eq!(grid_builder.transformation, builder.transformation);
+        assert_ne!(grid_builder.key_points(), builder.key_points());
+    }
+    #[test]
+    fn set_rotation_degrees() {
+        let builder = MosaicBuilder::default().set_rotation_degrees(90.0);
+        assert!(utility::approx_eq(
+            builder.transformation.rotation_angle,
+            consts::FRAC_PI_2
+        ));
+        let builder = builder.set_rotation_degrees(-180.0);
+        assert!(utility::approx_eq(
+            builder.transformation.rotation_angle,
+            -consts::PI
+        ));
+    }
+    #[test]
+    fn set_auto_fit() {
+        let builder = MosaicBuilder::default()
+            .set_polygonal_star_shape(7)
+            .set_image_size(500, 800)
+            .set_center(Vector::new(250.0, 400.0))
+            .set_rotation_angle(0.3)
+            .set_auto_fit(0.0);
+        let center = builder.transformation.translation;
+        let widest_extent = builder.key_points().iter().fold(0.0f64, |extent, point| {
+            extent
+                .max(2.0 * (point.x - center.x).abs())
+                .max(2.0 * (point.y - center.y).abs())
+        });
+        assert!((widest_extent - 500.0).abs() < 1e-6);
+        let builder = builder.set_auto_fit(50.0);
+        assert!(builder
+            .key_points()
+            .iter()
+            .all(|point| point.x >= 50.0 - 1e-6 && point.x <= 450.0 + 1e-6));
+    }
+    #[test]
+    fn center_and_fit() {
+        let builder = MosaicBuilder::default()
+            .set_grid_shape(4, 4)
+            .set_image_size(500, 800)
+            .set_center(Vector::new(100.0, 100.0))
+            .center_and_fit(50.0);
+        assert_eq!(
+            builder.transformation.translation,
+            Vector::new(250.0, 400.0)
+        );
+        let key_points = builder.key_points();
+        let (minimum_x, maximum_x) = key_points
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(minimum, maximum), point| {
+                (minimum.min(point.x), maximum.max(point.x))
+            });
+        assert!((minimum_x - 50.0).abs() < 1e-6);
+        assert!((maximum_x - 450.0).abs() < 1e-6);
+        assert!(key_points
+            .iter()
+            .all(|point| point.y >= 50.0 - 1e-6 && point.y <= 750.0 + 1e-6));
+    }
+    #[test]
+    fn set_shape_diameter() {
+        let builder = MosaicBuilder::default()
+            .set_regular_polygon_shape(8)
+            .set_image_size(800, 600)
+            .set_center(Vector::new(400.0, 300.0))
+            .set_shape_diameter(400.0);
+        assert_eq!(
+            builder.transformation.scale,
+            Scale::new_uniform(400.0 / 600.0)
+        );
+        let center = builder.transformation.translation;
+        let diameter = builder.key_points().iter().fold(0.0f64, |diameter, point| {
+            diameter.max(2.0 * point.distance_to(center))
+        });
+        assert!((diameter - 400.0).abs() < 1e-6);
+    }
+    #[test]
     fn set_rotation() {
         let builder = MosaicBuilder::default().set_rotation_angle(consts::FRAC_PI_4);
         assert_eq!(builder.transformation.rotation_angle, consts::FRAC_PI_4);
@@ -470,8 +1827,536 @@ mod tests {
         assert!(builder.transformation.scale.y < 10000.0);
     }
     #[test]
+    fn set_scale_value() {
+        let builder = MosaicBuilder::default().set_scale_value(Scale::new(2.0, 0.5));
+        assert_eq!(builder.transformation.scale, Scale::new(2.0, 0.5));
+        let builder = builder.set_scale_value(Scale::new(0.0, 10000.0));
+        assert_eq!(builder.transformation.scale, Scale::new(0.001, 1000.0));
+    }
+    #[test]
     fn set_shear() {
         let builder = MosaicBuilder::default().set_shear(0.5, -0.75);
         assert_eq!(builder.transformation.shear, Vector::new(0.5, -0.75));
     }
+    #[test]
+    fn set_shear_after_rotation() {
+        let builder = MosaicBuilder::default();
+        assert!(!builder.transformation.shear_after_rotation);
+        let builder = builder.set_shear_after_rotation(true);
+        assert!(builder.transformation.shear_after_rotation);
+    }
+    #[test]
+    fn set_bounding_padding() {
+        let builder = MosaicBuilder::default().set_bounding_padding(0.25);
+        assert_eq!(builder.settings.bounding_padding, 0.25);
+        let builder = builder.set_bounding_padding(-1.0);
+        assert_eq!(builder.settings.bounding_padding, 0.0);
+    }
+    #[test]
+    fn set_use_intersections() {
+        let builder = MosaicBuilder::default().set_regular_polygon_shape(8);
+        assert!(builder.settings.use_intersections);
+        let key_points = builder.key_points();
+        let builder = builder.set_use_intersections(false);
+        assert!(!builder.settings.use_intersections);
+        let primary_key_points = builder.key_points();
+        assert_eq!(primary_key_points.len(), 8);
+        assert!(primary_key_points.len() < key_points.len());
+        assert!(primary_key_points
+            .iter()
+            .all(|point| key_points.contains(point)));
+    }
+    #[test]
+    fn starry_preset() {
+        let builder = MosaicBuilder::starry_preset();
+        assert_eq!(builder.transformation.scale, Scale::new_uniform(0.6));
+        assert_eq!(
+            builder.transformation.translation,
+            Vector::new(320.0, 320.0)
+        );
+        assert_eq!(builder.shape.set_up_points(640, 640).len(), 12);
+        let mosaic = builder.build_star().unwrap();
+        assert_eq!(mosaic.image_size(), (640, 640));
+    }
+    #[test]
+    fn set_pixel_aspect() {
+        let builder = MosaicBuilder::default()
+            .set_image_size(400, 400)
+            .set_relative_center(0.5, 0.5)
+            .set_regular_polygon_shape(4)
+            .set_rotation_angle(consts::FRAC_PI_4)
+            .set_uniform_scale(0.5);
+        let square_points = builder.key_points();
+        let stretched_points = builder.clone().set_pixel_aspect(2.0).key_points();
+        assert_eq!(square_points.len(), stretched_points.len());
+        for point in square_points {
+            let stretched_point = Vector::new(point.x, 200.0 + (point.y - 200.0) * 2.0);
+            assert!(stretched_points.contains(&stretched_point));
+        }
+        let builder = builder.set_pixel_aspect(-1.0);
+        assert!(builder.settings.pixel_aspect > 0.0);
+    }
+    #[test]
+    fn build_star_grid() {
+        let configurations: Vec<(Box<dyn MosaicShape>, f64)> = vec![
+            (Box::new(RegularPolygon::new(6)), 0.5),
+            (Box::new(Grid::new(4, 4)), 0.75),
+            (Box::new(PolygonalStar::new(9)), 1.0),
+        ];
+        let builder = MosaicBuilder::default().set_relative_center(0.5, 0.5);
+        let mosaics = builder.clone().build_star_grid(&configurations);
+        assert_eq!(mosaics.len(), 3);
+        for (mosaic, (shape, scale)) in mosaics.iter().zip(&configurations) {
+            let mosaic = mosaic.as_ref().unwrap();
+            let expected_mosaic = MosaicBuilder {
+                shape: shape.clone(),
+                ..builder.clone()
+            }
+            .set_uniform_scale(*scale)
+            .build_star()
+            .unwrap();
+            assert_eq!(mosaic.transformation(), expected_mosaic.transformation());
+            assert_eq!(mosaic.sites(), expected_mosaic.sites());
+        }
+    }
+    #[test]
+    fn build_voronoi() {
+        let builder = MosaicBuilder::default()
+            .set_shape(PolygonalStar::new(7))
+            .set_relative_center(0.5, 0.5);
+        let (points, _, bounding_box) = builder.construct_sites();
+        let voronoi = super::build_voronoi(points.clone(), bounding_box).unwrap();
+        let sites: Vec<Vector> = voronoi.sites().iter().map(Vector::from).collect();
+        assert_eq!(sites, points);
+        assert_eq!(builder.clone().build_star().unwrap().sites(), points);
+        assert_eq!(
+            super::build_voronoi(points[..2].to_vec(), voronoi.bounding_box().clone()).err(),
+            Some(MosaicBuildError::NotEnoughKeyPoints(2))
+        );
+    }
+    #[test]
+    fn draw_wireframe() {
+        let builder = MosaicBuilder::default()
+            .set_shape(Grid::new(4, 4))
+            .set_image_size(400, 400)
+            .set_relative_center(0.5, 0.5)
+            .set_uniform_scale(0.5);
+        let wireframe_image = builder.draw_wireframe(
+            LinSrgb::new(1.0f64, 1.0, 1.0),
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            1.0,
+        );
+        assert_eq!(wireframe_image.dimensions(), (400, 400));
+        for line in [150, 200, 250] {
+            for position in (100..=300).step_by(25) {
+                assert_eq!(
+                    *wireframe_image.get_pixel(position, line),
+                    Rgb([255, 255, 255])
+                );
+                assert_eq!(
+                    *wireframe_image.get_pixel(line, position),
+                    Rgb([255, 255, 255])
+                );
+            }
+        }
+        for (x, y) in [(125, 125), (175, 225), (50, 200), (200, 350), (152, 225)] {
+            assert_eq!(*wireframe_image.get_pixel(x, y), Rgb([0, 0, 0]));
+        }
+    }
+    #[test]
+    fn shape_segments() {
+        let builder = MosaicBuilder::default()
+            .set_image_size(400, 400)
+            .set_relative_center(0.5, 0.5)
+            .set_grid_shape(4, 4)
+            .set_rotation_angle(consts::FRAC_PI_2)
+            .set_uniform_scale(0.5);
+        let shape = Grid::new(4, 4);
+        let shape_segments = shape.connect_points(&shape.set_up_points(400, 400));
+        let segments = builder.shape_segments();
+        assert_eq!(segments.len(), shape_segments.len());
+        assert!(segments.contains(&Segment::from(((150.0, 100.0), (150.0, 300.0)))));
+        assert!(segments.contains(&Segment::from(((100.0, 150.0), (300.0, 150.0)))));
+        assert!(!segments.contains(&shape_segments[0]));
+    }
+    #[test]
+    fn build_star_with_bounding_padding() {
+        let builder = MosaicBuilder::default()
+            .set_image_size(200, 200)
+            .set_relative_center(0.5, 0.5)
+            .set_grid_shape(4, 4)
+            .set_uniform_scale(1.2);
+        let mosaic = builder.clone().build_star().unwrap();
+        let padded_mosaic = builder.set_bounding_padding(0.5).build_star().unwrap();
+        assert_eq!(padded_mosaic.image_size(), (200, 200));
+        let color = LinSrgb::new(1.0f64, 0.5, 0.0);
+        let (image, padded_image) = (mosaic.draw(color), padded_mosaic.draw(color));
+        assert_eq!(padded_image.dimensions(), (200, 200));
+        assert_ne!(image.get_pixel(25, 40), padded_image.get_pixel(25, 40));
+        assert_eq!(image.get_pixel(100, 100), padded_image.get_pixel(100, 100));
+    }
+    #[test]
+    fn build_star_variants() {
+        let scales = [0.5, 1.0, 2.0];
+        let mosaics = MosaicBuilder::default()
+            .set_image_size(100, 100)
+            .set_relative_center(0.5, 0.5)
+            .build_star_variants(&scales);
+        assert_eq!(mosaics.len(), scales.len());
+        for (mosaic, scale) in mosaics.iter().zip(scales) {
+            assert_eq!(mosaic.transformation().scale, Scale::new_uniform(scale));
+        }
+        assert!(MosaicBuilder::default().build_star_variants(&[]).is_empty());
+    }
+    #[test]
+    fn deduplicate_points_non_adjacent_duplicates() {
+        let epsilon = utility::EPSILON;
+        let first = Vector::new(0.0, 5.0);
+        let second = Vector::new(1.5 * epsilon, 0.0);
+        let third = Vector::new(0.9 * epsilon, 5.0);
+        let points = vec![first, second, third];
+        let mut deduplicated_points = points.clone();
+        deduplicated_points.dedup();
+        assert_eq!(deduplicated_points.len(), 3);
+        let deduplicated_points = deduplicate_points(points, epsilon);
+        assert_eq!(deduplicated_points, vec![first, second]);
+    }
+    #[test]
+    fn deduplicate_points_with_custom_epsilon() {
+        let points = vec![
+            Vector::new(10.0, 10.0),
+            Vector::new(10.0 + 1e-4, 10.0 - 1e-4),
+            Vector::new(10.5, 10.0),
+        ];
+        let deduplicated_points = deduplicate_points(points.clone(), utility::EPSILON);
+        assert_eq!(deduplicated_points.len(), 3);
+        let deduplicated_points = deduplicate_points(points, 1e-3);
+        assert_eq!(deduplicated_points.len(), 2);
+        assert!(deduplicated_points.contains(&Vector::new(10.0, 10.0)));
+        assert!(deduplicated_points.contains(&Vector::new(10.5, 10.0)));
+    }
+    #[test]
+    fn build_star_with_tiny_epsilon() {
+        let builder = MosaicBuilder::default()
+            .set_image_size(6000, 6000)
+            .set_relative_center(0.5, 0.5)
+            .set_epsilon(1e-16);
+        assert_eq!(builder.settings.epsilon, 6000.0 * f64::EPSILON);
+        assert!(builder.build_star().is_some());
+        let points = vec![
+            Vector::new(1e30, 0.0),
+            Vector::new(1e30, 1.0),
+            Vector::new(-1e30, 0.0),
+        ];
+        assert_eq!(deduplicate_points(points, f64::EPSILON).len(), 3);
+    }
+    #[test]
+    fn set_max_sites() {
+        let builder = MosaicBuilder::default()
+            .set_shape(PolygonalStar::new(16))
+            .set_relative_center(0.5, 0.5)
+            .set_uniform_scale(0.9);
+        let key_points = builder.key_points();
+        assert!(key_points.len() > 1000);
+        let decimated_points = builder.clone().set_max_sites(50).key_points();
+        assert_eq!(decimated_points.len(), 50);
+        assert!(decimated_points
+            .iter()
+            .all(|point| key_points.contains(point)));
+        let minimum_distance = decimated_points
+            .iter()
+            .enumerate()
+            .flat_map(|(index, point)| {
+                decimated_points[index + 1..]
+                    .iter()
+                    .map(|other_point| point.distance_to(*other_point))
+            })
+            .fold(f64::INFINITY, f64::min);
+        assert!(minimum_distance > 40.0);
+        for quadrant in [(0.0, 0.0), (320.0, 0.0), (0.0, 320.0), (320.0, 320.0)] {
+            let quadrant_points_count = decimated_points
+                .iter()
+                .filter(|point| {
+                    (quadrant.0..quadrant.0 + 320.0).contains(&point.x)
+                        && (quadrant.1..quadrant.1 + 320.0).contains(&point.y)
+                })
+                .count();
+            assert!(quadrant_points_count >= 8);
+        }
+        let mosaic = builder.clone().set_max_sites(50).build_star().unwrap();
+        assert!(mosaic.sites().len() <= 50);
+        assert_eq!(builder.clone().set_max_sites(1).settings.max_sites, 3);
+        assert_eq!(
+            builder.set_max_sites(key_points.len()).key_points(),
+            key_points
+        );
+    }
+    #[test]
+    fn set_epsilon() {
+        let builder = MosaicBuilder::default();
+        assert_eq!(builder.settings.epsilon, utility::EPSILON);
+        let builder = builder.set_epsilon(0.5);
+        assert_eq!(builder.settings.epsilon, 0.5);
+        let builder = builder.set_epsilon(-1.0);
+        assert!(builder.settings.epsilon > 0.0);
+        let builder = MosaicBuilder::default().set_regular_polygon_shape(12);
+        let key_points = builder.key_points();
+        let merged_key_points = builder.set_epsilon(20.0).key_points();
+        assert!(merged_key_points.len() < key_points.len());
+    }
+    #[derive(Clone, Debug)]
+    struct FixedPointsShape {
+        points: Vec<Vector>,
+    }
+
+    impl MosaicShape for FixedPointsShape {
+        fn set_up_points(&self, _image_width: u32, _image_height: u32) -> Vec<Vector> {
+            self.points.clone()
+        }
+
+        fn connect_points(&self, _shape_points: &Vec<Vector>) -> Vec<Segment> {
+            vec![]
+        }
+    }
+
+    #[test]
+    fn try_build_star_with_degenerate_key_points() {
+        let builder = MosaicBuilder::default().set_shape(FixedPointsShape {
+            points: vec![
+                Vector::new(-100.0, -100.0),
+                Vector::new(0.0, 0.0),
+                Vector::new(100.0, 100.0),
+            ],
+        });
+        assert_eq!(
+            builder.try_build_star().unwrap_err(),
+            MosaicBuildError::DegenerateKeyPoints
+        );
+        let builder = MosaicBuilder::default().set_shape(FixedPointsShape {
+            points: vec![Vector::new(-100.0, 0.0), Vector::new(100.0, 0.0)],
+        });
+        assert_eq!(
+            builder.try_build_polygon().unwrap_err(),
+            MosaicBuildError::NotEnoughKeyPoints(2)
+        );
+    }
+    #[test]
+    fn try_build_star_with_triangle() {
+        let builder = MosaicBuilder::default().set_shape(FixedPointsShape {
+            points: vec![
+                Vector::new(-100.0, -100.0),
+                Vector::new(100.0, -100.0),
+                Vector::new(0.0, 100.0),
+            ],
+        });
+        assert!(builder.try_build_star().is_ok());
+    }
+    #[test]
+    fn build_tileable_star() {
+        let mosaic = MosaicBuilder::default()
+            .set_scattered_points_shape(40, 60.0, 5)
+            .set_image_size(300, 200)
+            .set_center(Vector::new(150.0, 100.0))
+            .set_tileable(true)
+            .build_star()
+            .unwrap();
+        let color = LinSrgb::new(0.2f64, 0.5, 1.0);
+        let mosaic_image = mosaic.draw(color);
+        let sites: Vec<Point> = mosaic.voronoi().sites().clone();
+        let tiled_voronoi = VoronoiBuilder::default()
+            .set_bounding_box(BoundingBox::new(
+                Point { x: 300.0, y: 200.0 },
+                1200.0,
+                800.0,
+            ))
+            .set_sites(sites)
+            .build()
+            .unwrap();
+        let tiled_mosaic = StarryMosaic::from_voronoi(
+            tiled_voronoi,
+            (600, 400),
+            mosaic.transformation().clone(),
+            mosaic.shape().clone(),
+        );
+        let tiled_image = tiled_mosaic.draw(color);
+        let assert_same_pixel = |(x, y): (u32, u32), (tiled_x, tiled_y): (u32, u32)| {
+            let (pixel, tiled_pixel) = (
+                mosaic_image.get_pixel(x, y),
+                tiled_image.get_pixel(tiled_x, tiled_y),
+            );
+            for channel in 0..3 {
+                assert!(
+                    (pixel[channel] as i32 - tiled_pixel[channel] as i32).abs() <= 1,
+                    "pixel ({}, {}) differs from tiled pixel ({}, {})",
+                    x,
+                    y,
+                    tiled_x,
+                    tiled_y
+                );
+            }
+        };
+        for y in 0..200 {
+            assert_same_pixel((299, y), (299, y));
+            assert_same_pixel((0, y), (300, y));
+        }
+        for x in 0..300 {
+            assert_same_pixel((x, 199), (x, 199));
+            assert_same_pixel((x, 0), (x, 200));
+        }
+    }
+    #[test]
+    fn build_star_with_weighted_points() {
+        let build_mosaic = |weight: f64| {
+            MosaicBuilder::default()
+                .set_shape(WeightedPoints::new(&[
+                    (Vector::new(-100.0, 0.0), weight),
+                    (Vector::new(100.0, 0.0), 0.0),
+                    (Vector::new(0.0, -150.0), 0.0),
+                    (Vector::new(0.0, 150.0), 0.0),
+                ]))
+                .set_image_size(400, 400)
+                .set_relative_center(0.5, 0.5)
+                .build_star()
+                .unwrap()
+        };
+        let count_pixels = |mosaic: &StarryMosaic, position: Vector| {
+            let site = mosaic
+                .sites()
+                .iter()
+                .position(|site| *site == position)
+                .unwrap();
+            mosaic
+                .site_map()
+                .iter()
+                .filter(|pixel_site| **pixel_site == site)
+                .count()
+        };
+        let (weighted_site, site) = (Vector::new(100.0, 200.0), Vector::new(300.0, 200.0));
+        let mosaic = build_mosaic(0.0);
+        assert_eq!(mosaic.site_weights(), Some(&[0.0; 4][..]));
+        let weighted_mosaic = build_mosaic(5000.0);
+        assert!(
+            count_pixels(&weighted_mosaic, weighted_site) > count_pixels(&mosaic, weighted_site)
+        );
+        assert!(count_pixels(&weighted_mosaic, site) < count_pixels(&mosaic, site));
+        let tileable_mosaic = MosaicBuilder::from(&weighted_mosaic)
+            .set_tileable(true)
+            .build_star()
+            .unwrap();
+        let site_weights = tileable_mosaic.site_weights().unwrap();
+        assert_eq!(
+            site_weights
+                .iter()
+                .filter(|weight| **weight == 5000.0)
+                .count(),
+            9
+        );
+        assert!(MosaicBuilder::default()
+            .build_star()
+            .unwrap()
+            .site_weights()
+            .is_none());
+    }
+    #[test]
+    fn build_star_with_weighted_intersections() {
+        #[derive(Clone, Debug)]
+        struct WeightedCross;
+        impl MosaicShape for WeightedCross {
+            fn set_up_points(&self, _image_width: u32, _image_height: u32) -> Vec<Vector> {
+                vec![
+                    Vector::new(-100.0, -100.0),
+                    Vector::new(100.0, 100.0),
+                    Vector::new(100.0, -100.0),
+                    Vector::new(-100.0, 100.0),
+                    Vector::new(0.0, 0.005),
+                ]
+            }
+            fn set_up_weights(&self, _image_width: u32, _image_height: u32) -> Option<Vec<f64>> {
+                Some(vec![0.0, 0.0, 0.0, 0.0, 2500.0])
+            }
+            fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment> {
+                vec![
+                    Segment::new(shape_points[0], shape_points[1]),
+                    Segment::new(shape_points[2], shape_points[3]),
+                ]
+            }
+        }
+        let builder = MosaicBuilder::default()
+            .set_shape(WeightedCross)
+            .set_image_size(400, 400)
+            .set_relative_center(0.5, 0.5)
+            .set_epsilon(0.01);
+        let center = Vector::new(200.0, 200.0);
+        let mosaic = builder.clone().build_star().unwrap();
+        assert_eq!(mosaic.sites().len(), 5);
+        let center_index = mosaic.sites().iter().position(|site| *site == center);
+        assert_eq!(
+            mosaic.site_weights().unwrap()[center_index.unwrap()],
+            2500.0
+        );
+        let tileable_mosaic = builder.set_tileable(true).build_star().unwrap();
+        let site_weights = tileable_mosaic.site_weights().unwrap();
+        assert_eq!(site_weights.len(), tileable_mosaic.sites().len());
+        let center_index = tileable_mosaic
+            .sites()
+            .iter()
+            .position(|site| *site == center);
+        assert_eq!(site_weights[center_index.unwrap()], 2500.0);
+        assert_eq!(
+            site_weights
+                .iter()
+                .filter(|weight| **weight == 2500.0)
+                .count(),
+            9
+        );
+    }
+    #[test]
+    fn build_tileable_polygon() {
+        let mosaic = MosaicBuilder::default()
+            .set_polygonal_star_shape(7)
+            .set_tileable(true)
+            .build_polygon();
+        assert!(mosaic.is_some());
+        let mosaic_image = mosaic.unwrap().draw(LinSrgb::new(0.2f64, 0.5, 1.0));
+        assert_eq!(mosaic_image.dimensions(), (640, 640));
+    }
+    #[test]
+    fn build_star_with_flower() {
+        let mosaic = MosaicBuilder::default()
+            .set_flower_shape(6, 6, 0.5)
+            .build_star();
+        assert!(mosaic.is_some());
+    }
+    #[test]
+    fn build_star_with_scattered_points() {
+        let mosaic = MosaicBuilder::default()
+            .set_scattered_points_shape(50, 40.0, 11)
+            .build_star();
+        assert!(mosaic.is_some());
+    }
+    #[test]
+    fn build_rotation_sequence() {
+        let frames = MosaicBuilder::default()
+            .set_regular_polygon_shape(6)
+            .set_image_size(120, 80)
+            .set_center(Vector::new(60.0, 40.0))
+            .build_rotation_sequence(4, LinSrgb::new(0.0f64, 0.0, 1.0));
+        assert_eq!(frames.len(), 4);
+        for frame in frames {
+            assert_eq!(frame.dimensions(), (120, 80));
+        }
+    }
+    #[test]
+    fn draw_with_key_points() {
+        let builder = MosaicBuilder::default();
+        let key_points = builder.key_points();
+        assert!(key_points.contains(&Vector::new(320.0, 320.0)));
+        let color = LinSrgb::new(0.0f64, 0.0, 1.0);
+        let marker = LinSrgb::new(1.0f64, 1.0, 0.0);
+        let mosaic_image = builder.draw_with_key_points(color, marker, 3);
+        assert!(mosaic_image.is_some());
+        let mosaic_image = mosaic_image.unwrap();
+        assert_eq!(*mosaic_image.get_pixel(320, 320), Rgb([255, 255, 0]));
+        assert_eq!(*mosaic_image.get_pixel(322, 320), Rgb([255, 255, 0]));
+    }
 }
@@ -0,0 +1,212 @@
+use super::{MosaicShape, PolygonalStar, Segment, Union, Vector};
+
+/// Defines mosaic shape which overlays two polygonal stars with different number of corners.
+///
+/// Second star is rotated relative to first one, so lines of both stars cross each other
+/// and create intricate interference patterns. Stars are overlaid by [`Union`].
+#[derive(Clone, Debug)]
+pub struct CompoundStar {
+    first_star: PolygonalStar,
+    second_star: PolygonalStar,
+    relative_rotation: f64,
+    union: Union,
+}
+
+impl CompoundStar {
+    /// Creates compound star from two polygonal stars.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_corners_count`: number of convex corners of first star; should be at least 3.
+    /// * `second_corners_count`: number of convex corners of second star; should be at least 3.
+    /// * `relative_rotation`: rotation angle of second star relative to first one, in radians.
+    ///
+    /// returns: [`CompoundStar`] - mosaic shape based on two overlaid polygonal stars.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts;
+    ///
+    /// use starry_mosaic::mosaic_shape::{CompoundStar, MosaicShape};
+    ///
+    /// let compound_star = CompoundStar::new(5, 7, consts::PI / 7.0);
+    /// let points = compound_star.set_up_points(400, 400);
+    ///
+    /// assert_eq!(points.len(), 10 + 14);
+    /// ```
+    pub fn new(
+        first_corners_count: u32,
+        second_corners_count: u32,
+        relative_rotation: f64,
+    ) -> Self {
+        let first_star = PolygonalStar::new(first_corners_count);
+        let second_star = PolygonalStar::new(second_corners_count);
+        let union = Self::create_union(&first_star, &second_star, relative_rotation);
+        Self {
+            first_star,
+            second_star,
+            relative_rotation,
+            union,
+        }
+    }
+
+    /// Number of convex corners of first star.
+    #[inline(always)]
+    pub fn first_corners_count(&self) -> u32 {
+        self.first_star.corners_count()
+    }
+
+    /// Sets number of convex corners of first star; should be at least 3.
+    pub fn set_first_corners_count(&mut self, corners_count: u32) {
+        self.first_star.set_corners_count(corners_count);
+        self.update_union();
+    }
+
+    /// Number of convex corners of second star.
+    #[inline(always)]
+    pub fn second_corners_count(&self) -> u32 {
+        self.second_star.corners_count()
+    }
+
+    /// Sets number of convex corners of second star; should be at least 3.
+    pub fn set_second_corners_count(&mut self, corners_count: u32) {
+        self.second_star.set_corners_count(corners_count);
+        self.update_union();
+    }
+
+    /// Rotation angle of second star relative to first one, in radians.
+    #[inline(always)]
+    pub fn relative_rotation(&self) -> f64 {
+        self.relative_rotation
+    }
+
+    /// Sets rotation angle of second star relative to first one, in radians.
+    pub fn set_relative_rotation(&mut self, relative_rotation: f64) {
+        self.relative_rotation = relative_rotation;
+        self.update_union();
+    }
+
+    fn update_union(&mut self) {
+        self.union =
+            Self::create_union(&self.first_star, &self.second_star, self.relative_rotation);
+    }
+
+    fn create_union(
+        first_star: &PolygonalStar,
+        second_star: &PolygonalStar,
+        relative_rotation: f64,
+    ) -> Union {
+        Union::new(
+            first_star.clone(),
+            RotatedStar {
+                star: second_star.clone(),
+                rotation: relative_rotation,
+            },
+        )
+    }
+}
+
+impl Default for CompoundStar {
+    fn default() -> Self {
+        Self::new(5, 8, 0.0)
+    }
+}
+
+impl MosaicShape for CompoundStar {
+    fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
+        self.union.set_up_points(image_width, image_height)
+    }
+
+    fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment> {
+        self.union.connect_points(shape_points)
+    }
+}
+
+/// Polygonal star rotated around origin, which is second star of [`CompoundStar`].
+#[derive(Clone, Debug)]
+struct RotatedStar {
+    star: PolygonalStar,
+    rotation: f64,
+}
+
+impl MosaicShape for RotatedStar {
+    fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
+        self.star
+            .set_up_points(image_width, image_height)
+            .iter()
+            .map(|point| point.rotate(self.rotation))
+            .collect()
+    }
+
+    fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment> {
+        let star_points = shape_points
+            .iter()
+            .map(|point| point.rotate(-self.rotation))
+            .collect();
+        self.star
+            .connect_points(&star_points)
+            .into_iter()
+            .map(|segment| {
+                Segment::new(
+                    segment.start.rotate(self.rotation),
+                    segment.end.rotate(self.rotation),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts;
+
+    use super::*;
+
+    #[test]
+    fn set_corners_count() {
+        let mut compound_star = CompoundStar::default();
+        compound_star.set_first_corners_count(6);
+        compound_star.set_second_corners_count(1);
+        assert_eq!(compound_star.first_corners_count(), 6);
+        assert_eq!(compound_star.second_corners_count(), 3);
+    }
+    #[test]
+    fn set_relative_rotation() {
+        let mut compound_star = CompoundStar::new(5, 7, 0.0);
+        compound_star.set_relative_rotation(consts::FRAC_PI_2);
+        assert_eq!(compound_star.relative_rotation(), consts::FRAC_PI_2);
+        assert_eq!(
+            compound_star.set_up_points(400, 400),
+            CompoundStar::new(5, 7, consts::FRAC_PI_2).set_up_points(400, 400)
+        );
+    }
+    #[test]
+    fn set_up_points() {
+        let compound_star = CompoundStar::new(5, 7, consts::FRAC_PI_2);
+        let points = compound_star.set_up_points(400, 400);
+        let first_points = PolygonalStar::new(5).set_up_points(400, 400);
+        let second_points = PolygonalStar::new(7).set_up_points(400, 400);
+        assert_eq!(points.len(), first_points.len() + second_points.len());
+        assert_eq!(points[..first_points.len()], first_points[..]);
+        for (point, second_point) in points[first_points.len()..].iter().zip(second_points) {
+            assert_eq!(*point, second_point.rotate(consts::FRAC_PI_2));
+        }
+    }
+    #[test]
+    fn connect_points() {
+        let compound_star = CompoundStar::new(6, 4, 0.3);
+        let points = compound_star.set_up_points(400, 400);
+        let segments = compound_star.connect_points(&points);
+        let first_star = PolygonalStar::new(6);
+        let first_segments = first_star.connect_points(&first_star.set_up_points(400, 400));
+        let second_star = PolygonalStar::new(4);
+        let second_segments = second_star.connect_points(&second_star.set_up_points(400, 400));
+        assert_eq!(segments.len(), first_segments.len() + second_segments.len());
+        assert_eq!(segments[..first_segments.len()], first_segments[..]);
+        assert!(segments[first_segments.len()..]
+            .iter()
+            .all(|segment| points[12..].contains(&segment.start)
+                && points[12..].contains(&segment.end)));
+    }
+}
@@ -0,0 +1,171 @@
+use std::f64::consts;
+
+use super::{helpers, MosaicShape, Segment, Vector};
+
+/// Defines mosaic shape based on flower: central regular polygon surrounded by ring
+/// of regular polygons (petals).
+///
+/// Neighbouring petals overlap each other and central polygon, so intersections of their
+/// edges create rich compound pattern.
+#[derive(Clone, Debug)]
+pub struct Flower {
+    petal_corners_count: u32,
+    petals_count: u32,
+    ring_radius_fraction: f64,
+}
+
+impl Flower {
+    /// Creates flower with set number of petals and corners of every petal.
+    ///
+    /// # Arguments
+    ///
+    /// * `petal_corners_count`: number of corners of central polygon and every petal;
+    ///   should be at least 3.
+    /// * `petals_count`: number of petals surrounding central polygon; should be at least 1.
+    /// * `ring_radius_fraction`: radius of ring of petal centers as fraction of radius
+    ///   of mosaic shape; should be in range from 0.0 to 1.0.
+    ///
+    /// returns: [`Flower`] - mosaic shape based on flower with given properties.
+    ///
+    pub fn new(petal_corners_count: u32, petals_count: u32, ring_radius_fraction: f64) -> Self {
+        Self {
+            petal_corners_count: petal_corners_count.max(3),
+            petals_count: petals_count.max(1),
+            ring_radius_fraction: ring_radius_fraction.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Number of corners of central polygon and every petal of flower.
+    #[inline(always)]
+    pub fn petal_corners_count(&self) -> u32 {
+        self.petal_corners_count
+    }
+
+    /// Sets number of corners of central polygon and every petal of flower.
+    ///
+    /// # Arguments
+    ///
+    /// * `petal_corners_count`: number of corners of every polygon; should be at least 3.
+    ///
+    pub fn set_petal_corners_count(&mut self, petal_corners_count: u32) {
+        self.petal_corners_count = petal_corners_count.max(3);
+    }
+
+    /// Number of petals surrounding central polygon of flower.
+    #[inline(always)]
+    pub fn petals_count(&self) -> u32 {
+        self.petals_count
+    }
+
+    /// Sets number of petals surrounding central polygon of flower.
+    ///
+    /// # Arguments
+    ///
+    /// * `petals_count`: number of petals; should be at least 1.
+    ///
+    pub fn set_petals_count(&mut self, petals_count: u32) {
+        self.petals_count = petals_count.max(1);
+    }
+
+    /// Radius of ring of petal centers as fraction of radius of mosaic shape.
+    #[inline(always)]
+    pub fn ring_radius_fraction(&self) -> f64 {
+        self.ring_radius_fraction
+    }
+
+    /// Sets radius of ring of petal centers as fraction of radius of mosaic shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `ring_radius_fraction`: radius of ring of petal centers; should be in range
+    ///   from 0.0 to 1.0.
+    ///
+    pub fn set_ring_radius_fraction(&mut self, ring_radius_fraction: f64) {
+        self.ring_radius_fraction = ring_radius_fraction.clamp(0.0, 1.0);
+    }
+}
+
+impl Default for Flower {
+    fn default() -> Self {
+        Self {
+            petal_corners_count: 6,
+            petals_count: 6,
+            ring_radius_fraction: 0.5,
+        }
+    }
+}
+
+impl MosaicShape for Flower {
+    fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
+        let radius = image_width.min(image_height) as f64 * 0.5;
+        let ring_radius = radius * self.ring_radius_fraction;
+        let petal_radius = radius - ring_radius;
+        let mut points =
+            helpers::set_up_polygon_points(self.petal_corners_count, petal_radius, 0.0);
+        for index in 0..self.petals_count {
+            let angle = consts::TAU * index as f64 / self.petals_count as f64;
+            let center = Vector::new(0.0, -ring_radius).rotate(angle);
+            let mut petal_points = helpers::set_up_polygon_points_around(
+                center,
+                self.petal_corners_count,
+                petal_radius,
+                angle,
+            );
+            points.append(&mut petal_points);
+        }
+        points
+    }
+
+    fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment> {
+        let corners_count = self.petal_corners_count as usize;
+        let mut segments = Vec::new();
+        for (index, polygon) in shape_points.chunks_exact(corners_count).enumerate() {
+            for (corner_index, start) in polygon.iter().enumerate() {
+                let end = polygon[(corner_index + 1) % corners_count];
+                segments.push(Segment::new(*start, end));
+            }
+            if index > 0 {
+                let center = polygon
+                    .iter()
+                    .fold(Vector::default(), |sum, point| sum + *point)
+                    / corners_count as f64;
+                segments.push(Segment::new(Vector::default(), center));
+            }
+        }
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_with_incorrect_parameters() {
+        let flower = Flower::new(1, 0, 1.5);
+        assert_eq!(flower.petal_corners_count(), 3);
+        assert_eq!(flower.petals_count(), 1);
+        assert_eq!(flower.ring_radius_fraction(), 1.0);
+    }
+    #[test]
+    fn set_up_points() {
+        let flower = Flower::new(5, 7, 0.6);
+        let points = flower.set_up_points(400, 400);
+        assert_eq!(points.len(), 5 * (7 + 1));
+        for point in &points[..5] {
+            assert!((point.length() - 80.0).abs() < 1e-9);
+        }
+        let petal_center = Vector::new(0.0, -120.0);
+        for point in &points[5..10] {
+            assert!((point.distance_to(petal_center) - 80.0).abs() < 1e-9);
+        }
+    }
+    #[test]
+    fn connect_points() {
+        let flower = Flower::new(4, 3, 0.5);
+        let points = flower.set_up_points(400, 400);
+        let segments = flower.connect_points(&points);
+        assert_eq!(segments.len(), 4 * 4 + 3);
+        assert!(segments.contains(&Segment::from(((0.0, 0.0), (0.0, -100.0)))));
+    }
+}
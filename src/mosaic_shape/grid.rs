@@ -1,10 +1,14 @@
 use super::{MosaicShape, Segment, Vector};
 
 /// Defines mosaic shape based on grid.
+///
+/// Optionally corners of grid cells can be connected diagonally, so cells are crossed
+/// by diagonals and mosaic becomes denser and more intricate.
 #[derive(Clone, Debug)]
 pub struct Grid {
     rows_count: u32,
     columns_count: u32,
+    diagonals: bool,
 }
 
 impl Grid {
@@ -21,9 +25,34 @@ impl Grid {
         Self {
             rows_count: rows_count.max(1),
             columns_count: columns_count.max(1),
+            diagonals: false,
         }
     }
 
+    /// Sets whether corners of grid cells are connected diagonally and returns this grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `diagonals`: whether corners of grid cells are connected diagonally.
+    ///
+    /// returns: [`Grid`] - mosaic shape based on grid with or without diagonals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::mosaic_shape::{Grid, MosaicShape};
+    ///
+    /// let grid = Grid::new(4, 4).with_diagonals(true);
+    /// let points = grid.set_up_points(400, 400);
+    /// let segments = grid.connect_points(&points);
+    ///
+    /// assert_eq!(segments.len(), 20);
+    /// ```
+    pub fn with_diagonals(mut self, diagonals: bool) -> Self {
+        self.diagonals = diagonals;
+        self
+    }
+
     /// Number of rows of grid on which mosaic shape is based.
     #[inline(always)]
     pub fn rows_count(&self) -> u32 {
@@ -55,6 +84,90 @@ impl Grid {
     pub fn set_columns_count(&mut self, columns_count: u32) {
         self.columns_count = columns_count.max(1);
     }
+
+    /// Whether corners of grid cells are connected diagonally.
+    #[inline(always)]
+    pub fn diagonals(&self) -> bool {
+        self.diagonals
+    }
+
+    /// Sets whether corners of grid cells are connected diagonally.
+    pub fn set_diagonals(&mut self, diagonals: bool) {
+        self.diagonals = diagonals;
+    }
+
+    /// Calculates size of grid cell which fits whole grid into image of given size.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_width`: width of mosaic image.
+    /// * `image_height`: height of mosaic image.
+    ///
+    /// returns: `f64` - size of side of square grid cell.
+    ///
+    pub fn cell_size(&self, image_width: u32, image_height: u32) -> f64 {
+        let (horizontal_step_size, vertical_step_size) = (
+            image_width as f64 / self.columns_count as f64,
+            image_height as f64 / self.rows_count as f64,
+        );
+        horizontal_step_size.min(vertical_step_size)
+    }
+
+    /// Calculates bounds of grid which fits into image of given size.
+    ///
+    /// Grid is centered at origin, so its bounds are symmetric.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_width`: width of mosaic image.
+    /// * `image_height`: height of mosaic image.
+    ///
+    /// returns: `(Vector, Vector)` - minimum and maximum corners of grid.
+    ///
+    /// # See also
+    ///
+    /// * [`Grid::cell_size`].
+    ///
+    pub fn bounds(&self, image_width: u32, image_height: u32) -> (Vector, Vector) {
+        let step_size = self.cell_size(image_width, image_height);
+        let half_size = Vector::new(
+            step_size * self.columns_count as f64 * 0.5,
+            step_size * self.rows_count as f64 * 0.5,
+        );
+        (-half_size, half_size)
+    }
+}
+
+impl Grid {
+    fn connect_diagonals(&self, minimum: Vector, maximum: Vector) -> Vec<Segment> {
+        let (columns_count, rows_count) = (self.columns_count as i64, self.rows_count as i64);
+        let step_size = (maximum.x - minimum.x) / columns_count as f64;
+        let corner = |column: i64, row: i64| {
+            Vector::new(
+                minimum.x + step_size * column as f64,
+                minimum.y + step_size * row as f64,
+            )
+        };
+        let mut segments = vec![];
+        for shift in (1 - rows_count)..columns_count {
+            let (column, row) = (shift.max(0), (-shift).max(0));
+            let length = (columns_count - column).min(rows_count - row);
+            segments.push(Segment::new(
+                corner(column, row),
+                corner(column + length, row + length),
+            ));
+        }
+        for sum in 1..(columns_count + rows_count) {
+            let column = (sum - rows_count).max(0);
+            let row = sum - column;
+            let length = (columns_count - column).min(row);
+            segments.push(Segment::new(
+                corner(column, row),
+                corner(column + length, row - length),
+            ));
+        }
+        segments
+    }
 }
 
 impl Default for Grid {
@@ -62,27 +175,22 @@ impl Default for Grid {
         Self {
             rows_count: 4,
             columns_count: 4,
+            diagonals: false,
         }
     }
 }
 
 impl MosaicShape for Grid {
     fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
-        let (image_width, image_height) = (image_width as f64, image_height as f64);
-        let (horizontal_step_size, vertical_step_size) = (
-            image_width / self.columns_count as f64,
-            image_height / self.rows_count as f64,
-        );
-        let step_size = horizontal_step_size.min(vertical_step_size);
-        let (horizontal_half_size, vertical_half_size) = (
-            step_size * self.columns_count as f64 * 0.5,
-            step_size * self.rows_count as f64 * 0.5,
-        );
-        let mut points = vec![];
-        points.push(Vector::new(-horizontal_half_size, -vertical_half_size));
-        points.push(Vector::new(-horizontal_half_size, vertical_half_size));
-        points.push(Vector::new(horizontal_half_size, -vertical_half_size));
-        points.push(Vector::new(horizontal_half_size, vertical_half_size));
+        let step_size = self.cell_size(image_width, image_height);
+        let (_, half_size) = self.bounds(image_width, image_height);
+        let (horizontal_half_size, vertical_half_size) = (half_size.x, half_size.y);
+        let mut points = vec![
+            Vector::new(-horizontal_half_size, -vertical_half_size),
+            Vector::new(-horizontal_half_size, vertical_half_size),
+            Vector::new(horizontal_half_size, -vertical_half_size),
+            Vector::new(horizontal_half_size, vertical_half_size),
+        ];
         for index in 1..self.rows_count {
             let index = index as f64;
             points.push(Vector::new(
@@ -114,6 +222,9 @@ impl MosaicShape for Grid {
         for index in (4..points_count).step_by(2) {
             segments.push(Segment::new(shape_points[index], shape_points[index + 1]));
         }
+        if self.diagonals && points_count >= 4 {
+            segments.extend(self.connect_diagonals(shape_points[0], shape_points[3]));
+        }
         segments
     }
 }
@@ -147,6 +258,26 @@ mod tests {
         assert_eq!(grid.columns_count(), 1);
     }
     #[test]
+    fn cell_size() {
+        let grid = Grid::new(4, 4);
+        assert_eq!(grid.cell_size(400, 400), 100.0);
+        assert_eq!(grid.cell_size(800, 400), 100.0);
+    }
+    #[test]
+    fn bounds() {
+        let grid = Grid::new(4, 4);
+        let (minimum, maximum) = grid.bounds(400, 400);
+        assert_eq!(minimum, Vector::new(-200.0, -200.0));
+        assert_eq!(maximum, Vector::new(200.0, 200.0));
+        let points = grid.set_up_points(400, 400);
+        assert!(points.contains(&minimum));
+        assert!(points.contains(&maximum));
+        let grid = Grid::new(2, 4);
+        let (minimum, maximum) = grid.bounds(400, 400);
+        assert_eq!(minimum, Vector::new(-200.0, -100.0));
+        assert_eq!(maximum, Vector::new(200.0, 100.0));
+    }
+    #[test]
     fn set_up_points() {
         let grid = Grid::new(4, 4);
         let points = grid.set_up_points(400, 400);
@@ -168,6 +299,41 @@ mod tests {
         assert!(segments.contains(&segment));
     }
     #[test]
+    fn set_diagonals() {
+        let mut grid = Grid::default();
+        assert!(!grid.diagonals());
+        grid.set_diagonals(true);
+        assert!(grid.diagonals());
+        assert!(Grid::new(3, 5).with_diagonals(true).diagonals());
+    }
+    #[test]
+    fn connect_points_with_diagonals() {
+        let grid = Grid::new(2, 4).with_diagonals(true);
+        let points = grid.set_up_points(400, 400);
+        let segments = grid.connect_points(&points);
+        assert_eq!(segments.len(), 4 + 5 + 5);
+        let segment = Segment::from(((-200.0, -100.0), (0.0, 100.0)));
+        assert!(segments.contains(&segment));
+        let segment = Segment::from(((100.0, -100.0), (200.0, 0.0)));
+        assert!(segments.contains(&segment));
+        let segment = Segment::from(((-200.0, 0.0), (-100.0, -100.0)));
+        assert!(segments.contains(&segment));
+        let segment = Segment::from(((0.0, 100.0), (200.0, -100.0)));
+        assert!(segments.contains(&segment));
+    }
+    #[test]
+    fn intersect_segments_with_diagonals() {
+        let grid = Grid::new(4, 4);
+        let points = grid.set_up_points(400, 400);
+        let intersections = grid.intersect_segments(&grid.connect_points(&points));
+        let diagonal_grid = Grid::new(4, 4).with_diagonals(true);
+        let diagonal_intersections =
+            diagonal_grid.intersect_segments(&diagonal_grid.connect_points(&points));
+        assert!(diagonal_intersections.len() > intersections.len());
+        assert!(diagonal_intersections.contains(&Vector::new(50.0, 50.0)));
+        assert!(diagonal_intersections.contains(&Vector::new(-150.0, 150.0)));
+    }
+    #[test]
     fn intersect_segments() {
         let grid = Grid::new(4, 4);
         let points = grid.set_up_points(400, 400);
@@ -1,14 +1,115 @@
-use std::f64::consts;
+use std::{
+    collections::HashMap,
+    f64::consts,
+    sync::{Mutex, MutexGuard},
+};
 
 use super::Vector;
 
+#[inline(always)]
 pub fn set_up_polygon_points(corners_count: u32, radius: f64, rotation_angle: f64) -> Vec<Vector> {
+    set_up_polygon_points_around(Vector::default(), corners_count, radius, rotation_angle)
+}
+
+pub fn set_up_polygon_points_around(
+    center: Vector,
+    corners_count: u32,
+    radius: f64,
+    rotation_angle: f64,
+) -> Vec<Vector> {
     let mut points = Vec::new();
     for index in 0..corners_count {
         let angle = rotation_angle
             + consts::PI / corners_count as f64 * (2 * index + 1 - corners_count % 2) as f64
             - consts::FRAC_PI_2;
-        points.push(Vector::new(radius * angle.cos(), radius * angle.sin()));
+        points.push(center + Vector::new(radius * angle.cos(), radius * angle.sin()));
     }
     points
 }
+
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^ (value >> 31)
+    }
+
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn next_index(&mut self, length: usize) -> usize {
+        (self.next_u64() % length as u64) as usize
+    }
+}
+
+/// Remembers how many leading key points set up by composite shape belong to its first part.
+///
+/// Composite shapes get back only list of key points when they connect them, so splits
+/// are looked up by total count of points. Shapes which wrap composite shape (like
+/// [`Mirrored`][`super::Mirrored`]) connect transformed copies of its points, so points
+/// themselves can't be used to find splits.
+#[derive(Debug, Default)]
+pub struct PointsSplits {
+    splits: Mutex<HashMap<usize, usize>>,
+}
+
+impl PointsSplits {
+    pub fn insert(&self, points_count: usize, split: usize) {
+        self.lock().insert(points_count, split);
+    }
+
+    pub fn get(&self, points_count: usize) -> Option<usize> {
+        self.lock().get(&points_count).copied()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<usize, usize>> {
+        self.splits
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Clone for PointsSplits {
+    fn clone(&self) -> Self {
+        Self {
+            splits: Mutex::new(self.lock().clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_up_polygon_points_around() {
+        let center = Vector::new(120.0, -45.0);
+        let points = set_up_polygon_points(5, 100.0, 0.3);
+        let shifted_points = super::set_up_polygon_points_around(center, 5, 100.0, 0.3);
+        assert_eq!(shifted_points.len(), 5);
+        for (point, shifted_point) in points.iter().zip(&shifted_points) {
+            assert_eq!(*point + center, *shifted_point);
+        }
+    }
+    #[test]
+    fn points_splits() {
+        let splits = PointsSplits::default();
+        splits.insert(10, 4);
+        splits.insert(16, 7);
+        let cloned_splits = splits.clone();
+        assert_eq!(cloned_splits.get(10), Some(4));
+        assert_eq!(cloned_splits.get(16), Some(7));
+        assert_eq!(cloned_splits.get(12), None);
+    }
+}
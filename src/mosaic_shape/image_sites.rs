@@ -0,0 +1,176 @@
+use image::GrayImage;
+
+use super::{MosaicShape, Segment, Vector};
+
+/// Defines mosaic shape based on bright pixels of grayscale image.
+///
+/// Every pixel of image which is brighter than threshold becomes key point of mosaic, so
+/// stippled image or image of edges (for example, made from photo) turns into sites
+/// of Voronoi diagram. When there are more bright pixels than requested count of key points,
+/// these pixels are evenly subsampled. Image is centered around origin and scaled to fit
+/// into size of mosaic keeping its aspect ratio.
+#[derive(Clone, Debug)]
+pub struct ImageSites {
+    image: GrayImage,
+    threshold: u8,
+    count: u32,
+}
+
+impl ImageSites {
+    /// Creates shape based on bright pixels of image.
+    ///
+    /// # Arguments
+    ///
+    /// * `image`: grayscale image which pixels become key points of mosaic.
+    /// * `threshold`: brightness which pixel should exceed to become key point.
+    /// * `count`: maximum number of key points; should be at least 1.
+    ///
+    /// returns: [`ImageSites`] - mosaic shape based on bright pixels of image.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use image::{GrayImage, Luma};
+    /// use starry_mosaic::mosaic_shape::{ImageSites, MosaicShape};
+    ///
+    /// let mut image = GrayImage::new(16, 16);
+    /// image.put_pixel(4, 4, Luma([255]));
+    /// image.put_pixel(12, 4, Luma([255]));
+    /// image.put_pixel(8, 12, Luma([255]));
+    ///
+    /// let image_sites = ImageSites::new(image, 127, 100);
+    ///
+    /// assert_eq!(image_sites.set_up_points(640, 640).len(), 3);
+    /// ```
+    pub fn new(image: GrayImage, threshold: u8, count: u32) -> Self {
+        Self {
+            image,
+            threshold,
+            count: count.max(1),
+        }
+    }
+
+    /// Grayscale image which pixels become key points of mosaic.
+    #[inline(always)]
+    pub fn image(&self) -> &GrayImage {
+        &self.image
+    }
+
+    /// Sets grayscale image which pixels become key points of mosaic.
+    pub fn set_image(&mut self, image: GrayImage) {
+        self.image = image;
+    }
+
+    /// Brightness which pixel should exceed to become key point of mosaic.
+    #[inline(always)]
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Sets brightness which pixel should exceed to become key point of mosaic.
+    pub fn set_threshold(&mut self, threshold: u8) {
+        self.threshold = threshold;
+    }
+
+    /// Maximum number of key points.
+    #[inline(always)]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Sets maximum number of key points.
+    ///
+    /// # Arguments
+    ///
+    /// * `count`: maximum number of key points; should be at least 1.
+    ///
+    pub fn set_count(&mut self, count: u32) {
+        self.count = count.max(1);
+    }
+}
+
+impl MosaicShape for ImageSites {
+    fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
+        let (width, height) = self.image.dimensions();
+        if width == 0 || height == 0 {
+            return Vec::new();
+        }
+        let bright_pixels: Vec<(u32, u32)> = self
+            .image
+            .enumerate_pixels()
+            .filter(|(_, _, pixel)| pixel[0] > self.threshold)
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        let count = bright_pixels.len().min(self.count as usize);
+        let scale = f64::min(
+            image_width as f64 / width as f64,
+            image_height as f64 / height as f64,
+        );
+        let center = Vector::new(width as f64 * 0.5, height as f64 * 0.5);
+        (0..count)
+            .map(|index| {
+                let (x, y) = bright_pixels[index * bright_pixels.len() / count];
+                (Vector::new(x as f64 + 0.5, y as f64 + 0.5) - center) * scale
+            })
+            .collect()
+    }
+
+    fn connect_points(&self, _shape_points: &Vec<Vector>) -> Vec<Segment> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::Luma;
+
+    use super::*;
+
+    fn create_image() -> GrayImage {
+        let mut image = GrayImage::from_pixel(10, 5, Luma([40]));
+        image.put_pixel(0, 0, Luma([200]));
+        image.put_pixel(9, 0, Luma([180]));
+        image.put_pixel(4, 2, Luma([100]));
+        image.put_pixel(9, 4, Luma([255]));
+        image
+    }
+
+    #[test]
+    fn set_incorrect_count() {
+        let mut image_sites = ImageSites::new(create_image(), 127, 10);
+        image_sites.set_count(0);
+        assert_eq!(image_sites.count(), 1);
+    }
+    #[test]
+    fn set_up_points() {
+        let image_sites = ImageSites::new(create_image(), 127, 10);
+        let points = image_sites.set_up_points(400, 400);
+        assert_eq!(
+            points,
+            vec![
+                Vector::new(-180.0, -80.0),
+                Vector::new(180.0, -80.0),
+                Vector::new(180.0, 80.0),
+            ]
+        );
+        let image_sites = ImageSites::new(create_image(), 50, 10);
+        assert_eq!(image_sites.set_up_points(400, 400).len(), 4);
+    }
+    #[test]
+    fn set_up_subsampled_points() {
+        let image = GrayImage::from_pixel(20, 20, Luma([255]));
+        let image_sites = ImageSites::new(image, 127, 50);
+        let points = image_sites.set_up_points(640, 480);
+        assert_eq!(points.len(), 50);
+        for point in points {
+            assert!(point.x > -240.0 && point.x < 240.0);
+            assert!(point.y > -240.0 && point.y < 240.0);
+        }
+    }
+    #[test]
+    fn connect_points() {
+        let image_sites = ImageSites::new(create_image(), 127, 10);
+        let points = image_sites.set_up_points(400, 400);
+        assert!(image_sites.connect_points(&points).is_empty());
+    }
+}
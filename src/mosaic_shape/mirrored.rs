@@ -0,0 +1,169 @@
+use super::{MosaicShape, Segment, Vector};
+
+/// Defines mosaic shape which reflects another (inner) shape across X and/or Y axes.
+///
+/// Mirroring enforces bilateral (or 4-fold) symmetry of mosaic even for asymmetric
+/// inner shapes.
+#[derive(Clone, Debug)]
+pub struct Mirrored {
+    shape: Box<dyn MosaicShape>,
+    x_axis: bool,
+    y_axis: bool,
+}
+
+impl Mirrored {
+    /// Creates mosaic shape which reflects inner shape across chosen axes.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: inner mosaic shape which key points are reflected; it can be boxed shape
+    ///   (`Box<dyn MosaicShape>`) as well.
+    /// * `x_axis`: whether to reflect inner shape across X (horizontal) axis.
+    /// * `y_axis`: whether to reflect inner shape across Y (vertical) axis.
+    ///
+    /// returns: [`Mirrored`] - mosaic shape symmetric relative to chosen axes.
+    ///
+    pub fn new<Shape>(shape: Shape, x_axis: bool, y_axis: bool) -> Self
+    where
+        Shape: 'static + MosaicShape,
+    {
+        Self {
+            shape: Box::new(shape),
+            x_axis,
+            y_axis,
+        }
+    }
+
+    /// Inner mosaic shape which key points are reflected.
+    #[allow(clippy::borrowed_box)]
+    pub fn shape(&self) -> &Box<dyn MosaicShape> {
+        &self.shape
+    }
+
+    /// Whether inner shape is reflected across X (horizontal) axis.
+    #[inline(always)]
+    pub fn x_axis(&self) -> bool {
+        self.x_axis
+    }
+
+    /// Sets whether inner shape is reflected across X (horizontal) axis.
+    pub fn set_x_axis(&mut self, x_axis: bool) {
+        self.x_axis = x_axis;
+    }
+
+    /// Whether inner shape is reflected across Y (vertical) axis.
+    #[inline(always)]
+    pub fn y_axis(&self) -> bool {
+        self.y_axis
+    }
+
+    /// Sets whether inner shape is reflected across Y (vertical) axis.
+    pub fn set_y_axis(&mut self, y_axis: bool) {
+        self.y_axis = y_axis;
+    }
+
+    fn copies_count(&self) -> usize {
+        (1 + self.x_axis as usize) * (1 + self.y_axis as usize)
+    }
+}
+
+impl MosaicShape for Mirrored {
+    fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
+        let mut points = self.shape.set_up_points(image_width, image_height);
+        if self.x_axis {
+            let mut reflected_points = points
+                .iter()
+                .map(|point| Vector::new(point.x, -point.y))
+                .collect();
+            points.append(&mut reflected_points);
+        }
+        if self.y_axis {
+            let mut reflected_points = points
+                .iter()
+                .map(|point| Vector::new(-point.x, point.y))
+                .collect();
+            points.append(&mut reflected_points);
+        }
+        points
+    }
+
+    fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment> {
+        let points_count = shape_points.len() / self.copies_count();
+        if points_count == 0 {
+            return Vec::new();
+        }
+        shape_points
+            .chunks_exact(points_count)
+            .flat_map(|points| self.shape.connect_points(&points.to_vec()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug)]
+    struct AsymmetricShape;
+
+    impl MosaicShape for AsymmetricShape {
+        fn set_up_points(&self, _image_width: u32, _image_height: u32) -> Vec<Vector> {
+            vec![
+                Vector::new(10.0, 20.0),
+                Vector::new(30.0, -5.0),
+                Vector::new(-40.0, 15.0),
+            ]
+        }
+
+        fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment> {
+            vec![Segment::new(shape_points[0], shape_points[1])]
+        }
+    }
+
+    #[test]
+    fn set_up_points_mirrored_across_x_axis() {
+        let shape = Mirrored::new(AsymmetricShape, true, false);
+        let points = shape.set_up_points(400, 400);
+        assert_eq!(points.len(), 6);
+        for point in &points {
+            assert!(points.contains(&Vector::new(point.x, -point.y)));
+        }
+        assert!(!points.contains(&Vector::new(-10.0, 20.0)));
+    }
+    #[test]
+    fn set_up_points_mirrored_across_both_axes() {
+        let shape = Mirrored::new(AsymmetricShape, true, true);
+        let points = shape.set_up_points(400, 400);
+        assert_eq!(points.len(), 12);
+        for point in &points {
+            assert!(points.contains(&Vector::new(point.x, -point.y)));
+            assert!(points.contains(&Vector::new(-point.x, point.y)));
+        }
+    }
+    #[test]
+    fn set_up_points_of_boxed_shape() {
+        let boxed_shape: Box<dyn MosaicShape> =
+            Box::new(Mirrored::new(AsymmetricShape, true, false));
+        let shape = Mirrored::new(boxed_shape.clone(), false, true);
+        let points = shape.set_up_points(400, 400);
+        assert_eq!(points.len(), 12);
+        for point in &points {
+            assert!(points.contains(&Vector::new(point.x, -point.y)));
+            assert!(points.contains(&Vector::new(-point.x, point.y)));
+        }
+        assert_eq!(
+            shape.connect_points(&points),
+            Mirrored::new(Mirrored::new(AsymmetricShape, true, false), false, true)
+                .connect_points(&points)
+        );
+    }
+    #[test]
+    fn connect_points() {
+        let shape = Mirrored::new(AsymmetricShape, false, true);
+        let points = shape.set_up_points(400, 400);
+        let segments = shape.connect_points(&points);
+        assert_eq!(segments.len(), 2);
+        assert!(segments.contains(&Segment::from(((10.0, 20.0), (30.0, -5.0)))));
+        assert!(segments.contains(&Segment::from(((-10.0, 20.0), (-30.0, -5.0)))));
+    }
+}
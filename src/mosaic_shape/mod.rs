@@ -17,9 +17,9 @@ use super::{segment::Segment, vector::Vector};
 ///
 /// 1. Setting up of basic key points using method [`MosaicShape::set_up_points`].
 /// 2. Connecting these basic key points with line segments using method
-/// [`MosaicShape::connect_points`].
+///    [`MosaicShape::connect_points`].
 /// 3. Constructing rest key points by intersecting line segments from step 2 using method
-/// [`MosaicShape::intersect_segments`].
+///    [`MosaicShape::intersect_segments`].
 ///
 /// All key points of mosaic shape should be contained within size of mosaic and centered
 /// origin (0.0, 0.0).
@@ -27,7 +27,8 @@ use super::{segment::Segment, vector::Vector};
 /// **_Note_**: structs which implement `MosaicShape` should *not* store any points or
 /// line segments. Instead all necessary geometry should be calculated by request.
 ///
-/// Implementers of `MosaicShape` trait are required to implement [`Clone`] and [`Debug`] traits.
+/// Implementers of `MosaicShape` trait are required to implement [`Clone`] and [`Debug`] traits
+/// and to be thread-safe ([`Send`] and [`Sync`]), so mosaics can be built in parallel.
 ///
 /// # Examples
 ///
@@ -91,7 +92,7 @@ use super::{segment::Segment, vector::Vector};
 ///     assert!(segments.contains(&vertical_segment));
 /// }
 /// ```
-pub trait MosaicShape: Debug + MosaicShapeBase {
+pub trait MosaicShape: Debug + MosaicShapeBase + Send + Sync {
     /// Sets up primary key points of mosaic shape. All key points shape should be contained
     /// within size of mosaic and centered origin (0.0, 0.0).
     ///
@@ -109,6 +110,32 @@ pub trait MosaicShape: Debug + MosaicShapeBase {
     ///
     fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector>;
 
+    /// Sets up weights of primary key points of mosaic shape.
+    ///
+    /// Weights make starry mosaic drawn as weighted Voronoi diagram
+    /// (power diagram): cells of key points with larger weights grow at the expense
+    /// of their neighbours. Key points which are constructed by intersection of line segments
+    /// have zero weight.
+    ///
+    /// Default implementation returns `None`, so key points of shape aren't weighted.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_width`: width of mosaic (and mosaic images one creates).
+    /// * `image_height`: height of mosaic (and mosaic images one creates).
+    ///
+    /// returns: `Option<Vec<f64>>` - weights of primary key points (in squared pixels of mosaic
+    /// image) listed in the same order as points set up by [`MosaicShape::set_up_points`],
+    /// or `None` if key points of shape aren't weighted.
+    ///
+    /// # See also
+    ///
+    /// * [`WeightedPoints`].
+    ///
+    fn set_up_weights(&self, _image_width: u32, _image_height: u32) -> Option<Vec<f64>> {
+        None
+    }
+
     /// Connects primary key points with line segments to form mosaic shape.
     ///
     /// # Arguments
@@ -121,6 +148,7 @@ pub trait MosaicShape: Debug + MosaicShapeBase {
     ///
     /// * [`MosaicShape`].
     ///
+    #[allow(clippy::ptr_arg)]
     fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment>;
 
     /// Intersects line segments of mosaic shape to construct its rest key points.
@@ -135,6 +163,7 @@ pub trait MosaicShape: Debug + MosaicShapeBase {
     ///
     /// * [`MosaicShape`].
     ///
+    #[allow(clippy::ptr_arg)]
     fn intersect_segments(&self, shape_segments: &Vec<Segment>) -> Vec<Vector> {
         let mut points = Vec::new();
         for (index, first_segment) in shape_segments.iter().enumerate() {
@@ -146,6 +175,59 @@ pub trait MosaicShape: Debug + MosaicShapeBase {
         }
         points
     }
+
+    /// Calculates natural bounding radius of mosaic shape: maximum distance from origin
+    /// (0.0, 0.0) to its primary key points.
+    ///
+    /// Default implementation sets up primary key points on every call; shapes which know
+    /// their radius should override it.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_width`: width of mosaic (and mosaic images one creates).
+    /// * `image_height`: height of mosaic (and mosaic images one creates).
+    ///
+    /// returns: `f64` - radius of circle centered at origin which contains all primary
+    /// key points of mosaic shape.
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicShape::set_up_points`].
+    ///
+    fn bounding_radius(&self, image_width: u32, image_height: u32) -> f64 {
+        self.set_up_points(image_width, image_height)
+            .iter()
+            .fold(0.0, |radius, point| radius.max(point.length()))
+    }
+
+    /// Estimates complexity of mosaic shape: count of its primary key points and count
+    /// of line segments connecting them.
+    ///
+    /// Estimate doesn't calculate intersections of line segments, which take most of time
+    /// of building mosaic (their count grows quadratically with count of line segments), so it
+    /// allows to warn about heavy shapes before building mosaic.
+    ///
+    /// Default implementation sets up primary key points and line segments on every call;
+    /// shapes which know these counts should override it.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_width`: width of mosaic (and mosaic images one creates).
+    /// * `image_height`: height of mosaic (and mosaic images one creates).
+    ///
+    /// returns: `(usize, usize)` - count of primary key points and count of line segments
+    /// of mosaic shape.
+    ///
+    /// # See also
+    ///
+    /// * [`MosaicShape::set_up_points`].
+    /// * [`MosaicShape::connect_points`].
+    ///
+    fn estimate_complexity(&self, image_width: u32, image_height: u32) -> (usize, usize) {
+        let points = self.set_up_points(image_width, image_height);
+        let segments_count = self.connect_points(&points).len();
+        (points.len(), segments_count)
+    }
 }
 
 /// Helper trait that implements [`Clone`] for `Box<dyn` [`MosaicShape`]`>`.
@@ -165,15 +247,69 @@ where
 impl Clone for Box<dyn MosaicShape> {
     #[inline(always)]
     fn clone(&self) -> Self {
-        self.clone_box()
+        self.as_ref().clone_box()
+    }
+}
+
+impl MosaicShape for Box<dyn MosaicShape> {
+    #[inline(always)]
+    fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
+        self.as_ref().set_up_points(image_width, image_height)
+    }
+
+    #[inline(always)]
+    fn set_up_weights(&self, image_width: u32, image_height: u32) -> Option<Vec<f64>> {
+        self.as_ref().set_up_weights(image_width, image_height)
+    }
+
+    #[inline(always)]
+    fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment> {
+        self.as_ref().connect_points(shape_points)
+    }
+
+    #[inline(always)]
+    fn intersect_segments(&self, shape_segments: &Vec<Segment>) -> Vec<Vector> {
+        self.as_ref().intersect_segments(shape_segments)
+    }
+
+    #[inline(always)]
+    fn bounding_radius(&self, image_width: u32, image_height: u32) -> f64 {
+        self.as_ref().bounding_radius(image_width, image_height)
+    }
+
+    #[inline(always)]
+    fn estimate_complexity(&self, image_width: u32, image_height: u32) -> (usize, usize) {
+        self.as_ref().estimate_complexity(image_width, image_height)
     }
 }
 
+mod compound_star;
+mod flower;
 mod grid;
 mod helpers;
+#[cfg(feature = "rendering")]
+mod image_sites;
+mod mirrored;
 mod polygonal_star;
+mod radial_symmetry;
 mod regular_polygon;
+mod scattered_points;
+mod subdivided;
+mod translated;
+mod union;
+mod weighted_points;
 
+pub use compound_star::CompoundStar;
+pub use flower::Flower;
 pub use grid::Grid;
+#[cfg(feature = "rendering")]
+pub use image_sites::ImageSites;
+pub use mirrored::Mirrored;
 pub use polygonal_star::PolygonalStar;
+pub use radial_symmetry::RadialSymmetry;
 pub use regular_polygon::RegularPolygon;
+pub use scattered_points::ScatteredPoints;
+pub use subdivided::Subdivided;
+pub use translated::Translated;
+pub use union::Union;
+pub use weighted_points::WeightedPoints;
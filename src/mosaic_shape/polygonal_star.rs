@@ -6,6 +6,7 @@ use super::{helpers, MosaicShape, Segment, Vector};
 #[derive(Clone, Debug)]
 pub struct PolygonalStar {
     corners_count: u32,
+    twist: f64,
 }
 
 impl PolygonalStar {
@@ -21,6 +22,7 @@ impl PolygonalStar {
     pub fn new(corners_count: u32) -> Self {
         Self {
             corners_count: corners_count.max(3),
+            twist: 0.0,
         }
     }
 
@@ -42,11 +44,33 @@ impl PolygonalStar {
     pub fn set_corners_count(&mut self, corners_count: u32) {
         self.corners_count = corners_count.max(3);
     }
+
+    /// Additional rotation angle of inner (concave) corners of polygonal star.
+    #[inline(always)]
+    pub fn twist(&self) -> f64 {
+        self.twist
+    }
+
+    /// Sets additional rotation angle of inner (concave) corners of polygonal star.
+    ///
+    /// Non-zero twist rotates inner corners relative to outer ones and so creates
+    /// pinwheel (twisted) star.
+    ///
+    /// # Arguments
+    ///
+    /// * `twist`: additional rotation angle of inner star corners, in radians.
+    ///
+    pub fn set_twist(&mut self, twist: f64) {
+        self.twist = twist;
+    }
 }
 
 impl Default for PolygonalStar {
     fn default() -> Self {
-        Self { corners_count: 8 }
+        Self {
+            corners_count: 8,
+            twist: 0.0,
+        }
     }
 }
 
@@ -54,7 +78,7 @@ impl MosaicShape for PolygonalStar {
     fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
         let corners_count = self.corners_count as f64;
         let radius = image_width.min(image_height) as f64 * 0.5;
-        let inner_rotation_angle = consts::PI / corners_count;
+        let inner_rotation_angle = consts::PI / corners_count + self.twist;
         let inner_radius = radius
             * (consts::PI * (corners_count * 0.5 - 2.0) / corners_count).sin()
             / (consts::FRAC_PI_2 * (corners_count - 2.0) / corners_count).sin();
@@ -86,6 +110,12 @@ impl MosaicShape for PolygonalStar {
         }
         segments
     }
+
+    fn estimate_complexity(&self, _image_width: u32, _image_height: u32) -> (usize, usize) {
+        let corners_count = self.corners_count as usize;
+        let segments_count = corners_count + corners_count * corners_count.saturating_sub(4);
+        (corners_count * 2, segments_count)
+    }
 }
 
 #[cfg(test)]
@@ -109,18 +139,24 @@ mod tests {
         let star = PolygonalStar::new(4);
         let points = star.set_up_points(400, 400);
         assert_eq!(points.len(), 8);
-        for index in 0..4 {
+        for (index, point) in points[..4].iter().enumerate() {
             let angle = consts::FRAC_PI_2 * (index as f64 - 0.5);
             assert_eq!(
-                points[index],
+                *point,
                 Vector::new(200.0 * angle.cos(), 200.0 * angle.sin())
             );
         }
-        for index in 4..8 {
-            assert_eq!(points[index], Vector::new(0.0, 0.0));
+        for point in &points[4..] {
+            assert_eq!(*point, Vector::new(0.0, 0.0));
         }
     }
     #[test]
+    fn bounding_radius() {
+        let star = PolygonalStar::new(5);
+        let radius = star.bounding_radius(400, 300);
+        assert!((radius - 150.0).abs() < 1e-9);
+    }
+    #[test]
     fn connect_points() {
         let star = PolygonalStar::new(4);
         let points = star.set_up_points(400, 400);
@@ -170,4 +206,29 @@ mod tests {
         let intersections = star.intersect_segments(&segments);
         assert!(intersections.contains(&Vector::new(0.0, 0.0)));
     }
+    #[test]
+    fn set_up_points_with_twist() {
+        let mut star = PolygonalStar::default();
+        let points = star.set_up_points(400, 400);
+        star.set_twist(0.2);
+        assert_eq!(star.twist(), 0.2);
+        let twisted_points = star.set_up_points(400, 400);
+        assert_eq!(points[..8], twisted_points[..8]);
+        for (point, twisted_point) in points[8..].iter().zip(&twisted_points[8..]) {
+            assert_ne!(point, twisted_point);
+            assert_eq!(point.rotate(0.2), *twisted_point);
+        }
+    }
+    #[test]
+    fn estimate_complexity() {
+        for corners_count in 3..=12 {
+            let star = PolygonalStar::new(corners_count);
+            let points = star.set_up_points(400, 400);
+            let segments = star.connect_points(&points);
+            assert_eq!(
+                star.estimate_complexity(400, 400),
+                (points.len(), segments.len())
+            );
+        }
+    }
 }
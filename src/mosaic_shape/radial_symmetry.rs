@@ -0,0 +1,120 @@
+use std::f64::consts;
+
+use super::{MosaicShape, Segment, Vector};
+
+/// Defines mosaic shape which replicates another (inner) shape with rotational symmetry
+/// around origin (kaleidoscope).
+///
+/// Every key point of inner shape is rotated by `k * TAU / order` for every `k` in `0..order`,
+/// so even sketchy inner shape becomes mandala.
+#[derive(Clone, Debug)]
+pub struct RadialSymmetry {
+    shape: Box<dyn MosaicShape>,
+    order: u32,
+}
+
+impl RadialSymmetry {
+    /// Creates mosaic shape which replicates inner shape with rotational symmetry of given order.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: inner mosaic shape which key points are replicated.
+    /// * `order`: number of rotated copies of inner shape; should be at least 1.
+    ///
+    /// returns: [`RadialSymmetry`] - mosaic shape with `order`-fold rotational symmetry.
+    ///
+    pub fn new<Shape>(shape: Shape, order: u32) -> Self
+    where
+        Shape: 'static + MosaicShape,
+    {
+        Self {
+            shape: Box::new(shape),
+            order: order.max(1),
+        }
+    }
+
+    /// Inner mosaic shape which key points are replicated.
+    #[allow(clippy::borrowed_box)]
+    pub fn shape(&self) -> &Box<dyn MosaicShape> {
+        &self.shape
+    }
+
+    /// Number of rotated copies of inner shape.
+    #[inline(always)]
+    pub fn order(&self) -> u32 {
+        self.order
+    }
+
+    /// Sets number of rotated copies of inner shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `order`: number of rotated copies of inner shape; should be at least 1.
+    ///
+    pub fn set_order(&mut self, order: u32) {
+        self.order = order.max(1);
+    }
+}
+
+impl MosaicShape for RadialSymmetry {
+    fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
+        let points = self.shape.set_up_points(image_width, image_height);
+        let rotation_step = consts::TAU / self.order as f64;
+        (0..self.order)
+            .flat_map(|index| {
+                let angle = rotation_step * index as f64;
+                points.iter().map(move |point| point.rotate(angle))
+            })
+            .collect()
+    }
+
+    fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment> {
+        let points_count = shape_points.len() / self.order as usize;
+        if points_count == 0 {
+            return Vec::new();
+        }
+        shape_points
+            .chunks_exact(points_count)
+            .flat_map(|points| self.shape.connect_points(&points.to_vec()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug)]
+    struct SinglePointShape;
+
+    impl MosaicShape for SinglePointShape {
+        fn set_up_points(&self, _image_width: u32, _image_height: u32) -> Vec<Vector> {
+            vec![Vector::new(30.0, 40.0)]
+        }
+
+        fn connect_points(&self, _shape_points: &Vec<Vector>) -> Vec<Segment> {
+            vec![]
+        }
+    }
+
+    #[test]
+    fn new_with_incorrect_order() {
+        let shape = RadialSymmetry::new(SinglePointShape, 0);
+        assert_eq!(shape.order(), 1);
+    }
+    #[test]
+    fn set_up_points() {
+        let order = 7;
+        let shape = RadialSymmetry::new(SinglePointShape, order);
+        let points = shape.set_up_points(400, 400);
+        assert_eq!(points.len(), order as usize);
+        let rotation_step = consts::TAU / order as f64;
+        let base_angle = 40.0f64.atan2(30.0);
+        for (index, point) in points.iter().enumerate() {
+            assert!((point.length() - 50.0).abs() < 1e-9);
+            let angle = (point.y.atan2(point.x) - base_angle).rem_euclid(consts::TAU);
+            let expected_angle = rotation_step * index as f64;
+            assert!((angle - expected_angle).abs() < 1e-9);
+        }
+    }
+}
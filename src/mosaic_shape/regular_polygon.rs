@@ -1,9 +1,14 @@
 use super::{helpers, MosaicShape, Segment, Vector};
 
 /// Defines mosaic shape based on regular polygon.
+///
+/// By default every pair of polygon corners is connected. When chord step is set, only
+/// corners which are exactly that number of positions apart are connected, which produces
+/// star polygon (`{n/k}`) pattern with far fewer segments.
 #[derive(Clone, Debug)]
 pub struct RegularPolygon {
     corners_count: u32,
+    chord_step: Option<u32>,
 }
 
 impl RegularPolygon {
@@ -19,6 +24,26 @@ impl RegularPolygon {
     pub fn new(corners_count: u32) -> Self {
         Self {
             corners_count: corners_count.max(3),
+            chord_step: None,
+        }
+    }
+
+    /// Creates regular polygon with set number of corners which connects only corners
+    /// that are `chord_step` positions apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `corners_count`: number of polygon corners; should be at least 3.
+    /// * `chord_step`: distance (in corners) between connected corners; should be in range
+    ///   from 1 to half of corners count.
+    ///
+    /// returns: [`RegularPolygon`] - mosaic shape based on star polygon `{n/k}`.
+    ///
+    pub fn with_chord_step(corners_count: u32, chord_step: u32) -> Self {
+        let corners_count = corners_count.max(3);
+        Self {
+            corners_count,
+            chord_step: Some(chord_step.clamp(1, corners_count / 2)),
         }
     }
 
@@ -37,11 +62,33 @@ impl RegularPolygon {
     pub fn set_corners_count(&mut self, corners_count: u32) {
         self.corners_count = corners_count.max(3);
     }
+
+    /// Distance (in corners) between connected corners of regular polygon.
+    ///
+    /// `None` means that every pair of corners is connected.
+    #[inline(always)]
+    pub fn chord_step(&self) -> Option<u32> {
+        self.chord_step
+    }
+
+    /// Sets distance (in corners) between connected corners of regular polygon.
+    ///
+    /// # Arguments
+    ///
+    /// * `chord_step`: distance between connected corners; should be in range from 1 to half
+    ///   of corners count; `None` connects every pair of corners.
+    ///
+    pub fn set_chord_step(&mut self, chord_step: Option<u32>) {
+        self.chord_step = chord_step.map(|chord_step| chord_step.max(1));
+    }
 }
 
 impl Default for RegularPolygon {
     fn default() -> Self {
-        Self { corners_count: 8 }
+        Self {
+            corners_count: 8,
+            chord_step: None,
+        }
     }
 }
 
@@ -51,8 +98,28 @@ impl MosaicShape for RegularPolygon {
         helpers::set_up_polygon_points(self.corners_count, radius, 0.0)
     }
 
+    fn bounding_radius(&self, image_width: u32, image_height: u32) -> f64 {
+        image_width.min(image_height) as f64 * 0.5
+    }
+
     fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment> {
         let points_count = shape_points.len();
+        if let Some(chord_step) = self.chord_step {
+            let chord_step = (chord_step as usize).clamp(1, points_count / 2);
+            let segments_count = if chord_step * 2 == points_count {
+                chord_step
+            } else {
+                points_count
+            };
+            return (0..segments_count)
+                .map(|start_index| {
+                    Segment::new(
+                        shape_points[start_index],
+                        shape_points[(start_index + chord_step) % points_count],
+                    )
+                })
+                .collect();
+        }
         let mut segments = Vec::with_capacity(points_count * (points_count - 1) / 2);
         for start_index in 0..points_count - 1 {
             for end_index in start_index + 1..points_count {
@@ -64,6 +131,20 @@ impl MosaicShape for RegularPolygon {
         }
         segments
     }
+
+    fn estimate_complexity(&self, _image_width: u32, _image_height: u32) -> (usize, usize) {
+        let points_count = self.corners_count as usize;
+        let segments_count = match self.chord_step {
+            Some(chord_step)
+                if (chord_step as usize).clamp(1, points_count / 2) * 2 == points_count =>
+            {
+                points_count / 2
+            }
+            Some(_) => points_count,
+            None => points_count * (points_count - 1) / 2,
+        };
+        (points_count, segments_count)
+    }
 }
 
 #[cfg(test)]
@@ -85,19 +166,37 @@ mod tests {
         assert_eq!(polygon.corners_count, 3);
     }
     #[test]
+    fn set_chord_step() {
+        let mut polygon = RegularPolygon::default();
+        polygon.set_chord_step(Some(3));
+        assert_eq!(polygon.chord_step, Some(3));
+        polygon.set_chord_step(Some(0));
+        assert_eq!(polygon.chord_step, Some(1));
+        polygon.set_chord_step(None);
+        assert_eq!(polygon.chord_step, None);
+        let polygon = RegularPolygon::with_chord_step(8, 7);
+        assert_eq!(polygon.chord_step, Some(4));
+    }
+    #[test]
     fn set_up_points() {
         let polygon = RegularPolygon::new(8);
         let points = polygon.set_up_points(400, 400);
         assert_eq!(points.len(), 8);
-        for index in 0..8 {
+        for (index, point) in points.iter().enumerate() {
             let angle = consts::FRAC_PI_4 * (index as f64 + 0.5) - consts::FRAC_PI_2;
             assert_eq!(
-                points[index],
+                *point,
                 Vector::new(200.0 * angle.cos(), 200.0 * angle.sin())
             );
         }
     }
     #[test]
+    fn bounding_radius() {
+        let polygon = RegularPolygon::new(7);
+        assert_eq!(polygon.bounding_radius(400, 400), 200.0);
+        assert_eq!(polygon.bounding_radius(600, 400), 200.0);
+    }
+    #[test]
     fn connect_points() {
         let polygon = RegularPolygon::new(8);
         let points = polygon.set_up_points(400, 400);
@@ -127,6 +226,28 @@ mod tests {
         assert!(segments.contains(&segment));
     }
     #[test]
+    fn connect_points_with_chord_step() {
+        let polygon = RegularPolygon::with_chord_step(5, 2);
+        let points = polygon.set_up_points(400, 400);
+        let segments = polygon.connect_points(&points);
+        assert_eq!(segments.len(), 5);
+        for index in 0..5 {
+            let segment = Segment::new(points[index], points[(index + 2) % 5]);
+            assert!(segments.contains(&segment));
+        }
+        let intersections = polygon.intersect_segments(&segments);
+        assert_eq!(intersections.len(), 5);
+    }
+    #[test]
+    fn connect_points_with_half_chord_step() {
+        let polygon = RegularPolygon::with_chord_step(8, 4);
+        let points = polygon.set_up_points(400, 400);
+        let segments = polygon.connect_points(&points);
+        assert_eq!(segments.len(), 4);
+        let intersections = polygon.intersect_segments(&segments);
+        assert!(intersections.contains(&Vector::new(0.0, 0.0)));
+    }
+    #[test]
     fn intersect_segments_with_even_corners_count() {
         let polygon = RegularPolygon::new(8);
         let points = polygon.set_up_points(400, 400);
@@ -142,4 +263,24 @@ mod tests {
         let intersections = polygon.intersect_segments(&segments);
         assert!(!intersections.contains(&Vector::new(0.0, 0.0)));
     }
+    #[test]
+    fn estimate_complexity() {
+        let polygon = RegularPolygon::new(8);
+        let points = polygon.set_up_points(400, 400);
+        let segments = polygon.connect_points(&points);
+        assert_eq!(polygon.estimate_complexity(400, 400), (8, 28));
+        assert_eq!(
+            polygon.estimate_complexity(400, 400),
+            (points.len(), segments.len())
+        );
+        for (corners_count, chord_step) in [(5, 2), (8, 4), (8, 3), (7, 9)] {
+            let polygon = RegularPolygon::with_chord_step(corners_count, chord_step);
+            let points = polygon.set_up_points(400, 400);
+            let segments = polygon.connect_points(&points);
+            assert_eq!(
+                polygon.estimate_complexity(400, 400),
+                (points.len(), segments.len())
+            );
+        }
+    }
 }
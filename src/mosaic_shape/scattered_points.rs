@@ -0,0 +1,216 @@
+use std::f64::consts;
+
+use super::{helpers::Random, MosaicShape, Segment, Vector};
+
+const CANDIDATES_COUNT: u32 = 30;
+
+/// Defines mosaic shape based on pseudo-random points scattered using Poisson-disk
+/// (blue noise) sampling.
+///
+/// Scattered points become key points of mosaic as is, so this shape gives organic
+/// low-poly look without any geometric base shape. Points are generated reproducibly:
+/// same seed (and same size of mosaic) gives same set of points.
+#[derive(Clone, Debug)]
+pub struct ScatteredPoints {
+    count: u32,
+    minimum_distance: f64,
+    seed: u64,
+}
+
+impl ScatteredPoints {
+    /// Creates shape of scattered points.
+    ///
+    /// # Arguments
+    ///
+    /// * `count`: maximum number of scattered points; should be at least 1.
+    /// * `minimum_distance`: minimum distance between any two points, in pixels; should be
+    ///   at least 1.0.
+    /// * `seed`: seed of pseudo-random generator of points.
+    ///
+    /// returns: [`ScatteredPoints`] - mosaic shape based on scattered points.
+    ///
+    pub fn new(count: u32, minimum_distance: f64, seed: u64) -> Self {
+        Self {
+            count: count.max(1),
+            minimum_distance: minimum_distance.max(1.0),
+            seed,
+        }
+    }
+
+    /// Maximum number of scattered points.
+    ///
+    /// Actual number of points can be smaller when mosaic has no room for more points
+    /// with given minimum distance between them.
+    #[inline(always)]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Sets maximum number of scattered points.
+    ///
+    /// # Arguments
+    ///
+    /// * `count`: maximum number of scattered points; should be at least 1.
+    ///
+    pub fn set_count(&mut self, count: u32) {
+        self.count = count.max(1);
+    }
+
+    /// Minimum distance between any two scattered points.
+    #[inline(always)]
+    pub fn minimum_distance(&self) -> f64 {
+        self.minimum_distance
+    }
+
+    /// Sets minimum distance between any two scattered points.
+    ///
+    /// # Arguments
+    ///
+    /// * `minimum_distance`: minimum distance between any two points, in pixels; should be
+    ///   at least 1.0.
+    ///
+    pub fn set_minimum_distance(&mut self, minimum_distance: f64) {
+        self.minimum_distance = minimum_distance.max(1.0);
+    }
+
+    /// Seed of pseudo-random generator of points.
+    #[inline(always)]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Sets seed of pseudo-random generator of points.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+}
+
+impl Default for ScatteredPoints {
+    fn default() -> Self {
+        Self {
+            count: 64,
+            minimum_distance: 40.0,
+            seed: 0,
+        }
+    }
+}
+
+impl MosaicShape for ScatteredPoints {
+    fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
+        if image_width == 0 || image_height == 0 {
+            return Vec::new();
+        }
+        let (width, height) = (image_width as f64, image_height as f64);
+        let cell_size = self.minimum_distance / consts::SQRT_2;
+        let (columns_count, rows_count) = (
+            (width / cell_size).ceil() as usize,
+            (height / cell_size).ceil() as usize,
+        );
+        let mut grid: Vec<Option<usize>> = vec![None; columns_count * rows_count];
+        let cell_of = |point: Vector| {
+            (
+                ((point.x / cell_size) as usize).min(columns_count - 1),
+                ((point.y / cell_size) as usize).min(rows_count - 1),
+            )
+        };
+        let mut random = Random::new(self.seed);
+        let first_point = Vector::new(random.next_f64() * width, random.next_f64() * height);
+        let (column, row) = cell_of(first_point);
+        grid[row * columns_count + column] = Some(0);
+        let mut points = vec![first_point];
+        let mut active_points = vec![0];
+        while !active_points.is_empty() && points.len() < self.count as usize {
+            let active_index = random.next_index(active_points.len());
+            let origin = points[active_points[active_index]];
+            let mut is_found = false;
+            for _ in 0..CANDIDATES_COUNT {
+                let angle = random.next_f64() * consts::TAU;
+                let distance = self.minimum_distance * (1.0 + random.next_f64());
+                let candidate = Vector::new(
+                    origin.x + distance * angle.cos(),
+                    origin.y + distance * angle.sin(),
+                );
+                if candidate.x < 0.0
+                    || candidate.x >= width
+                    || candidate.y < 0.0
+                    || candidate.y >= height
+                {
+                    continue;
+                }
+                let (column, row) = cell_of(candidate);
+                let is_far_enough = (row.saturating_sub(2)..(row + 3).min(rows_count)).all(|row| {
+                    (column.saturating_sub(2)..(column + 3).min(columns_count)).all(|column| {
+                        match grid[row * columns_count + column] {
+                            Some(index) => {
+                                points[index].distance_to(candidate) >= self.minimum_distance
+                            }
+                            None => true,
+                        }
+                    })
+                });
+                if is_far_enough {
+                    grid[row * columns_count + column] = Some(points.len());
+                    active_points.push(points.len());
+                    points.push(candidate);
+                    is_found = true;
+                    break;
+                }
+            }
+            if !is_found {
+                active_points.swap_remove(active_index);
+            }
+        }
+        let center = Vector::new(width * 0.5, height * 0.5);
+        points.iter().map(|point| *point - center).collect()
+    }
+
+    fn connect_points(&self, _shape_points: &Vec<Vector>) -> Vec<Segment> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_incorrect_count() {
+        let mut scattered_points = ScatteredPoints::default();
+        scattered_points.set_count(0);
+        assert_eq!(scattered_points.count(), 1);
+    }
+    #[test]
+    fn set_up_points_with_same_seed() {
+        let first_points = ScatteredPoints::new(100, 30.0, 42).set_up_points(400, 300);
+        let second_points = ScatteredPoints::new(100, 30.0, 42).set_up_points(400, 300);
+        assert_eq!(first_points, second_points);
+        let third_points = ScatteredPoints::new(100, 30.0, 43).set_up_points(400, 300);
+        assert_ne!(first_points, third_points);
+    }
+    #[test]
+    fn set_up_points_of_empty_size() {
+        let scattered_points = ScatteredPoints::default();
+        assert!(scattered_points.set_up_points(0, 300).is_empty());
+        assert!(scattered_points.set_up_points(400, 0).is_empty());
+    }
+    #[test]
+    fn set_up_points() {
+        let scattered_points = ScatteredPoints::new(100, 30.0, 7);
+        let points = scattered_points.set_up_points(400, 300);
+        assert!(points.len() > 1);
+        assert!(points.len() <= 100);
+        for (index, point) in points.iter().enumerate() {
+            assert!(point.x >= -200.0 && point.x < 200.0);
+            assert!(point.y >= -150.0 && point.y < 150.0);
+            for other_point in &points[index + 1..] {
+                assert!(point.distance_to(*other_point) >= 30.0);
+            }
+        }
+    }
+    #[test]
+    fn connect_points() {
+        let scattered_points = ScatteredPoints::default();
+        let points = scattered_points.set_up_points(400, 400);
+        assert!(scattered_points.connect_points(&points).is_empty());
+    }
+}
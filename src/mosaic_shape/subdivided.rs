@@ -0,0 +1,163 @@
+use super::{helpers::PointsSplits, MosaicShape, Segment, Vector};
+
+/// Defines mosaic shape which adds key points along line segments of another (inner) shape.
+///
+/// Every line segment of inner shape is subdivided into equal parts by evenly spaced points,
+/// which are added to key points of inner shape. Such points create denser (smaller) cells
+/// of mosaic along edges of inner shape. Line segments of subdivided shape are line segments
+/// of inner shape.
+///
+/// **_Note_**: subdivided shape connects only lists of key points with the same count of points
+/// as ones set up by [`MosaicShape::set_up_points`] of this shape; if the same count of points
+/// is set up for different sizes of image, but inner shape sets up different count of them,
+/// the count for the most recently used size is applied.
+#[derive(Clone, Debug)]
+pub struct Subdivided {
+    shape: Box<dyn MosaicShape>,
+    subdivisions: u32,
+    points_splits: PointsSplits,
+}
+
+impl Subdivided {
+    /// Creates mosaic shape which adds evenly spaced key points along line segments
+    /// of inner shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: inner mosaic shape which line segments are subdivided.
+    /// * `subdivisions`: count of points added to every line segment of inner shape.
+    ///
+    /// returns: [`Subdivided`] - mosaic shape with subdivided line segments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::mosaic_shape::{MosaicShape, PolygonalStar, Subdivided};
+    ///
+    /// let star = Subdivided::new(PolygonalStar::new(5), 2);
+    /// let points = star.set_up_points(400, 400);
+    ///
+    /// assert_eq!(points.len(), 10 + 10 * 2);
+    /// ```
+    pub fn new<Shape>(shape: Shape, subdivisions: u32) -> Self
+    where
+        Shape: 'static + MosaicShape,
+    {
+        Self {
+            shape: Box::new(shape),
+            subdivisions,
+            points_splits: PointsSplits::default(),
+        }
+    }
+
+    /// Inner mosaic shape which line segments are subdivided.
+    #[allow(clippy::borrowed_box)]
+    pub fn shape(&self) -> &Box<dyn MosaicShape> {
+        &self.shape
+    }
+
+    /// Count of points added to every line segment of inner shape.
+    #[inline(always)]
+    pub fn subdivisions(&self) -> u32 {
+        self.subdivisions
+    }
+
+    /// Sets count of points added to every line segment of inner shape.
+    pub fn set_subdivisions(&mut self, subdivisions: u32) {
+        self.subdivisions = subdivisions;
+    }
+}
+
+impl MosaicShape for Subdivided {
+    fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
+        let mut points = self.shape.set_up_points(image_width, image_height);
+        let shape_points_count = points.len();
+        let parts_count = self.subdivisions as f64 + 1.0;
+        for segment in self.shape.connect_points(&points) {
+            let direction = segment.end - segment.start;
+            for index in 1..=self.subdivisions {
+                points.push(segment.start + direction * (index as f64 / parts_count));
+            }
+        }
+        self.points_splits.insert(points.len(), shape_points_count);
+        points
+    }
+
+    fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment> {
+        let shape_points_count = match self.points_splits.get(shape_points.len()) {
+            Some(shape_points_count) => shape_points_count,
+            None => return Vec::new(),
+        };
+        self.shape
+            .connect_points(&shape_points[..shape_points_count].to_vec())
+    }
+
+    fn bounding_radius(&self, image_width: u32, image_height: u32) -> f64 {
+        self.shape.bounding_radius(image_width, image_height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{Grid, RegularPolygon, ScatteredPoints, Union},
+        *,
+    };
+
+    #[test]
+    fn set_subdivisions() {
+        let mut shape = Subdivided::new(Grid::default(), 1);
+        shape.set_subdivisions(3);
+        assert_eq!(shape.subdivisions(), 3);
+    }
+    #[test]
+    fn set_up_points() {
+        let shape = Subdivided::new(RegularPolygon::new(3), 1);
+        let points = shape.set_up_points(400, 400);
+        let corners = RegularPolygon::new(3).set_up_points(400, 400);
+        assert_eq!(points.len(), corners.len() + 3);
+        assert_eq!(points[..3], corners[..]);
+        for (start_index, end_index) in [(0, 1), (0, 2), (1, 2)] {
+            let midpoint = (corners[start_index] + corners[end_index]) * 0.5;
+            assert!(points.contains(&midpoint));
+        }
+    }
+    #[test]
+    fn set_up_points_without_subdivisions() {
+        let shape = Subdivided::new(RegularPolygon::new(5), 0);
+        assert_eq!(
+            shape.set_up_points(400, 400),
+            RegularPolygon::new(5).set_up_points(400, 400)
+        );
+    }
+    #[test]
+    fn connect_points() {
+        let shape = Subdivided::new(Grid::new(4, 4), 3);
+        let points = shape.set_up_points(400, 400);
+        let segments = shape.connect_points(&points);
+        let grid = Grid::new(4, 4);
+        assert_eq!(segments, grid.connect_points(&grid.set_up_points(400, 400)));
+    }
+    #[test]
+    fn connect_points_set_up_for_different_sizes() {
+        let shape = Subdivided::new(
+            Union::new(Grid::new(4, 4), ScatteredPoints::new(100, 30.0, 7)),
+            2,
+        );
+        let large_points = shape.set_up_points(400, 300);
+        let small_points = shape.set_up_points(100, 100);
+        assert_ne!(large_points.len(), small_points.len());
+        let grid = Grid::new(4, 4);
+        assert_eq!(
+            shape.connect_points(&large_points),
+            grid.connect_points(&grid.set_up_points(400, 300))
+        );
+        assert_eq!(
+            shape.connect_points(&small_points),
+            grid.connect_points(&grid.set_up_points(100, 100))
+        );
+        assert!(Subdivided::new(Grid::new(4, 4), 2)
+            .connect_points(&large_points)
+            .is_empty());
+    }
+}
@@ -0,0 +1,142 @@
+use super::{MosaicShape, Segment, Vector};
+
+/// Defines mosaic shape which shifts another (inner) shape by offset.
+///
+/// Offset is applied to key points of inner shape before transformation of mosaic, so
+/// together with [`Union`][`super::Union`] it allows to compose several clusters of shapes
+/// (like two stars side by side).
+#[derive(Clone, Debug)]
+pub struct Translated {
+    shape: Box<dyn MosaicShape>,
+    offset: Vector,
+}
+
+impl Translated {
+    /// Creates mosaic shape which shifts inner shape by offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: inner mosaic shape which key points are shifted.
+    /// * `offset`: offset of key points of inner shape, in pixels.
+    ///
+    /// returns: [`Translated`] - mosaic shape shifted by offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{
+    ///     mosaic_shape::{MosaicShape, PolygonalStar, Translated, Union},
+    ///     Vector,
+    /// };
+    ///
+    /// let two_stars = Union::new(
+    ///     Translated::new(PolygonalStar::new(5), Vector::new(-100.0, 0.0)),
+    ///     Translated::new(PolygonalStar::new(5), Vector::new(100.0, 0.0)),
+    /// );
+    /// let points = two_stars.set_up_points(400, 200);
+    ///
+    /// assert_eq!(points.len(), 20);
+    /// ```
+    pub fn new<Shape>(shape: Shape, offset: Vector) -> Self
+    where
+        Shape: 'static + MosaicShape,
+    {
+        Self {
+            shape: Box::new(shape),
+            offset,
+        }
+    }
+
+    /// Inner mosaic shape which key points are shifted.
+    #[allow(clippy::borrowed_box)]
+    pub fn shape(&self) -> &Box<dyn MosaicShape> {
+        &self.shape
+    }
+
+    /// Offset of key points of inner shape, in pixels.
+    #[inline(always)]
+    pub fn offset(&self) -> Vector {
+        self.offset
+    }
+
+    /// Sets offset of key points of inner shape, in pixels.
+    pub fn set_offset(&mut self, offset: Vector) {
+        self.offset = offset;
+    }
+}
+
+impl MosaicShape for Translated {
+    fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
+        self.shape
+            .set_up_points(image_width, image_height)
+            .iter()
+            .map(|point| *point + self.offset)
+            .collect()
+    }
+
+    fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment> {
+        let inner_points = shape_points
+            .iter()
+            .map(|point| *point - self.offset)
+            .collect();
+        self.shape
+            .connect_points(&inner_points)
+            .into_iter()
+            .map(|segment| Segment::new(segment.start + self.offset, segment.end + self.offset))
+            .collect()
+    }
+
+    fn bounding_radius(&self, image_width: u32, image_height: u32) -> f64 {
+        self.shape.bounding_radius(image_width, image_height) + self.offset.length()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{Flower, Grid, RegularPolygon},
+        *,
+    };
+
+    #[test]
+    fn set_offset() {
+        let mut shape = Translated::new(Grid::default(), Vector::new(10.0, 20.0));
+        shape.set_offset(Vector::new(-5.0, 15.0));
+        assert_eq!(shape.offset(), Vector::new(-5.0, 15.0));
+    }
+    #[test]
+    fn set_up_points() {
+        let offset = Vector::new(40.0, -25.0);
+        let shape = Translated::new(RegularPolygon::new(7), offset);
+        let points = shape.set_up_points(400, 400);
+        let inner_points = RegularPolygon::new(7).set_up_points(400, 400);
+        assert_eq!(points.len(), inner_points.len());
+        for (point, inner_point) in points.iter().zip(inner_points) {
+            assert_eq!(*point, inner_point + offset);
+        }
+    }
+    #[test]
+    fn connect_points() {
+        let shape = Translated::new(Grid::new(4, 4), Vector::new(100.0, 0.0));
+        let points = shape.set_up_points(400, 400);
+        let segments = shape.connect_points(&points);
+        assert_eq!(segments.len(), 6);
+        assert!(segments.contains(&Segment::from(((-100.0, 100.0), (300.0, 100.0)))));
+        assert!(segments.contains(&Segment::from(((200.0, -200.0), (200.0, 200.0)))));
+    }
+    #[test]
+    fn connect_points_of_flower() {
+        let offset = Vector::new(-60.0, 80.0);
+        let shape = Translated::new(Flower::new(4, 3, 0.5), offset);
+        let points = shape.set_up_points(400, 400);
+        let segments = shape.connect_points(&points);
+        assert_eq!(segments.len(), 4 * 4 + 3);
+        assert!(segments.contains(&Segment::from(((-60.0, 80.0), (-60.0, -20.0)))));
+        assert!(!segments.contains(&Segment::from(((0.0, 0.0), (0.0, -100.0)))));
+    }
+    #[test]
+    fn bounding_radius() {
+        let shape = Translated::new(RegularPolygon::new(6), Vector::new(30.0, 40.0));
+        assert_eq!(shape.bounding_radius(400, 400), 250.0);
+    }
+}
@@ -0,0 +1,183 @@
+use super::{helpers::PointsSplits, MosaicShape, Segment, Vector};
+
+/// Defines mosaic shape which overlays two other (inner) shapes.
+///
+/// Key points and line segments of union are key points and line segments of both inner shapes,
+/// so, for example, star can be placed over grid without creating new shape type. Points
+/// shared by both inner shapes are merged later while building mosaic. Weights of key points
+/// are weights of both inner shapes; points of unweighted inner shape have zero weight.
+///
+/// **_Note_**: union connects only lists of key points with the same count of points as ones
+/// set up by [`MosaicShape::set_up_points`] of this union; if inner shapes set up the same
+/// total count of points for different sizes of image, but split it differently, the split
+/// for the most recently used size is applied.
+#[derive(Clone, Debug)]
+pub struct Union {
+    first_shape: Box<dyn MosaicShape>,
+    second_shape: Box<dyn MosaicShape>,
+    points_splits: PointsSplits,
+}
+
+impl Union {
+    /// Creates mosaic shape which overlays two inner shapes.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_shape`: first inner mosaic shape.
+    /// * `second_shape`: second inner mosaic shape.
+    ///
+    /// returns: [`Union`] - mosaic shape which combines both inner shapes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::mosaic_shape::{Grid, MosaicShape, PolygonalStar, Union};
+    ///
+    /// let union = Union::new(Grid::new(4, 4), PolygonalStar::new(5));
+    /// let points = union.set_up_points(400, 400);
+    ///
+    /// assert_eq!(points.len(), 16 + 10);
+    /// ```
+    pub fn new<FirstShape, SecondShape>(first_shape: FirstShape, second_shape: SecondShape) -> Self
+    where
+        FirstShape: 'static + MosaicShape,
+        SecondShape: 'static + MosaicShape,
+    {
+        Self {
+            first_shape: Box::new(first_shape),
+            second_shape: Box::new(second_shape),
+            points_splits: PointsSplits::default(),
+        }
+    }
+
+    /// First inner mosaic shape.
+    #[allow(clippy::borrowed_box)]
+    pub fn first_shape(&self) -> &Box<dyn MosaicShape> {
+        &self.first_shape
+    }
+
+    /// Second inner mosaic shape.
+    #[allow(clippy::borrowed_box)]
+    pub fn second_shape(&self) -> &Box<dyn MosaicShape> {
+        &self.second_shape
+    }
+}
+
+impl MosaicShape for Union {
+    fn set_up_points(&self, image_width: u32, image_height: u32) -> Vec<Vector> {
+        let mut points = self.first_shape.set_up_points(image_width, image_height);
+        let first_points_count = points.len();
+        points.append(&mut self.second_shape.set_up_points(image_width, image_height));
+        self.points_splits.insert(points.len(), first_points_count);
+        points
+    }
+
+    fn set_up_weights(&self, image_width: u32, image_height: u32) -> Option<Vec<f64>> {
+        let first_weights = self.first_shape.set_up_weights(image_width, image_height);
+        let second_weights = self.second_shape.set_up_weights(image_width, image_height);
+        if first_weights.is_none() && second_weights.is_none() {
+            return None;
+        }
+        let weights_of = |shape: &dyn MosaicShape, weights: Option<Vec<f64>>| {
+            weights
+                .unwrap_or_else(|| vec![0.0; shape.set_up_points(image_width, image_height).len()])
+        };
+        let mut weights = weights_of(self.first_shape.as_ref(), first_weights);
+        weights.append(&mut weights_of(self.second_shape.as_ref(), second_weights));
+        Some(weights)
+    }
+
+    fn connect_points(&self, shape_points: &Vec<Vector>) -> Vec<Segment> {
+        let first_points_count = match self.points_splits.get(shape_points.len()) {
+            Some(first_points_count) => first_points_count,
+            None => return Vec::new(),
+        };
+        let (first_points, second_points) = shape_points.split_at(first_points_count);
+        let mut segments = self.first_shape.connect_points(&first_points.to_vec());
+        segments.append(&mut self.second_shape.connect_points(&second_points.to_vec()));
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{Grid, PolygonalStar, RegularPolygon, ScatteredPoints, WeightedPoints},
+        *,
+    };
+
+    #[test]
+    fn set_up_points() {
+        let union = Union::new(Grid::new(3, 5), RegularPolygon::new(7));
+        let points = union.set_up_points(400, 300);
+        let grid_points = Grid::new(3, 5).set_up_points(400, 300);
+        let polygon_points = RegularPolygon::new(7).set_up_points(400, 300);
+        assert_eq!(points.len(), grid_points.len() + polygon_points.len());
+        assert_eq!(points[..grid_points.len()], grid_points[..]);
+        assert_eq!(points[grid_points.len()..], polygon_points[..]);
+    }
+    #[test]
+    fn connect_points() {
+        let union = Union::new(PolygonalStar::new(5), Grid::new(4, 4));
+        let points = union.set_up_points(400, 400);
+        let segments = union.connect_points(&points);
+        let star = PolygonalStar::new(5);
+        let star_segments = star.connect_points(&star.set_up_points(400, 400));
+        let grid = Grid::new(4, 4);
+        let grid_segments = grid.connect_points(&grid.set_up_points(400, 400));
+        assert_eq!(segments.len(), star_segments.len() + grid_segments.len());
+        assert_eq!(segments[..star_segments.len()], star_segments[..]);
+        assert_eq!(segments[star_segments.len()..], grid_segments[..]);
+    }
+    #[test]
+    fn connect_points_set_up_for_different_sizes() {
+        let union = Union::new(ScatteredPoints::new(100, 30.0, 7), Grid::new(4, 4));
+        let large_points = union.set_up_points(400, 300);
+        let small_points = union.set_up_points(100, 100);
+        assert_ne!(large_points.len(), small_points.len());
+        let grid = Grid::new(4, 4);
+        assert_eq!(
+            union.connect_points(&large_points),
+            grid.connect_points(&grid.set_up_points(400, 300))
+        );
+        assert_eq!(
+            union.connect_points(&small_points),
+            grid.connect_points(&grid.set_up_points(100, 100))
+        );
+        assert!(Union::new(Grid::new(4, 4), Grid::new(4, 4))
+            .connect_points(&large_points)
+            .is_empty());
+    }
+    #[test]
+    fn set_up_weights() {
+        let weighted_points = WeightedPoints::new(&[
+            (Vector::new(-50.0, 0.0), 100.0),
+            (Vector::new(50.0, 0.0), 200.0),
+        ]);
+        let union = Union::new(RegularPolygon::new(3), weighted_points.clone());
+        assert_eq!(
+            union.set_up_weights(400, 400),
+            Some(vec![0.0, 0.0, 0.0, 100.0, 200.0])
+        );
+        let union = Union::new(weighted_points, RegularPolygon::new(3));
+        assert_eq!(
+            union.set_up_weights(400, 400),
+            Some(vec![100.0, 200.0, 0.0, 0.0, 0.0])
+        );
+        let union = Union::new(RegularPolygon::new(3), Grid::new(4, 4));
+        assert_eq!(union.set_up_weights(400, 400), None);
+    }
+    #[test]
+    fn union_of_boxed_shapes() {
+        let first_shape: Box<dyn MosaicShape> = Box::new(PolygonalStar::new(5));
+        let second_shape: Box<dyn MosaicShape> = Box::new(Grid::new(4, 4));
+        let union = Union::new(first_shape.clone(), second_shape.clone());
+        let points = union.set_up_points(400, 400);
+        let first_points = first_shape.set_up_points(400, 400);
+        let second_points = second_shape.set_up_points(400, 400);
+        assert_eq!(points.len(), first_points.len() + second_points.len());
+        let mut segments = first_shape.connect_points(&first_points);
+        segments.append(&mut second_shape.connect_points(&second_points));
+        assert_eq!(union.connect_points(&points), segments);
+    }
+}
@@ -0,0 +1,97 @@
+use super::{MosaicShape, Segment, Vector};
+
+/// Defines mosaic shape based on custom key points with weights.
+///
+/// Points become key points of mosaic as is (they aren't connected with line segments).
+/// Weights make starry mosaic drawn as weighted Voronoi diagram (power diagram): every pixel
+/// belongs to key point with the least `distance² - weight`, so cells of key points with larger
+/// weights grow at the expense of their neighbours.
+///
+/// **_Note_**: weighted cells have no closed form, so every build of starry mosaic with this
+/// shape measures sizes of cells (which glow of cells depends on) by checking every site
+/// for every pixel of mosaic image. Building and drawing weighted mosaics therefore takes
+/// time proportional to number of pixels multiplied by number of key points, which is much
+/// slower than for unweighted shapes.
+#[derive(Clone, Debug)]
+pub struct WeightedPoints {
+    points: Vec<Vector>,
+    weights: Vec<f64>,
+}
+
+impl WeightedPoints {
+    /// Creates shape of weighted key points.
+    ///
+    /// # Arguments
+    ///
+    /// * `weighted_points`: key points of mosaic centered around origin (0.0, 0.0), in pixels,
+    ///   with their weights, in squared pixels of mosaic image.
+    ///
+    /// returns: [`WeightedPoints`] - mosaic shape based on weighted key points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{
+    ///     mosaic_shape::{MosaicShape, WeightedPoints},
+    ///     Vector,
+    /// };
+    ///
+    /// let shape = WeightedPoints::new(&[
+    ///     (Vector::new(-100.0, 0.0), 2500.0),
+    ///     (Vector::new(100.0, 0.0), 0.0),
+    ///     (Vector::new(0.0, 100.0), 0.0),
+    /// ]);
+    ///
+    /// assert_eq!(shape.set_up_points(400, 400).len(), 3);
+    /// assert_eq!(shape.set_up_weights(400, 400), Some(vec![2500.0, 0.0, 0.0]));
+    /// ```
+    pub fn new(weighted_points: &[(Vector, f64)]) -> Self {
+        let (points, weights) = weighted_points.iter().copied().unzip();
+        Self { points, weights }
+    }
+
+    /// Key points of mosaic shape.
+    #[inline(always)]
+    pub fn points(&self) -> &[Vector] {
+        &self.points
+    }
+
+    /// Weights of key points of mosaic shape, in squared pixels of mosaic image.
+    #[inline(always)]
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+}
+
+impl MosaicShape for WeightedPoints {
+    fn set_up_points(&self, _image_width: u32, _image_height: u32) -> Vec<Vector> {
+        self.points.clone()
+    }
+
+    fn set_up_weights(&self, _image_width: u32, _image_height: u32) -> Option<Vec<f64>> {
+        Some(self.weights.clone())
+    }
+
+    fn connect_points(&self, _shape_points: &Vec<Vector>) -> Vec<Segment> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_up_points() {
+        let shape = WeightedPoints::new(&[
+            (Vector::new(-50.0, 10.0), 100.0),
+            (Vector::new(30.0, -20.0), 0.0),
+        ]);
+        assert_eq!(
+            shape.set_up_points(200, 200),
+            vec![Vector::new(-50.0, 10.0), Vector::new(30.0, -20.0)]
+        );
+        assert_eq!(shape.set_up_weights(200, 200), Some(vec![100.0, 0.0]));
+        assert!(shape.connect_points(&shape.points().to_vec()).is_empty());
+    }
+}
@@ -1,17 +1,30 @@
-use image::{Rgb, RgbImage};
-use palette::{IntoColor, LinSrgb, Mix, Pixel, Shade};
+use std::{
+    collections::HashSet,
+    ops::Range,
+    sync::atomic::{self, AtomicBool},
+};
+
+use image::{GrayImage, ImageBuffer, Rgb, RgbImage, Rgba, RgbaImage};
+use palette::{IntoColor, LinSrgb, LinSrgba, Mix, Pixel, Shade};
+use rayon::prelude::*;
 use robust::Coord;
 use voronoice::Voronoi;
 
 use super::{
+    aabb::Aabb,
     coloring_method::ColoringMethod,
-    mosaic::Mosaic,
-    mosaic_builder::MosaicBuilder,
+    mosaic::{self, Mosaic, Shading},
+    mosaic_builder::{BuildSettings, MosaicBuilder},
     mosaic_shape::MosaicShape,
+    rasterization, sampling,
+    segment::Segment,
     transform::{Transformation, TryToTransform},
+    utility,
     vector::Vector,
 };
 
+const PARALLEL_BAND_HEIGHT: u32 = 16;
+
 /// Represents polygonal mosaic and creates mosaic images painted with with different
 /// [methods][`ColoringMethod`].
 ///
@@ -30,6 +43,10 @@ pub struct PolygonalMosaic {
     image_size: (u32, u32),
     transformation: Transformation,
     shape: Box<dyn MosaicShape>,
+    build_settings: BuildSettings,
+    shading: Shading,
+    shading_exponent: f64,
+    dithering: bool,
 }
 
 impl PolygonalMosaic {
@@ -38,64 +55,326 @@ impl PolygonalMosaic {
         image_size: (u32, u32),
         transformation: Transformation,
         shape: Box<dyn MosaicShape>,
+        build_settings: BuildSettings,
     ) -> Self {
         Self {
             voronoi,
             image_size,
             transformation,
             shape,
+            build_settings,
+            shading: Shading::default(),
+            shading_exponent: 2.0,
+            dithering: false,
         }
     }
 
-    fn draw_triangle<Color, Method>(
-        &self,
-        mosaic_image: &mut RgbImage,
-        coloring_method: &Method,
-        vertex_index: usize,
-    ) where
+    /// Voronoi diagram which Delaunay triangulation defines triangles of mosaic.
+    ///
+    /// Sites, vertices and triangulation of diagram are given in coordinate space of mosaic
+    /// image (in pixels), so they can be used directly for custom analysis of mosaic.
+    /// Key points of mosaic which lie outside of bounding box of diagram are not its sites.
+    #[inline(always)]
+    pub fn voronoi(&self) -> &Voronoi {
+        &self.voronoi
+    }
+
+    /// Shading of triangles of mosaic used while drawing mosaic image.
+    ///
+    /// By default triangles are shaded with [`Shading::Glow`].
+    #[inline(always)]
+    pub fn shading(&self) -> Shading {
+        self.shading
+    }
+
+    /// Sets shading of triangles of mosaic used while drawing mosaic image.
+    ///
+    /// # Arguments
+    ///
+    /// * `shading`: new [shading][`Shading`] of mosaic triangles.
+    ///
+    pub fn set_shading(&mut self, shading: Shading) {
+        self.shading = shading;
+    }
+
+    /// Exponent of falloff of lightness from center to edges of triangles of mosaic.
+    ///
+    /// Used only by [`Shading::Glow`]; lightness of every pixel equals
+    /// `(1.0 - distance_ratio).powf(shading_exponent)`. Default exponent is 2.0.
+    #[inline(always)]
+    pub fn shading_exponent(&self) -> f64 {
+        self.shading_exponent
+    }
+
+    /// Sets exponent of falloff of lightness from center to edges of triangles of mosaic.
+    ///
+    /// # Arguments
+    ///
+    /// * `shading_exponent`: new exponent of falloff; 1.0 gives linear falloff, larger values
+    ///   give sharper glow; should be non-negative.
+    ///
+    pub fn set_shading_exponent(&mut self, shading_exponent: f64) {
+        self.shading_exponent = shading_exponent.max(0.0);
+    }
+
+    /// Whether ordered dither is applied while converting colors of mosaic image to 8-bit
+    /// pixels.
+    ///
+    /// Dithering is disabled by default.
+    #[inline(always)]
+    pub fn dithering(&self) -> bool {
+        self.dithering
+    }
+
+    /// Sets whether ordered dither is applied while converting colors of mosaic image
+    /// to 8-bit pixels.
+    ///
+    /// Dithering reduces visible banding of smooth gradients and glow of large triangles.
+    ///
+    /// # Arguments
+    ///
+    /// * `dithering`: whether ordered (Bayer) dither is applied.
+    ///
+    pub fn set_dithering(&mut self, dithering: bool) {
+        self.dithering = dithering;
+    }
+
+    /// Creates mosaic image painted with specified coloring method.
+    ///
+    /// Delaunay triangulation of mosaic is calculated once when mosaic is built, so repainting
+    /// existing mosaic with another coloring method is much cheaper than building new one.
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image.
+    ///
+    /// returns: `RgbImage` - painted mosaic image containing mosaic shape (pattern).
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    ///
+    #[inline(always)]
+    pub fn recolor<Color, Method>(&self, coloring_method: Method) -> RgbImage
+    where
         Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
         Method: ColoringMethod<Color>,
     {
+        self.draw(coloring_method)
+    }
+
+    /// Creates image of edges of triangles of mosaic (edges of Delaunay triangulation) without
+    /// filling these triangles.
+    ///
+    /// Every edge shared by two neighbouring triangles is drawn only once.
+    ///
+    /// # Arguments
+    ///
+    /// * `color`: color of edges of mosaic triangles.
+    /// * `background`: color of background of image.
+    /// * `thickness`: thickness of edges, in pixels; should be at least 1.0.
+    ///
+    /// returns: `RgbImage` - image containing edges of mosaic triangles.
+    ///
+    pub fn draw_wireframe<Color>(&self, color: Color, background: Color, thickness: f64) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>>,
+    {
+        let color: LinSrgb<f64> = color.into_color();
+        let background: LinSrgb<f64> = background.into_color();
+        let mut wireframe_image = RgbImage::from_pixel(
+            self.image_size.0,
+            self.image_size.1,
+            Rgb(background.into_format().into_raw()),
+        );
+        let pixel = Rgb(color.into_format().into_raw());
         let sites = self.voronoi.sites();
-        let triangulation = self.voronoi.triangulation();
-        let vertex_position: Vector = (&self.voronoi.vertices()[vertex_index]).into();
-        let corner_positions: [Coord<f64>; 3] = [
-            (&sites[triangulation.triangles[vertex_index * 3]]).into(),
-            (&sites[triangulation.triangles[vertex_index * 3 + 1]]).into(),
-            (&sites[triangulation.triangles[vertex_index * 3 + 2]]).into(),
-        ];
-        let radius = vertex_position.distance_to(corner_positions[0].into());
-        let x_min = f64::min(corner_positions[0].x, corner_positions[1].x)
-            .min(corner_positions[2].x)
-            .round() as u32;
-        let x_max = f64::max(corner_positions[0].x, corner_positions[1].x)
-            .max(corner_positions[2].x)
-            .round() as u32;
-        let y_min = f64::min(corner_positions[0].y, corner_positions[1].y)
-            .min(corner_positions[2].y)
-            .round() as u32;
-        let y_max = f64::max(corner_positions[0].y, corner_positions[1].y)
-            .max(corner_positions[2].y)
-            .round() as u32;
-        for x in x_min..=x_max {
-            for y in y_min..=y_max {
-                let position = Vector::new(x as f64, y as f64);
-                let orientations = [
-                    robust::orient2d(corner_positions[0], corner_positions[1], position.into()),
-                    robust::orient2d(corner_positions[1], corner_positions[2], position.into()),
-                    robust::orient2d(corner_positions[2], corner_positions[0], position.into()),
-                ];
-                if orientations[0] <= 0.0 && orientations[1] <= 0.0 && orientations[2] <= 0.0 {
-                    let distance = position.distance_to(vertex_position);
-                    let lightness = (1.0 - distance / radius).powi(2);
-                    let color = coloring_method
-                        .interpolate(position, vertex_position)
-                        .lighten(lightness)
-                        .into_color();
-                    mosaic_image.put_pixel(x, y, Rgb(color.into_format().into_raw()));
+        let mut drawn_edges = HashSet::new();
+        for triangle in self.voronoi.triangulation().triangles.chunks_exact(3) {
+            for index in 0..3 {
+                let (start, end) = (triangle[index], triangle[(index + 1) % 3]);
+                if !drawn_edges.insert((start.min(end), start.max(end))) {
+                    continue;
                 }
+                let edge = Segment::new((&sites[start]).into(), (&sites[end]).into());
+                rasterization::draw_segment(&mut wireframe_image, &edge, pixel, thickness);
             }
         }
+        wireframe_image
+    }
+
+    /// Draws painted mosaic image unless drawing is cancelled.
+    ///
+    /// Cancellation flag is checked before drawing of every triangle of mosaic, so long
+    /// drawing can be aborted from another thread (for example, when parameters of mosaic
+    /// are changed during drawing).
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image.
+    /// * `cancelled`: flag which aborts drawing when set to `true`.
+    ///
+    /// returns: `Option<RgbImage>` - painted mosaic image containing mosaic shape (pattern);
+    /// `None` if drawing is cancelled.
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    ///
+    pub fn draw_cancellable<Color, Method>(
+        &self,
+        coloring_method: Method,
+        cancelled: &AtomicBool,
+    ) -> Option<RgbImage>
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+    {
+        self.draw_triangles(&coloring_method, Some(cancelled), |color, x, y| {
+            self.to_rgb8(color, x, y)
+        })
+    }
+
+    /// Creates mosaic image with opacity painted with specified coloring method.
+    ///
+    /// Unlike [`Mosaic::draw`] this method preserves opacity (alpha channel) of colors
+    /// of coloring method, so it allows to paint mosaic with semi-transparent colors
+    /// and gradients. Pixels outside of triangles of mosaic are fully transparent.
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image.
+    ///
+    /// returns: `RgbaImage` - painted mosaic image with opacity containing mosaic shape (pattern).
+    ///
+    pub fn draw_rgba<Color, Method>(&self, coloring_method: Method) -> RgbaImage
+    where
+        Color: IntoColor<LinSrgba<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+    {
+        self.draw_triangles(&coloring_method, None, |color: Color, x, y| {
+            sampling::quantize_to_rgba8(color.into_color(), x, y, self.dithering)
+        })
+        .unwrap()
+    }
+
+    /// Creates mosaic image painted with specified coloring method using all available
+    /// threads.
+    ///
+    /// Image is split into horizontal bands which are drawn in parallel. Every band draws
+    /// triangles of mosaic in same order as [`Mosaic::draw`] does, so pixels on shared edges
+    /// of triangles are resolved deterministically and result is identical to result
+    /// of [`Mosaic::draw`].
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image; it should be shareable between threads.
+    ///
+    /// returns: `RgbImage` - painted mosaic image containing mosaic shape (pattern).
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    ///
+    pub fn draw_parallel<Color, Method>(&self, coloring_method: Method) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color> + Sync,
+    {
+        let dithering = self.dithering;
+        self.draw_triangles_parallel(&coloring_method, |color: Color, x, y| {
+            sampling::quantize_to_rgb8(color.into_color(), x, y, dithering)
+        })
+    }
+
+    fn to_rgb8<Color>(&self, color: Color, x: u32, y: u32) -> Rgb<u8>
+    where
+        Color: IntoColor<LinSrgb<f64>>,
+    {
+        sampling::quantize_to_rgb8(color.into_color(), x, y, self.dithering)
+    }
+
+    fn draw_triangles<Color, Method, Output, ToPixel>(
+        &self,
+        coloring_method: &Method,
+        cancelled: Option<&AtomicBool>,
+        to_pixel: ToPixel,
+    ) -> Option<ImageBuffer<Output, Vec<u8>>>
+    where
+        Color: Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+        Output: image::Pixel<Subpixel = u8>,
+        ToPixel: Fn(Color, u32, u32) -> Output,
+    {
+        let mut mosaic_image = ImageBuffer::new(self.image_size.0, self.image_size.1);
+        let vertices_count = self.voronoi.triangulation().triangles.len() / 3;
+        let rasterizer = self.rasterizer();
+        for vertex_index in 0..vertices_count {
+            if cancelled.is_some_and(|cancelled| cancelled.load(atomic::Ordering::Relaxed)) {
+                return None;
+            }
+            rasterizer.draw_triangle(
+                coloring_method,
+                &to_pixel,
+                vertex_index,
+                0..self.image_size.1,
+                |x, y, pixel| mosaic_image.put_pixel(x, y, pixel),
+            );
+        }
+        Some(mosaic_image)
+    }
+
+    fn draw_triangles_parallel<Color, Method, Output, ToPixel>(
+        &self,
+        coloring_method: &Method,
+        to_pixel: ToPixel,
+    ) -> ImageBuffer<Output, Vec<u8>>
+    where
+        Color: Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color> + Sync,
+        Output: image::Pixel<Subpixel = u8>,
+        ToPixel: Fn(Color, u32, u32) -> Output + Sync,
+    {
+        let (image_width, image_height) = self.image_size;
+        let channels_count = Output::CHANNEL_COUNT as usize;
+        let row_length = image_width as usize * channels_count;
+        let mut buffer = vec![0u8; row_length * image_height as usize];
+        let vertices_count = self.voronoi.triangulation().triangles.len() / 3;
+        let rasterizer = self.rasterizer();
+        buffer
+            .par_chunks_mut(row_length * PARALLEL_BAND_HEIGHT as usize)
+            .enumerate()
+            .for_each(|(band_index, band)| {
+                let band_start = band_index as u32 * PARALLEL_BAND_HEIGHT;
+                let band_end = (band_start + PARALLEL_BAND_HEIGHT).min(image_height);
+                for vertex_index in 0..vertices_count {
+                    rasterizer.draw_triangle(
+                        coloring_method,
+                        &to_pixel,
+                        vertex_index,
+                        band_start..band_end,
+                        |x, y, pixel| {
+                            let offset = (y - band_start) as usize * row_length
+                                + x as usize * channels_count;
+                            band[offset..offset + channels_count].copy_from_slice(pixel.channels());
+                        },
+                    );
+                }
+            });
+        ImageBuffer::from_raw(image_width, image_height, buffer).unwrap()
+    }
+
+    fn rasterizer(&self) -> TriangleRasterizer<'_> {
+        TriangleRasterizer {
+            voronoi: &self.voronoi,
+            image_size: self.image_size,
+            shading: self.shading,
+            shading_exponent: self.shading_exponent,
+        }
     }
 }
 
@@ -105,12 +384,38 @@ impl Mosaic for PolygonalMosaic {
         Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
         Method: ColoringMethod<Color>,
     {
-        let mut mosaic_image = RgbImage::new(self.image_size.0, self.image_size.1);
-        let vertices_count = self.voronoi.triangulation().triangles.len() / 3;
-        for vertex_index in 0..vertices_count {
-            self.draw_triangle(&mut mosaic_image, &coloring_method, vertex_index);
+        self.draw_triangles(&coloring_method, None, |color, x, y| {
+            self.to_rgb8(color, x, y)
+        })
+        .unwrap()
+    }
+
+    fn draw_raw<Color, Method>(&self, coloring_method: Method) -> (u32, u32, Vec<u8>)
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+    {
+        let (width, height) = self.image_size;
+        let mut mosaic_image = self
+            .draw_triangles(&coloring_method, None, |color: Color, x, y| {
+                let Rgb([red, green, blue]) = self.to_rgb8(color, x, y);
+                Rgba([red, green, blue, u8::MAX])
+            })
+            .unwrap();
+        // Pixels outside of triangles stay black like in `draw`, so they must be opaque too.
+        for pixel in mosaic_image.pixels_mut() {
+            pixel[3] = u8::MAX;
         }
-        mosaic_image
+        (width, height, mosaic_image.into_raw())
+    }
+
+    fn draw_heightmap(&self) -> GrayImage {
+        self.draw_triangles(
+            &LinSrgb::new(0.0f64, 0.0, 0.0),
+            None,
+            |color: LinSrgb<f64>, _, _| mosaic::lightened_black_to_height(color),
+        )
+        .unwrap()
     }
 
     fn image_size(&self) -> (u32, u32) {
@@ -124,11 +429,393 @@ impl Mosaic for PolygonalMosaic {
     fn shape(&self) -> &Box<dyn MosaicShape> {
         &self.shape
     }
+
+    fn build_settings(&self) -> BuildSettings {
+        self.build_settings
+    }
+
+    fn contains(&self, point: Vector) -> bool {
+        let sites = self.voronoi.sites();
+        let hull: Vec<Vector> = self
+            .voronoi
+            .triangulation()
+            .hull
+            .iter()
+            .map(|site| (&sites[*site]).into())
+            .collect();
+        mosaic::convex_polygon_contains(&hull, point)
+    }
 }
 impl TryToTransform for PolygonalMosaic {
     fn try_to_transform(&self, transformation: &Transformation) -> Option<Self> {
         MosaicBuilder::from(self)
             .set_transformation(transformation)
             .build_polygon()
+            .map(|mut mosaic| {
+                mosaic.set_shading(self.shading);
+                mosaic.set_shading_exponent(self.shading_exponent);
+                mosaic.set_dithering(self.dithering);
+                mosaic
+            })
+    }
+}
+
+/// Draws triangles of Delaunay triangulation of mosaic, so both sequential and parallel
+/// drawing paint pixels of triangles in the same way.
+struct TriangleRasterizer<'mosaic> {
+    voronoi: &'mosaic Voronoi,
+    image_size: (u32, u32),
+    shading: Shading,
+    shading_exponent: f64,
+}
+
+impl TriangleRasterizer<'_> {
+    fn draw_triangle<Color, Method, Output, ToPixel, PutPixel>(
+        &self,
+        coloring_method: &Method,
+        to_pixel: &ToPixel,
+        vertex_index: usize,
+        rows: Range<u32>,
+        put_pixel: PutPixel,
+    ) where
+        Color: Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+        Output: image::Pixel<Subpixel = u8>,
+        ToPixel: Fn(Color, u32, u32) -> Output,
+        PutPixel: FnMut(u32, u32, Output),
+    {
+        let sites = self.voronoi.sites();
+        let triangulation = self.voronoi.triangulation();
+        let vertex_position: Vector = (&self.voronoi.vertices()[vertex_index]).into();
+        let corner_positions: [Coord<f64>; 3] = [
+            (&sites[triangulation.triangles[vertex_index * 3]]).into(),
+            (&sites[triangulation.triangles[vertex_index * 3 + 1]]).into(),
+            (&sites[triangulation.triangles[vertex_index * 3 + 2]]).into(),
+        ];
+        self.fill_triangle(
+            coloring_method,
+            to_pixel,
+            vertex_position,
+            &corner_positions,
+            rows,
+            put_pixel,
+        );
+    }
+
+    fn fill_triangle<Color, Method, Output, ToPixel, PutPixel>(
+        &self,
+        coloring_method: &Method,
+        to_pixel: &ToPixel,
+        vertex_position: Vector,
+        corner_positions: &[Coord<f64>; 3],
+        rows: Range<u32>,
+        mut put_pixel: PutPixel,
+    ) where
+        Color: Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+        Output: image::Pixel<Subpixel = u8>,
+        ToPixel: Fn(Color, u32, u32) -> Output,
+        PutPixel: FnMut(u32, u32, Output),
+    {
+        if rows.is_empty() || is_degenerate_triangle(corner_positions) {
+            return;
+        }
+        let radius = calculate_triangle_radius(vertex_position, corner_positions);
+        let bounding_box = Aabb::from_points(&corner_positions.map(Vector::from)).unwrap();
+        let x_min = bounding_box.min.x.round() as u32;
+        let x_max = (bounding_box.max.x.round() as u32).min(self.image_size.0 - 1);
+        let y_min = (bounding_box.min.y.round() as u32).max(rows.start);
+        let y_max = (bounding_box.max.y.round() as u32).min(rows.end - 1);
+        for x in x_min..=x_max {
+            for y in y_min..=y_max {
+                let position = Vector::new(x as f64, y as f64);
+                let orientations = [
+                    robust::orient2d(corner_positions[0], corner_positions[1], position.into()),
+                    robust::orient2d(corner_positions[1], corner_positions[2], position.into()),
+                    robust::orient2d(corner_positions[2], corner_positions[0], position.into()),
+                ];
+                if orientations[0] <= 0.0 && orientations[1] <= 0.0 && orientations[2] <= 0.0 {
+                    let color = coloring_method.interpolate(position, vertex_position);
+                    let color = match self.shading {
+                        Shading::Flat => color,
+                        Shading::Glow => {
+                            let distance = position.distance_to(vertex_position);
+                            color.lighten(utility::calculate_lightness(
+                                distance / radius,
+                                self.shading_exponent,
+                            ))
+                        }
+                    };
+                    put_pixel(x, y, to_pixel(color, x, y));
+                }
+            }
+        }
+    }
+}
+
+fn is_degenerate_triangle(corner_positions: &[Coord<f64>; 3]) -> bool {
+    let [first, second, third] = corner_positions.map(Vector::from);
+    utility::approx_eq((second - first).cross(third - first), 0.0)
+}
+
+/// Calculates distance from center of triangle (vertex of Voronoi diagram) to its farthest
+/// corner, so lightness of glow reaches zero at extremity of triangle.
+///
+/// Vertices of Voronoi diagram near its bounds are not exact circumcenters of triangles,
+/// so all corners are checked.
+fn calculate_triangle_radius(center: Vector, corner_positions: &[Coord<f64>; 3]) -> f64 {
+    corner_positions
+        .iter()
+        .map(|corner| center.distance_to((*corner).into()))
+        .fold(0.0, f64::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skip_degenerate_triangle() {
+        let mosaic = MosaicBuilder::default().build_polygon().unwrap();
+        let corner_positions = [
+            Coord { x: 10.0, y: 10.0 },
+            Coord { x: 50.0, y: 30.0 },
+            Coord { x: 90.0, y: 50.0 },
+        ];
+        assert!(is_degenerate_triangle(&corner_positions));
+        let mut painted_pixels = vec![];
+        mosaic.rasterizer().fill_triangle(
+            &palette::LinSrgb::new(1.0f64, 0.0, 0.0),
+            &|color: palette::LinSrgb<f64>, _, _| Rgb(color.into_format().into_raw()),
+            Vector::new(50.0, 30.0),
+            &corner_positions,
+            0..640,
+            |x, y, _| painted_pixels.push((x, y)),
+        );
+        assert!(painted_pixels.is_empty());
+        let corner_positions = [
+            Coord { x: 10.0, y: 10.0 },
+            Coord { x: 10.0, y: 50.0 },
+            Coord { x: 90.0, y: 50.0 },
+        ];
+        assert!(!is_degenerate_triangle(&corner_positions));
+    }
+    #[test]
+    fn voronoi() {
+        let builder = MosaicBuilder::default()
+            .set_image_size(400, 300)
+            .set_relative_center(0.5, 0.5)
+            .set_uniform_scale(0.4);
+        let mosaic = builder.clone().build_polygon().unwrap();
+        assert_eq!(mosaic.voronoi().sites().len(), builder.key_points().len());
+    }
+    #[test]
+    fn try_to_transform_with_build_settings() {
+        let builder = MosaicBuilder::default()
+            .set_polygonal_star_shape(9)
+            .set_use_intersections(false)
+            .set_tileable(true);
+        let mosaic = builder.clone().build_polygon().unwrap();
+        let mut transformation = mosaic.transformation().clone();
+        transformation.rotation_angle += 0.5;
+        let transformed_mosaic = mosaic.try_to_transform(&transformation).unwrap();
+        assert_eq!(
+            transformed_mosaic.build_settings(),
+            builder.build_settings()
+        );
+        let expected_mosaic = builder
+            .set_transformation(&transformation)
+            .build_polygon()
+            .unwrap();
+        assert_eq!(
+            transformed_mosaic.voronoi().sites().len(),
+            expected_mosaic.voronoi().sites().len()
+        );
+    }
+    #[test]
+    fn draw_parallel() {
+        let mut mosaic = MosaicBuilder::default()
+            .set_shape(crate::mosaic_shape::Grid::new(24, 24))
+            .set_image_size(300, 250)
+            .set_relative_center(0.5, 0.5)
+            .set_rotation_angle(0.3)
+            .build_polygon()
+            .unwrap();
+        let gradient = palette::Gradient::new(vec![
+            palette::LinSrgb::new(1.0f64, 0.0, 0.0),
+            palette::LinSrgb::new(0.0f64, 1.0, 0.0),
+            palette::LinSrgb::new(0.0f64, 0.0, 1.0),
+        ]);
+        let coloring_method = crate::coloring_method::RadialGradient::new_simple(
+            gradient,
+            mosaic.center(),
+            160.0,
+            0.25,
+        );
+        assert_eq!(
+            mosaic.draw_parallel(coloring_method.clone()),
+            mosaic.draw(coloring_method.clone())
+        );
+        mosaic.set_dithering(true);
+        mosaic.set_shading(Shading::Flat);
+        assert_eq!(
+            mosaic.draw_parallel(coloring_method.clone()),
+            mosaic.draw(coloring_method)
+        );
+    }
+    #[test]
+    fn draw_with_dithering() {
+        let mut mosaic = MosaicBuilder::default()
+            .set_image_size(200, 200)
+            .set_relative_center(0.5, 0.5)
+            .build_polygon()
+            .unwrap();
+        let color = palette::LinSrgb::new(1.0f64, 0.3, 0.0);
+        let image = mosaic.draw(color);
+        mosaic.set_dithering(true);
+        let dithered_image = mosaic.draw(color);
+        let differences: Vec<i32> = image
+            .pixels()
+            .zip(dithered_image.pixels())
+            .flat_map(|(pixel, dithered_pixel)| {
+                (0..3).map(|channel| pixel[channel] as i32 - dithered_pixel[channel] as i32)
+            })
+            .collect();
+        assert!(differences.iter().any(|difference| *difference != 0));
+        assert!(differences.iter().all(|difference| difference.abs() <= 1));
+        mosaic.set_shading(Shading::Flat);
+        let flat_color = palette::LinSrgb::new(1.0f64, 0.2, 0.0);
+        let dithered_image = mosaic.draw(flat_color);
+        mosaic.set_dithering(false);
+        assert_eq!(dithered_image, mosaic.draw(flat_color));
+    }
+    #[test]
+    fn calculate_radius_of_skewed_triangle() {
+        let corner_positions: [Coord<f64>; 3] = [
+            Coord { x: 0.0, y: 0.0 },
+            Coord { x: 100.0, y: 0.0 },
+            Coord { x: 0.0, y: 5.0 },
+        ];
+        let center = Vector::new(10.0, 2.0);
+        let radius = calculate_triangle_radius(center, &corner_positions);
+        assert_eq!(radius, Vector::new(90.0, 2.0).length());
+        for x in 0..=100 {
+            for y in 0..=5 {
+                let position = Vector::new(x as f64, y as f64);
+                if position.x / 100.0 + position.y / 5.0 <= 1.0 {
+                    assert!(1.0 - position.distance_to(center) / radius >= 0.0);
+                }
+            }
+        }
+    }
+    #[test]
+    fn draw_raw() {
+        let mosaic = MosaicBuilder::default().build_polygon().unwrap();
+        let color = LinSrgb::new(1.0f64, 0.5, 0.0);
+        let mosaic_image = mosaic.draw(color);
+        let (width, height, bytes) = mosaic.draw_raw(color);
+        assert_eq!((width, height), mosaic.image_size());
+        assert_eq!(bytes.len(), (width * height * 4) as usize);
+        let Rgb([red, green, blue]) = *mosaic_image.get_pixel(0, 0);
+        assert_eq!(bytes[..4], [red, green, blue, u8::MAX]);
+        assert!(mosaic_image
+            .pixels()
+            .zip(bytes.chunks_exact(4))
+            .all(|(pixel, raw_pixel)| pixel.0 == raw_pixel[..3]));
+    }
+    #[test]
+    fn draw_rgba() {
+        let mosaic = MosaicBuilder::default().build_polygon().unwrap();
+        let mosaic_image = mosaic.draw_rgba(palette::LinSrgba::new(0.0f64, 0.5, 1.0, 0.5));
+        assert_eq!(mosaic_image.dimensions(), mosaic.image_size());
+        assert!(mosaic_image
+            .pixels()
+            .all(|pixel| pixel[3] == 0 || pixel[3] == 128));
+        assert_eq!(mosaic_image.get_pixel(320, 320)[3], 128);
+    }
+    #[test]
+    fn draw_wireframe() {
+        let mosaic = MosaicBuilder::default().build_polygon().unwrap();
+        let color = LinSrgb::new(1.0f64, 1.0, 1.0);
+        let background = LinSrgb::new(0.0f64, 0.0, 0.0);
+        let wireframe_image = mosaic.draw_wireframe(color, background, 1.0);
+        assert_eq!(wireframe_image.dimensions(), mosaic.image_size());
+        let triangulation = mosaic.voronoi.triangulation();
+        let sites = mosaic.voronoi.sites();
+        let corners: Vec<Vector> = triangulation.triangles[..3]
+            .iter()
+            .map(|site| (&sites[*site]).into())
+            .collect();
+        let middle = corners[0].interpolate(corners[1], 0.5);
+        let (x, y) = (middle.x.round() as u32, middle.y.round() as u32);
+        assert_eq!(*wireframe_image.get_pixel(x, y), Rgb([255, 255, 255]));
+        let centroid = (corners[0] + corners[1] + corners[2]) / 3.0;
+        let (x, y) = (centroid.x.round() as u32, centroid.y.round() as u32);
+        assert_eq!(*wireframe_image.get_pixel(x, y), Rgb([0, 0, 0]));
+    }
+    #[test]
+    fn draw_with_flat_shading() {
+        let mut mosaic = MosaicBuilder::default().build_polygon().unwrap();
+        assert_eq!(mosaic.shading(), Shading::Glow);
+        mosaic.set_shading(Shading::Flat);
+        let color = LinSrgb::new(0.0f64, 0.5, 1.0);
+        let mosaic_image = mosaic.draw(color);
+        let (background, fill) = (Rgb([0, 0, 0]), Rgb(color.into_format().into_raw()));
+        assert!(mosaic_image
+            .pixels()
+            .all(|pixel| *pixel == background || *pixel == fill));
+        mosaic.set_shading(Shading::Glow);
+        let mosaic_image = mosaic.draw(color);
+        assert!(mosaic_image
+            .pixels()
+            .any(|pixel| *pixel != background && *pixel != fill));
+    }
+    #[test]
+    fn draw_heightmap() {
+        let mosaic = MosaicBuilder::default().build_polygon().unwrap();
+        let heightmap = mosaic.draw_heightmap();
+        assert_eq!(heightmap.dimensions(), mosaic.image_size());
+        let triangulation = mosaic.voronoi.triangulation();
+        let sites = mosaic.voronoi.sites();
+        let center: Vector = (&mosaic.voronoi.vertices()[0]).into();
+        let corners: Vec<Vector> = triangulation.triangles[..3]
+            .iter()
+            .map(|site| (&sites[*site]).into())
+            .collect();
+        let centroid = (corners[0] + corners[1] + corners[2]) / 3.0;
+        let (x, y) = (centroid.x.round() as u32, centroid.y.round() as u32);
+        let centroid_ratio = centroid.distance_to(center) / corners[0].distance_to(center);
+        let expected_value = 255.0 * (1.0 - centroid_ratio).powi(2);
+        assert!((heightmap.get_pixel(x, y)[0] as f64 - expected_value).abs() <= 8.0);
+        for corner in corners {
+            let position = corner.interpolate(centroid, 0.02);
+            let (x, y) = (position.x.round() as u32, position.y.round() as u32);
+            assert!(heightmap.get_pixel(x, y)[0] <= 5);
+        }
+    }
+    #[test]
+    fn draw_heightmap_with_dithering() {
+        let mut mosaic = MosaicBuilder::default().build_polygon().unwrap();
+        let heightmap = mosaic.draw_heightmap();
+        mosaic.set_dithering(true);
+        assert_eq!(mosaic.draw_heightmap(), heightmap);
+        assert!(heightmap.pixels().any(|height| height[0] % 255 != 0));
+    }
+    #[test]
+    fn draw_cancellable() {
+        let mosaic = MosaicBuilder::default()
+            .set_image_size(64, 48)
+            .set_relative_center(0.5, 0.5)
+            .set_uniform_scale(0.1)
+            .build_polygon()
+            .unwrap();
+        let color = LinSrgb::new(0.0f64, 0.5, 1.0);
+        let cancelled = AtomicBool::new(true);
+        assert!(mosaic.draw_cancellable(color, &cancelled).is_none());
+        cancelled.store(false, atomic::Ordering::Relaxed);
+        assert_eq!(
+            mosaic.draw_cancellable(color, &cancelled),
+            Some(mosaic.draw(color))
+        );
     }
 }
@@ -0,0 +1,21 @@
+use image::{Rgb, RgbImage};
+
+use super::{segment::Segment, vector::Vector};
+
+pub fn draw_segment(image: &mut RgbImage, segment: &Segment, pixel: Rgb<u8>, thickness: f64) {
+    let half_thickness = thickness.max(1.0) * 0.5;
+    let (image_width, image_height) = image.dimensions();
+    let x_min = (segment.start.x.min(segment.end.x) - half_thickness).floor();
+    let x_max = (segment.start.x.max(segment.end.x) + half_thickness).ceil();
+    let y_min = (segment.start.y.min(segment.end.y) - half_thickness).floor();
+    let y_max = (segment.start.y.max(segment.end.y) + half_thickness).ceil();
+    let x_range = x_min.max(0.0) as u32..=x_max.min(image_width as f64 - 1.0).max(0.0) as u32;
+    let y_range = y_min.max(0.0) as u32..=y_max.min(image_height as f64 - 1.0).max(0.0) as u32;
+    for y in y_range {
+        for x in x_range.clone() {
+            if segment.distance_to(Vector::new(x as f64, y as f64)) <= half_thickness {
+                image.put_pixel(x, y, pixel);
+            }
+        }
+    }
+}
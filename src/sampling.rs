@@ -0,0 +1,303 @@
+//! This module provides helpers to convert colors of mosaic images between linear and
+//! 8-bit sRGB color spaces and to downsample (supersampled) mosaic images.
+//!
+//! Values of pixels of mosaic image are linear components of colors (see
+//! [`Mosaic::draw`][`super::Mosaic::draw`]), so their averaging is averaging in linear
+//! color space, which preserves brightness: mean of black and white pixels is 128 (which is
+//! gamma encoded to 188 in sRGB). Averaging of gamma encoded values instead darkens result,
+//! so by default pixels are averaged as is.
+
+use image::{Rgb, RgbImage, Rgba};
+use palette::{LinSrgb, LinSrgba, Pixel, Srgb};
+
+/// Defines how colors of neighbouring pixels are averaged while downsampling mosaic image.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Averaging {
+    /// Values of pixels (linear components of colors) are averaged as is.
+    ///
+    /// Preserves brightness of image and so is physically correct.
+    #[default]
+    Linear,
+
+    /// Values of pixels are gamma encoded to sRGB, averaged and decoded back.
+    ///
+    /// Matches behaviour of image editors which average sRGB images, but darkens fine details.
+    Srgb,
+}
+
+const BAYER_MATRIX: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Converts color components to 8-bit pixel applying ordered (Bayer) dither at given position.
+///
+/// Threshold of rounding of every component depends on position of pixel, so smooth gradients
+/// turn into fine pattern of neighbouring 8-bit values instead of visible bands. Components
+/// that are exactly representable by 8-bit values are left unchanged.
+pub(crate) fn dither_to_rgb8(color: LinSrgb<f64>, x: u32, y: u32) -> Rgb<u8> {
+    let threshold = (BAYER_MATRIX[(y % 4) as usize][(x % 4) as usize] as f64 + 0.5) / 16.0 - 0.5;
+    let components: [f64; 3] = color.into_raw();
+    Rgb(components.map(|component| (component * 255.0 + threshold).round().clamp(0.0, 255.0) as u8))
+}
+
+/// Converts color components to 8-bit pixel of mosaic image, optionally dithering it.
+pub(crate) fn quantize_to_rgb8(color: LinSrgb<f64>, x: u32, y: u32, dithering: bool) -> Rgb<u8> {
+    if dithering {
+        dither_to_rgb8(color, x, y)
+    } else {
+        Rgb(color.into_format().into_raw())
+    }
+}
+
+/// Converts color components and opacity to 8-bit pixel of mosaic image, optionally
+/// dithering its color (but not opacity).
+pub(crate) fn quantize_to_rgba8(color: LinSrgba<f64>, x: u32, y: u32, dithering: bool) -> Rgba<u8> {
+    let Rgb([red, green, blue]) = quantize_to_rgb8(color.color, x, y, dithering);
+    let alpha = (color.alpha * 255.0).round().clamp(0.0, 255.0) as u8;
+    Rgba([red, green, blue, alpha])
+}
+
+/// Converts color from linear color space to gamma encoded 8-bit sRGB pixel.
+///
+/// Mosaic images contain linear components of colors, so this function isn't used while
+/// drawing them; it helps to convert colors for consumers which expect sRGB values.
+///
+/// # Arguments
+///
+/// * `color`: color in linear sRGB color space.
+///
+/// returns: `Rgb<u8>` - gamma encoded 8-bit sRGB pixel.
+///
+/// # Examples
+///
+/// ```
+/// use image::Rgb;
+/// use palette::LinSrgb;
+/// use starry_mosaic::sampling::linear_to_srgb8;
+///
+/// assert_eq!(linear_to_srgb8(LinSrgb::new(0.0, 0.5, 1.0)), Rgb([0, 188, 255]));
+/// ```
+pub fn linear_to_srgb8(color: LinSrgb<f64>) -> Rgb<u8> {
+    Rgb(Srgb::from_linear(color).into_format().into_raw())
+}
+
+/// Converts gamma encoded 8-bit sRGB pixel to color in linear color space.
+///
+/// # Arguments
+///
+/// * `pixel`: gamma encoded 8-bit sRGB pixel.
+///
+/// returns: `LinSrgb<f64>` - color in linear sRGB color space.
+///
+/// # See also
+///
+/// * [`linear_to_srgb8`].
+///
+pub fn srgb8_to_linear(pixel: Rgb<u8>) -> LinSrgb<f64> {
+    Srgb::new(pixel[0], pixel[1], pixel[2])
+        .into_format::<f64>()
+        .into_linear()
+}
+
+/// Downsamples image by averaging every square block of pixels.
+///
+/// To get smooth (antialiased) mosaic image draw mosaic with several times larger size and
+/// downsample resulting image with same factor.
+///
+/// # Arguments
+///
+/// * `image`: image to downsample.
+/// * `factor`: width and height of block of pixels which becomes single pixel of resulting image;
+///   should be at least 1.
+/// * `averaging`: method of averaging colors of pixels.
+///
+/// returns: `RgbImage` - downsampled image; its size is size of source image divided by `factor`
+/// (but at least 1 pixel in both dimensions).
+///
+/// # Examples
+///
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use starry_mosaic::sampling::{downsample, Averaging};
+///
+/// let image = RgbImage::from_fn(4, 2, |x, _| Rgb([255 * (x % 2) as u8; 3]));
+/// let downsampled_image = downsample(&image, 2, Averaging::Linear);
+///
+/// assert_eq!(downsampled_image.dimensions(), (2, 1));
+/// assert_eq!(*downsampled_image.get_pixel(0, 0), Rgb([128, 128, 128]));
+/// ```
+pub fn downsample(image: &RgbImage, factor: u32, averaging: Averaging) -> RgbImage {
+    let factor = factor.max(1);
+    let (width, height) = image.dimensions();
+    let (target_width, target_height) = ((width / factor).max(1), (height / factor).max(1));
+    RgbImage::from_fn(target_width, target_height, |target_x, target_y| {
+        let (x_start, y_start) = (target_x * factor, target_y * factor);
+        let (x_end, y_end) = (
+            (x_start + factor).min(width),
+            (y_start + factor).min(height),
+        );
+        average_block(image, (x_start, y_start), (x_end, y_end), averaging)
+    })
+}
+
+/// Downsamples image so its longest side equals given size, preserving aspect ratio.
+///
+/// Unlike [`downsample`] this function supports arbitrary (non-integer) ratios of sizes:
+/// every pixel of resulting image averages block of source pixels it covers (box filter).
+///
+/// # Arguments
+///
+/// * `image`: image to downsample.
+/// * `max_dimension`: size of longest side of resulting image; should be at least 1.
+/// * `averaging`: method of averaging colors of pixels.
+///
+/// returns: `RgbImage` - downsampled image; if image already fits into given size,
+/// its copy is returned.
+///
+/// # Examples
+///
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use starry_mosaic::sampling::{downsample_to_fit, Averaging};
+///
+/// let image = RgbImage::from_pixel(300, 120, Rgb([64, 128, 255]));
+/// let downsampled_image = downsample_to_fit(&image, 100, Averaging::Linear);
+///
+/// assert_eq!(downsampled_image.dimensions(), (100, 40));
+/// assert_eq!(*downsampled_image.get_pixel(50, 20), Rgb([64, 128, 255]));
+/// ```
+pub fn downsample_to_fit(image: &RgbImage, max_dimension: u32, averaging: Averaging) -> RgbImage {
+    let max_dimension = max_dimension.max(1);
+    let (width, height) = image.dimensions();
+    let longest_side = width.max(height);
+    if longest_side <= max_dimension {
+        return image.clone();
+    }
+    let fit = |side: u32| {
+        ((side as u64 * max_dimension as u64 + longest_side as u64 / 2) / longest_side as u64)
+            .max(1) as u32
+    };
+    let (target_width, target_height) = (fit(width), fit(height));
+    let block_start = |index: u32, size: u32, target_size: u32| {
+        (index as u64 * size as u64 / target_size as u64) as u32
+    };
+    let block_end = |index: u32, size: u32, target_size: u32| {
+        let end = ((index as u64 + 1) * size as u64).div_ceil(target_size as u64) as u32;
+        end.clamp(block_start(index, size, target_size) + 1, size)
+    };
+    RgbImage::from_fn(target_width, target_height, |target_x, target_y| {
+        average_block(
+            image,
+            (
+                block_start(target_x, width, target_width),
+                block_start(target_y, height, target_height),
+            ),
+            (
+                block_end(target_x, width, target_width),
+                block_end(target_y, height, target_height),
+            ),
+            averaging,
+        )
+    })
+}
+
+fn average_block(
+    image: &RgbImage,
+    (x_start, y_start): (u32, u32),
+    (x_end, y_end): (u32, u32),
+    averaging: Averaging,
+) -> Rgb<u8> {
+    let mut sum = [0.0f64; 3];
+    for y in y_start..y_end {
+        for x in x_start..x_end {
+            let color = LinSrgb::from_raw(&image.get_pixel(x, y).0).into_format::<f64>();
+            let components: [f64; 3] = match averaging {
+                Averaging::Linear => color.into_raw(),
+                Averaging::Srgb => Srgb::from_linear(color).into_raw(),
+            };
+            sum.iter_mut()
+                .zip(components)
+                .for_each(|(sum, component)| *sum += component);
+        }
+    }
+    let pixels_count = ((x_end - x_start) * (y_end - y_start)) as f64;
+    let [red, green, blue] = sum.map(|component| component / pixels_count);
+    let color = match averaging {
+        Averaging::Linear => LinSrgb::new(red, green, blue),
+        Averaging::Srgb => Srgb::new(red, green, blue).into_linear(),
+    };
+    Rgb(color.into_format().into_raw())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_black_and_white_image() -> RgbImage {
+        RgbImage::from_fn(4, 4, |x, y| Rgb([255 * ((x + y) % 2) as u8; 3]))
+    }
+
+    #[test]
+    fn linear_to_srgb8_mid_gray() {
+        let pixel = linear_to_srgb8(LinSrgb::new(0.5, 0.5, 0.5));
+        assert_eq!(pixel, Rgb([188, 188, 188]));
+    }
+    #[test]
+    fn srgb8_to_linear_round_trip() {
+        for value in [0u8, 17, 64, 128, 188, 255] {
+            let pixel = Rgb([value; 3]);
+            assert_eq!(linear_to_srgb8(srgb8_to_linear(pixel)), pixel);
+        }
+    }
+    #[test]
+    fn dither_to_rgb8_keeps_exact_components() {
+        let color = LinSrgb::new(0.0, 1.0, 0.2);
+        for (x, y) in [(0, 0), (1, 2), (3, 3), (7, 5)] {
+            assert_eq!(dither_to_rgb8(color, x, y), Rgb([0, 255, 51]));
+        }
+    }
+    #[test]
+    fn dither_to_rgb8_spreads_intermediate_components() {
+        let color = LinSrgb::new(100.5 / 255.0, 0.0, 0.0);
+        let reds: Vec<u8> = (0..4)
+            .flat_map(|y| (0..4).map(move |x| dither_to_rgb8(color, x, y)[0]))
+            .collect();
+        assert_eq!(reds.iter().filter(|red| **red == 100).count(), 8);
+        assert_eq!(reds.iter().filter(|red| **red == 101).count(), 8);
+    }
+    #[test]
+    fn downsample_linear() {
+        let image = create_black_and_white_image();
+        let downsampled_image = downsample(&image, 2, Averaging::Linear);
+        assert_eq!(downsampled_image.dimensions(), (2, 2));
+        for pixel in downsampled_image.pixels() {
+            assert_eq!(*pixel, Rgb([128, 128, 128]));
+            let color = LinSrgb::from_raw(&pixel.0).into_format();
+            assert_eq!(linear_to_srgb8(color), Rgb([188, 188, 188]));
+        }
+    }
+    #[test]
+    fn downsample_srgb() {
+        let image = create_black_and_white_image();
+        let downsampled_image = downsample(&image, 2, Averaging::Srgb);
+        for pixel in downsampled_image.pixels() {
+            assert_eq!(*pixel, Rgb([55, 55, 55]));
+            let color = LinSrgb::from_raw(&pixel.0).into_format();
+            assert_eq!(linear_to_srgb8(color), Rgb([128, 128, 128]));
+        }
+    }
+    #[test]
+    fn downsample_to_fit_with_fractional_ratio() {
+        let image = RgbImage::from_fn(10, 5, |x, _| Rgb([255 * (x % 2) as u8; 3]));
+        let downsampled_image = downsample_to_fit(&image, 4, Averaging::Linear);
+        assert_eq!(downsampled_image.dimensions(), (4, 2));
+        let image = create_black_and_white_image();
+        assert_eq!(downsample_to_fit(&image, 8, Averaging::Linear), image);
+        let downsampled_image = downsample_to_fit(&image, 2, Averaging::Linear);
+        assert_eq!(downsampled_image, downsample(&image, 2, Averaging::Linear));
+    }
+    #[test]
+    fn downsample_with_incorrect_factor() {
+        let image = create_black_and_white_image();
+        let downsampled_image = downsample(&image, 0, Averaging::Linear);
+        assert_eq!(downsampled_image, image);
+    }
+}
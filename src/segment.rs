@@ -71,6 +71,106 @@ impl Segment {
         self.start.distance_to(self.end)
     }
 
+    /// Calculates direction angle of line segment (from its start to its end), in radians.
+    ///
+    /// Note that unlike comparison of line segments, angle depends on order of points:
+    /// inverse line segment has opposite direction.
+    ///
+    /// returns: `f64` - angle between positive direction of X axis and direction of line segment
+    /// ranging from -π to π.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts;
+    ///
+    /// use starry_mosaic::{Segment, Vector};
+    ///
+    /// let segment = Segment::new(Vector::new(1.0, 1.0), Vector::new(3.0, 3.0));
+    ///
+    /// assert_eq!(segment.angle(), consts::FRAC_PI_4);
+    /// ```
+    pub fn angle(&self) -> f64 {
+        let segment_vector = self.end - self.start;
+        segment_vector.y.atan2(segment_vector.x)
+    }
+
+    /// Checks whether this line segment is parallel to another one.
+    ///
+    /// Collinear line segments are parallel too.
+    ///
+    /// # Arguments
+    ///
+    /// * `segment`: line segment which direction is compared with direction of this one.
+    ///
+    /// returns: `bool` - `true` if line segments are parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{Segment, Vector};
+    ///
+    /// let segment = Segment::new(Vector::new(0.0, 0.0), Vector::new(4.0, 2.0));
+    ///
+    /// assert!(segment.is_parallel_to(&Segment::new(Vector::new(0.0, 1.0), Vector::new(-2.0, 0.0))));
+    /// assert!(!segment.is_parallel_to(&Segment::new(Vector::new(0.0, 1.0), Vector::new(2.0, 0.0))));
+    /// ```
+    pub fn is_parallel_to(&self, segment: &Self) -> bool {
+        let self_vector = self.end - self.start;
+        let segment_vector = segment.end - segment.start;
+        utility::approx_eq(self_vector.cross(segment_vector), 0.0)
+    }
+
+    /// Finds distance from given point to closest point of line segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `point`: point to which distance is calculated.
+    ///
+    /// returns: f64 - distance between point and line segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{Segment, Vector};
+    ///
+    /// let segment = Segment::new(Vector::new(-2.0, 1.0), Vector::new(6.0, 1.0));
+    ///
+    /// assert_eq!(segment.distance_to(Vector::new(3.0, 4.0)), 3.0);
+    /// assert_eq!(segment.distance_to(Vector::new(9.0, 5.0)), 5.0);
+    /// ```
+    pub fn distance_to(&self, point: Vector) -> f64 {
+        point.distance_to(self.closest_point(point))
+    }
+
+    /// Finds point of line segment closest to given point.
+    ///
+    /// # Arguments
+    ///
+    /// * `point`: point to which closest point of line segment is searched.
+    ///
+    /// returns: [`Vector`] - point of line segment closest to given point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{Segment, Vector};
+    ///
+    /// let segment = Segment::new(Vector::new(-2.0, 1.0), Vector::new(6.0, 1.0));
+    ///
+    /// assert_eq!(segment.closest_point(Vector::new(3.0, 4.0)), Vector::new(3.0, 1.0));
+    /// assert_eq!(segment.closest_point(Vector::new(9.0, 5.0)), Vector::new(6.0, 1.0));
+    /// ```
+    pub fn closest_point(&self, point: Vector) -> Vector {
+        let segment_vector = self.end - self.start;
+        let squared_length = segment_vector.squared_length();
+        if utility::approx_eq(squared_length, 0.0) {
+            return self.start;
+        }
+        let factor = (point - self.start).dot(segment_vector) / squared_length;
+        self.start.interpolate(self.end, factor)
+    }
+
     /// Computes point of intersection of this line segment with another one, if such point exists.
     ///
     /// # Examples
@@ -100,6 +200,61 @@ impl Segment {
         }
         None
     }
+
+    /// Clips line segment to axis-aligned rectangle using Liang–Barsky algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `min`: corner of rectangle with minimal coordinates.
+    /// * `max`: corner of rectangle with maximal coordinates.
+    ///
+    /// returns: `Option<Segment>` - part of line segment which lies inside rectangle;
+    /// `None` if line segment is completely outside of rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{Segment, Vector};
+    ///
+    /// let segment = Segment::new(Vector::new(-5.0, 2.0), Vector::new(5.0, 2.0));
+    /// let clipped_segment = segment.clip_to_rect(Vector::new(0.0, 0.0), Vector::new(4.0, 4.0));
+    ///
+    /// assert_eq!(
+    ///     clipped_segment,
+    ///     Some(Segment::new(Vector::new(0.0, 2.0), Vector::new(4.0, 2.0)))
+    /// );
+    /// ```
+    pub fn clip_to_rect(&self, min: Vector, max: Vector) -> Option<Segment> {
+        let segment_vector = self.end - self.start;
+        let boundaries = [
+            (-segment_vector.x, self.start.x - min.x),
+            (segment_vector.x, max.x - self.start.x),
+            (-segment_vector.y, self.start.y - min.y),
+            (segment_vector.y, max.y - self.start.y),
+        ];
+        let (mut start_factor, mut end_factor) = (0.0f64, 1.0f64);
+        for (direction, distance) in boundaries {
+            if utility::approx_eq(direction, 0.0) {
+                if distance < 0.0 {
+                    return None;
+                }
+                continue;
+            }
+            let factor = distance / direction;
+            if direction < 0.0 {
+                start_factor = start_factor.max(factor);
+            } else {
+                end_factor = end_factor.min(factor);
+            }
+            if start_factor > end_factor {
+                return None;
+            }
+        }
+        Some(Segment::new(
+            self.start.interpolate(self.end, start_factor),
+            self.start.interpolate(self.end, end_factor),
+        ))
+    }
 }
 
 impl Debug for Segment {
@@ -151,6 +306,8 @@ impl Transform for Segment {
 
 #[cfg(test)]
 mod tests {
+    use std::f64::consts;
+
     use super::*;
     use crate::transform::Scale;
 
@@ -165,6 +322,58 @@ mod tests {
         assert_eq!(segment.length(), 5.0);
     }
     #[test]
+    fn angle() {
+        let segment = Segment::from(((1.0, 2.0), (5.0, 2.0)));
+        assert_eq!(segment.angle(), 0.0);
+        let segment = Segment::from(((5.0, 2.0), (1.0, 2.0)));
+        assert_eq!(segment.angle(), consts::PI);
+        let segment = Segment::from(((1.0, 2.0), (1.0, 7.0)));
+        assert_eq!(segment.angle(), consts::FRAC_PI_2);
+        let segment = Segment::from(((1.0, 2.0), (-2.0, -1.0)));
+        assert_eq!(segment.angle(), -3.0 * consts::FRAC_PI_4);
+    }
+    #[test]
+    fn is_parallel_to() {
+        let segment = Segment::from(((0.0, 0.0), (3.0, 3.0)));
+        assert!(segment.is_parallel_to(&Segment::from(((5.0, 1.0), (7.0, 3.0)))));
+        assert!(segment.is_parallel_to(&Segment::from(((1.0, 1.0), (-2.0, -2.0)))));
+        assert!(!segment.is_parallel_to(&Segment::from(((0.0, 3.0), (3.0, 0.0)))));
+        let horizontal_segment = Segment::from(((0.0, 1.0), (4.0, 1.0)));
+        let vertical_segment = Segment::from(((2.0, -1.0), (2.0, 3.0)));
+        assert!(!horizontal_segment.is_parallel_to(&vertical_segment));
+        assert!(horizontal_segment.intersect(&vertical_segment).is_some());
+        assert!(horizontal_segment.is_parallel_to(&Segment::from(((-3.0, 5.0), (8.0, 5.0)))));
+    }
+    #[test]
+    fn distance_to() {
+        let segment = Segment::from(((1.0, 1.0), (5.0, 5.0)));
+        assert_eq!(segment.distance_to(Vector::new(5.0, 1.0)), 8.0f64.sqrt());
+        assert_eq!(segment.distance_to(Vector::new(-2.0, -3.0)), 5.0);
+        assert_eq!(segment.distance_to(Vector::new(3.0, 3.0)), 0.0);
+    }
+    #[test]
+    fn distance_to_degenerate_segment() {
+        let segment = Segment::from(((2.0, 2.0), (2.0, 2.0)));
+        assert_eq!(segment.distance_to(Vector::new(5.0, 6.0)), 5.0);
+    }
+    #[test]
+    fn closest_point() {
+        let segment = Segment::from(((1.0, 1.0), (5.0, 5.0)));
+        assert_eq!(
+            segment.closest_point(Vector::new(5.0, 1.0)),
+            Vector::new(3.0, 3.0)
+        );
+        assert_eq!(
+            segment.closest_point(Vector::new(-2.0, -3.0)),
+            Vector::new(1.0, 1.0)
+        );
+        let segment = Segment::from(((2.0, 2.0), (2.0, 2.0)));
+        assert_eq!(
+            segment.closest_point(Vector::new(5.0, 6.0)),
+            Vector::new(2.0, 2.0)
+        );
+    }
+    #[test]
     fn intersect() {
         let first = Segment::from(((-1.0, -1.0), (2.0, 2.0)));
         let second = Segment::from(((-3.0, 3.0), (5.0, -5.0)));
@@ -182,12 +391,37 @@ mod tests {
         assert!(intersection.is_none());
     }
     #[test]
+    fn clip_to_rect_partially_inside() {
+        let segment = Segment::from(((-2.0, -1.0), (6.0, 3.0)));
+        let clipped_segment = segment.clip_to_rect(Vector::new(0.0, 0.0), Vector::new(4.0, 4.0));
+        assert_eq!(
+            clipped_segment,
+            Some(Segment::from(((0.0, 0.0), (4.0, 2.0))))
+        );
+    }
+    #[test]
+    fn clip_to_rect_inside() {
+        let segment = Segment::from(((1.0, 3.0), (3.0, 1.0)));
+        let clipped_segment = segment.clip_to_rect(Vector::new(0.0, 0.0), Vector::new(4.0, 4.0));
+        assert_eq!(clipped_segment, Some(segment));
+    }
+    #[test]
+    fn clip_to_rect_outside() {
+        let min = Vector::new(0.0, 0.0);
+        let max = Vector::new(4.0, 4.0);
+        let segment = Segment::from(((5.0, -1.0), (5.0, 6.0)));
+        assert!(segment.clip_to_rect(min, max).is_none());
+        let segment = Segment::from(((-1.0, 3.0), (2.0, 8.0)));
+        assert!(segment.clip_to_rect(min, max).is_none());
+    }
+    #[test]
     fn transform() {
         let transformation = Transformation {
             translation: Vector::new(-50.0, 100.0),
             rotation_angle: std::f64::consts::FRAC_PI_2,
             scale: Scale::new(-2.0, 1.5),
             shear: Vector::new(1.0, 0.5),
+            ..Default::default()
         };
         let segment = Segment::from(((0.0, 100.0), (200.0, -50.0)));
         let transformed_segment = segment.transform(&transformation);
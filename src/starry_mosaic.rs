@@ -1,13 +1,23 @@
-use image::{Rgb, RgbImage};
-use palette::{IntoColor, LinSrgb, Mix, Pixel, Shade};
-use voronoice::Voronoi;
+use std::{
+    cmp::Ordering,
+    collections::HashSet,
+    f64::consts,
+    sync::atomic::{self, AtomicBool},
+};
+
+use image::{GrayImage, ImageBuffer, Luma, Rgb, RgbImage, Rgba, RgbaImage};
+use palette::{Gradient, IntoColor, LinSrgb, LinSrgba, Mix, Pixel, Shade};
+use voronoice::{TopologicalNeighborSiteIterator, Voronoi};
 
 use super::{
     coloring_method::ColoringMethod,
-    mosaic::Mosaic,
-    mosaic_builder::MosaicBuilder,
+    mosaic::{self, Mosaic, Shading},
+    mosaic_builder::{BuildSettings, MosaicBuilder},
     mosaic_shape::MosaicShape,
+    rasterization, sampling,
+    segment::Segment,
     transform::{Transformation, TryToTransform},
+    utility,
     vector::Vector,
 };
 
@@ -25,9 +35,106 @@ use super::{
 #[derive(Clone, Debug)]
 pub struct StarryMosaic {
     voronoi: Voronoi,
+    maximum_cell_distances: Vec<f64>,
     image_size: (u32, u32),
     transformation: Transformation,
     shape: Box<dyn MosaicShape>,
+    build_settings: BuildSettings,
+    shading: Shading,
+    shading_exponent: f64,
+    dithering: bool,
+    site_weights: Option<Vec<f64>>,
+    metric: DistanceMetric,
+}
+
+/// Statistics of drawing of [starry mosaic][`StarryMosaic`] image.
+///
+/// Statistics help to profile and tune mosaics: search of site (cell) of every pixel walks
+/// through neighbouring cells of Voronoi diagram, so large count of visited cells per pixel
+/// means slow drawing.
+///
+/// # See also
+///
+/// * [`StarryMosaic::draw_with_stats`].
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RenderStats {
+    /// Count of sites (cells) of mosaic.
+    pub site_count: usize,
+    /// Average count of cells visited while searching for site of every pixel;
+    /// 0.0 if mosaic has weights of sites (sites are searched without walking through
+    /// cells then).
+    pub average_path_steps: f64,
+    /// Count of drawn pixels.
+    pub pixels_processed: u64,
+}
+
+/// Defines how distance between pixel and site (key point) of [starry mosaic][`StarryMosaic`]
+/// is measured while assigning pixels to cells of mosaic.
+///
+/// # See also
+///
+/// * [`StarryMosaic::set_metric`].
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DistanceMetric {
+    /// Ordinary (straight line) distance; cells of mosaic are cells of Voronoi diagram.
+    #[default]
+    Euclidean,
+
+    /// Sum of absolute differences of coordinates (taxicab distance); cells of mosaic have
+    /// edges which are horizontal, vertical or diagonal.
+    Manhattan,
+
+    /// Maximum of absolute differences of coordinates; cells of mosaic have edges which are
+    /// horizontal, vertical or diagonal.
+    Chebyshev,
+}
+
+impl DistanceMetric {
+    /// Calculates distance between two points according to metric.
+    ///
+    /// # Arguments
+    ///
+    /// * `start`: first point.
+    /// * `end`: second point.
+    ///
+    /// returns: `f64` - distance between points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{DistanceMetric, Vector};
+    ///
+    /// let start = Vector::new(1.0, 2.0);
+    /// let end = Vector::new(4.0, -2.0);
+    ///
+    /// assert_eq!(DistanceMetric::Euclidean.distance(start, end), 5.0);
+    /// assert_eq!(DistanceMetric::Manhattan.distance(start, end), 7.0);
+    /// assert_eq!(DistanceMetric::Chebyshev.distance(start, end), 4.0);
+    /// ```
+    pub fn distance(&self, start: Vector, end: Vector) -> f64 {
+        match self {
+            Self::Euclidean => start.distance_to(end),
+            _ => self.squared_distance(start, end).sqrt(),
+        }
+    }
+
+    fn euclidean_distance_factor(&self) -> f64 {
+        match self {
+            Self::Euclidean | Self::Manhattan => 1.0,
+            Self::Chebyshev => consts::SQRT_2,
+        }
+    }
+
+    fn squared_distance(&self, start: Vector, end: Vector) -> f64 {
+        let difference = end - start;
+        match self {
+            Self::Euclidean => difference.squared_length(),
+            Self::Manhattan => (difference.x.abs() + difference.y.abs()).powi(2),
+            Self::Chebyshev => difference.x.abs().max(difference.y.abs()).powi(2),
+        }
+    }
 }
 
 impl StarryMosaic {
@@ -36,18 +143,871 @@ impl StarryMosaic {
         image_size: (u32, u32),
         transformation: Transformation,
         shape: Box<dyn MosaicShape>,
+        build_settings: BuildSettings,
     ) -> Self {
+        let maximum_cell_distances = Self::calculate_maximum_cell_distances(&voronoi);
         Self {
             voronoi,
+            maximum_cell_distances,
             image_size,
             transformation,
             shape,
+            build_settings,
+            shading: Shading::default(),
+            shading_exponent: 2.0,
+            dithering: false,
+            site_weights: None,
+            metric: DistanceMetric::default(),
         }
     }
 
-    fn calculate_maximum_cell_distances(&self) -> Vec<f64> {
-        let mut maximum_cell_distances = vec![0.0f64; self.voronoi.cells().len()];
-        self.voronoi.iter_cells().for_each(|cell| {
+    /// Creates starry mosaic from already built Voronoi diagram.
+    ///
+    /// This constructor skips construction of mosaic shape, so sites of Voronoi diagram
+    /// are fully controlled by caller. Diagram should satisfy next invariants:
+    ///
+    /// * sites are given in coordinate space of mosaic image (already transformed),
+    /// * bounding box of diagram is centered at center of image and covers whole image.
+    ///
+    /// `transformation` and `shape` are not used to compute cells of mosaic; they are only
+    /// stored, so mosaic can be converted back to [`MosaicBuilder`].
+    ///
+    /// # Arguments
+    ///
+    /// * `voronoi`: Voronoi diagram on which mosaic is based.
+    /// * `image_size`: width and height of mosaic (and created images).
+    /// * `transformation`: transformation (position, rotation, scale and shear) of mosaic shape.
+    /// * `shape`: mosaic shape associated with mosaic.
+    ///
+    /// returns: [`StarryMosaic`] - mosaic based on given Voronoi diagram.
+    ///
+    /// # See also
+    ///
+    /// * [MosaicBuilder::build_star][`super::mosaic_builder::MosaicBuilder::build_star`].
+    ///
+    pub fn from_voronoi(
+        voronoi: Voronoi,
+        image_size: (u32, u32),
+        transformation: Transformation,
+        shape: Box<dyn MosaicShape>,
+    ) -> Self {
+        Self::new(
+            voronoi,
+            image_size,
+            transformation,
+            shape,
+            BuildSettings::default(),
+        )
+    }
+
+    /// Voronoi diagram which defines cells of mosaic.
+    ///
+    /// Sites, vertices and cells of diagram are given in coordinate space of mosaic image
+    /// (in pixels), so they can be used directly for custom analysis of mosaic.
+    /// Key points of mosaic which lie outside of bounding box of diagram are not its sites.
+    #[inline(always)]
+    pub fn voronoi(&self) -> &Voronoi {
+        &self.voronoi
+    }
+
+    /// Shading of cells of mosaic used while drawing mosaic image.
+    ///
+    /// By default cells are shaded with [`Shading::Glow`].
+    #[inline(always)]
+    pub fn shading(&self) -> Shading {
+        self.shading
+    }
+
+    /// Sets shading of cells of mosaic used while drawing mosaic image.
+    ///
+    /// # Arguments
+    ///
+    /// * `shading`: new [shading][`Shading`] of mosaic cells.
+    ///
+    pub fn set_shading(&mut self, shading: Shading) {
+        self.shading = shading;
+    }
+
+    /// Exponent of falloff of lightness from center to edges of cells of mosaic.
+    ///
+    /// Used only by [`Shading::Glow`]; lightness of every pixel equals
+    /// `(1.0 - distance_ratio).powf(shading_exponent)`. Default exponent is 2.0.
+    #[inline(always)]
+    pub fn shading_exponent(&self) -> f64 {
+        self.shading_exponent
+    }
+
+    /// Sets exponent of falloff of lightness from center to edges of cells of mosaic.
+    ///
+    /// # Arguments
+    ///
+    /// * `shading_exponent`: new exponent of falloff; 1.0 gives linear falloff, larger values
+    ///   give sharper glow; should be non-negative.
+    ///
+    pub fn set_shading_exponent(&mut self, shading_exponent: f64) {
+        self.shading_exponent = shading_exponent.max(0.0);
+    }
+
+    /// Whether ordered dither is applied while converting colors of mosaic image to 8-bit
+    /// pixels.
+    ///
+    /// Dithering is disabled by default.
+    #[inline(always)]
+    pub fn dithering(&self) -> bool {
+        self.dithering
+    }
+
+    /// Sets whether ordered dither is applied while converting colors of mosaic image
+    /// to 8-bit pixels.
+    ///
+    /// Dithering reduces visible banding of smooth gradients and glow of large cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `dithering`: whether ordered (Bayer) dither is applied.
+    ///
+    pub fn set_dithering(&mut self, dithering: bool) {
+        self.dithering = dithering;
+    }
+
+    /// Weights of key points (sites) of mosaic, if mosaic is drawn as weighted Voronoi diagram.
+    ///
+    /// Weights are listed in the same order as sites of Voronoi diagram of mosaic.
+    #[inline(always)]
+    pub fn site_weights(&self) -> Option<&[f64]> {
+        self.site_weights.as_deref()
+    }
+
+    /// Sets weights of key points (sites) of mosaic, so mosaic image is drawn
+    /// as weighted Voronoi diagram (power diagram).
+    ///
+    /// Every pixel of mosaic image belongs to site with the least `distance² - weight`,
+    /// so cells of sites with larger weights grow at the expense of their neighbours.
+    /// When all weights are equal, cells are the same as cells of ordinary Voronoi diagram.
+    ///
+    /// **_Note_**: weights affect only drawing of painted mosaic images; other geometry
+    /// (cells, adjacency, wireframe) is based on ordinary Voronoi diagram. Glow of cells is
+    /// measured by sizes of weighted cells, which are found by checking every pixel of mosaic
+    /// image. Weighted drawing checks every site for every pixel, so it's much slower than
+    /// ordinary one. Weights set by this method are lost when mosaic is transformed; weights
+    /// of key points supplied by [mosaic shape][`MosaicShape::set_up_weights`] are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `weight`: function which calculates weight of site by its position in mosaic image;
+    ///   weight is measured in squared pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{MosaicBuilder, Vector};
+    ///
+    /// let mut mosaic = MosaicBuilder::default().build_star().unwrap();
+    /// let center = Vector::new(320.0, 320.0);
+    /// mosaic.set_site_weights(|site| {
+    ///     if site.distance_to(center) < 100.0 { 2500.0 } else { 0.0 }
+    /// });
+    ///
+    /// assert!(mosaic.site_weights().is_some());
+    /// ```
+    pub fn set_site_weights<WeightFunction>(&mut self, weight: WeightFunction)
+    where
+        WeightFunction: Fn(Vector) -> f64,
+    {
+        let site_weights: Vec<f64> = self
+            .voronoi
+            .sites()
+            .iter()
+            .map(|site| weight(site.into()))
+            .collect();
+        self.set_site_weight_list(site_weights);
+    }
+
+    pub(crate) fn set_site_weight_list(&mut self, site_weights: Vec<f64>) {
+        self.site_weights = Some(site_weights);
+        self.update_maximum_cell_distances();
+    }
+
+    /// Removes weights of key points (sites) of mosaic, so mosaic image is drawn
+    /// as ordinary Voronoi diagram.
+    pub fn clear_site_weights(&mut self) {
+        self.site_weights = None;
+        self.update_maximum_cell_distances();
+    }
+
+    /// Metric which measures distance between pixels and sites of mosaic while assigning pixels
+    /// to cells of mosaic.
+    ///
+    /// By default distance is measured with [`DistanceMetric::Euclidean`].
+    #[inline(always)]
+    pub fn metric(&self) -> DistanceMetric {
+        self.metric
+    }
+
+    /// Sets metric which measures distance between pixels and sites of mosaic while assigning
+    /// pixels to cells of mosaic.
+    ///
+    /// Every pixel of mosaic image belongs to the closest site according to metric, so
+    /// non-Euclidean metrics create cells with rectilinear (and diagonal) edges. Search of such
+    /// site starts from the closest (by Euclidean distance) site of pixel and checks only
+    /// neighbouring cells which can contain closer site, so drawing is nearly as fast as
+    /// ordinary one. If mosaic has
+    /// [weights of sites][`StarryMosaic::set_site_weights`], every site is checked instead
+    /// and weights are subtracted from squared distance measured with metric.
+    ///
+    /// **_Note_**: metric affects only drawing of painted mosaic images and
+    /// [site map][`StarryMosaic::site_map`]; other geometry (cells, adjacency, wireframe)
+    /// is based on ordinary Voronoi diagram. Glow of cells is measured by sizes of cells
+    /// drawn with metric, which are found by checking every pixel of mosaic image whenever
+    /// non-Euclidean metric is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `metric`: new [distance metric][`DistanceMetric`] of mosaic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::{DistanceMetric, MosaicBuilder};
+    ///
+    /// let mut mosaic = MosaicBuilder::default().build_star().unwrap();
+    /// mosaic.set_metric(DistanceMetric::Manhattan);
+    ///
+    /// assert_eq!(mosaic.metric(), DistanceMetric::Manhattan);
+    /// ```
+    pub fn set_metric(&mut self, metric: DistanceMetric) {
+        self.metric = metric;
+        self.update_maximum_cell_distances();
+    }
+
+    /// Creates mosaic image painted with specified coloring method.
+    ///
+    /// Voronoi diagram of mosaic (and other geometry derived from it) is calculated once
+    /// when mosaic is built, so repainting existing mosaic with another coloring method
+    /// is much cheaper than building new one.
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image.
+    ///
+    /// returns: `RgbImage` - painted mosaic image containing mosaic shape (pattern).
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    ///
+    #[inline(always)]
+    pub fn recolor<Color, Method>(&self, coloring_method: Method) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+    {
+        self.draw(coloring_method)
+    }
+
+    /// Draws painted mosaic image and reports progress of drawing.
+    ///
+    /// Drawing large mosaic images takes noticeable time, so this method allows to track
+    /// its progress (for example, to update progress bar).
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image.
+    /// * `on_progress`: callback invoked after every drawn row of image with fraction
+    ///   of drawn rows (from 0.0 to 1.0).
+    ///
+    /// returns: `RgbImage` - painted mosaic image containing mosaic shape (pattern).
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    ///
+    pub fn draw_with_progress<Color, Method, OnProgress>(
+        &self,
+        coloring_method: Method,
+        on_progress: OnProgress,
+    ) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+        OnProgress: FnMut(f32),
+    {
+        self.draw_rows(
+            &coloring_method,
+            (0, 0),
+            self.image_size,
+            None,
+            None,
+            |color, x, y| self.to_rgb8(color, x, y),
+            on_progress,
+        )
+        .unwrap()
+    }
+
+    /// Draws painted mosaic image and collects statistics of drawing.
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image.
+    ///
+    /// returns: `(RgbImage, RenderStats)` - painted mosaic image containing mosaic shape (pattern)
+    /// and [statistics][`RenderStats`] of its drawing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::MosaicBuilder;
+    ///
+    /// let starry_mosaic = MosaicBuilder::default().build_star().unwrap();
+    /// let (mosaic_image, stats) = starry_mosaic.draw_with_stats(LinSrgb::new(1.0f64, 0.5, 0.0));
+    ///
+    /// assert_eq!(stats.pixels_processed, 640 * 640);
+    /// assert!(stats.average_path_steps >= 1.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    ///
+    pub fn draw_with_stats<Color, Method>(&self, coloring_method: Method) -> (RgbImage, RenderStats)
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+    {
+        let mut path_steps = 0;
+        let mosaic_image = self
+            .draw_rows(
+                &coloring_method,
+                (0, 0),
+                self.image_size,
+                None,
+                Some(&mut path_steps),
+                |color, x, y| self.to_rgb8(color, x, y),
+                |_| {},
+            )
+            .unwrap();
+        let pixels_processed = mosaic_image.width() as u64 * mosaic_image.height() as u64;
+        let average_path_steps = if pixels_processed > 0 {
+            path_steps as f64 / pixels_processed as f64
+        } else {
+            0.0
+        };
+        let stats = RenderStats {
+            site_count: self.voronoi.sites().len(),
+            average_path_steps,
+            pixels_processed,
+        };
+        (mosaic_image, stats)
+    }
+
+    /// Draws painted mosaic image unless drawing is cancelled.
+    ///
+    /// Cancellation flag is checked before drawing of every row of image, so long drawing
+    /// can be aborted from another thread (for example, when parameters of mosaic are changed
+    /// during drawing).
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image.
+    /// * `cancelled`: flag which aborts drawing when set to `true`.
+    ///
+    /// returns: `Option<RgbImage>` - painted mosaic image containing mosaic shape (pattern);
+    /// `None` if drawing is cancelled.
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    /// * [`StarryMosaic::draw_with_progress`].
+    ///
+    pub fn draw_cancellable<Color, Method>(
+        &self,
+        coloring_method: Method,
+        cancelled: &AtomicBool,
+    ) -> Option<RgbImage>
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+    {
+        self.draw_rows(
+            &coloring_method,
+            (0, 0),
+            self.image_size,
+            Some(cancelled),
+            None,
+            |color, x, y| self.to_rgb8(color, x, y),
+            |_| {},
+        )
+    }
+
+    /// Draws rectangular region (tile) of painted mosaic image.
+    ///
+    /// Region is drawn exactly as the same part of image created by [`Mosaic::draw`], so
+    /// huge mosaic images can be drawn in tiles which are stitched seamlessly.
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image.
+    /// * `x`: horizontal position of top left corner of region in mosaic image.
+    /// * `y`: vertical position of top left corner of region in mosaic image.
+    /// * `width`: width of region; region is clipped to bounds of mosaic image.
+    /// * `height`: height of region; region is clipped to bounds of mosaic image.
+    ///
+    /// returns: `RgbImage` - painted region of mosaic image.
+    ///
+    /// # See also
+    ///
+    /// * [`Mosaic::draw`].
+    ///
+    pub fn draw_region<Color, Method>(
+        &self,
+        coloring_method: Method,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    ) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+    {
+        let origin = (x.min(self.image_size.0), y.min(self.image_size.1));
+        let size = (
+            width.min(self.image_size.0 - origin.0),
+            height.min(self.image_size.1 - origin.1),
+        );
+        self.draw_rows(
+            &coloring_method,
+            origin,
+            size,
+            None,
+            None,
+            |color, x, y| self.to_rgb8(color, x, y),
+            |_| {},
+        )
+        .unwrap()
+    }
+
+    /// Creates mosaic image with opacity painted with specified coloring method.
+    ///
+    /// Unlike [`Mosaic::draw`] this method preserves opacity (alpha channel) of colors
+    /// of coloring method, so it allows to paint mosaic with semi-transparent colors
+    /// and gradients.
+    ///
+    /// # Arguments
+    ///
+    /// * `coloring_method`: [coloring method][`ColoringMethod`] used to draw every pixel
+    ///   of mosaic shape in image.
+    ///
+    /// returns: `RgbaImage` - painted mosaic image with opacity containing mosaic shape (pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgba;
+    /// use starry_mosaic::MosaicBuilder;
+    ///
+    /// let starry_mosaic = MosaicBuilder::default().build_star().unwrap();
+    /// let mosaic_image = starry_mosaic.draw_rgba(LinSrgba::new(1.0f64, 0.5, 0.0, 0.5));
+    ///
+    /// assert_eq!(mosaic_image.get_pixel(100, 100)[3], 128);
+    /// ```
+    pub fn draw_rgba<Color, Method>(&self, coloring_method: Method) -> RgbaImage
+    where
+        Color: IntoColor<LinSrgba<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+    {
+        self.draw_rows(
+            &coloring_method,
+            (0, 0),
+            self.image_size,
+            None,
+            None,
+            |color: Color, x, y| {
+                sampling::quantize_to_rgba8(color.into_color(), x, y, self.dithering)
+            },
+            |_| {},
+        )
+        .unwrap()
+    }
+
+    /// Iterates over cells of mosaic (cells of Voronoi diagram).
+    ///
+    /// returns: `impl Iterator<Item = (Vector, Vec<Vector>)>` - iterator over pairs of site
+    /// (key point) of every cell and vertices of polygon of this cell clipped to bounds of mosaic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::MosaicBuilder;
+    ///
+    /// let starry_mosaic = MosaicBuilder::default().build_star().unwrap();
+    ///
+    /// for (_site, polygon) in starry_mosaic.cells() {
+    ///     assert!(polygon.len() >= 3);
+    ///     assert!(polygon.iter().all(|vertex| vertex.x >= 0.0 && vertex.x <= 640.0));
+    ///     assert!(polygon.iter().all(|vertex| vertex.y >= 0.0 && vertex.y <= 640.0));
+    /// }
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = (Vector, Vec<Vector>)> + '_ {
+        let (image_width, image_height) = (self.image_size.0 as f64, self.image_size.1 as f64);
+        self.voronoi.iter_cells().map(move |cell| {
+            let polygon = cell.iter_vertices().map(|vertex| vertex.into()).collect();
+            (
+                cell.site_position().into(),
+                clip_polygon(polygon, image_width, image_height),
+            )
+        })
+    }
+
+    /// Creates image of edges of cells of mosaic (edges of Voronoi diagram) without filling
+    /// these cells.
+    ///
+    /// Every edge shared by two neighbouring cells is drawn only once.
+    ///
+    /// # Arguments
+    ///
+    /// * `color`: color of edges of mosaic cells.
+    /// * `background`: color of background of image.
+    /// * `thickness`: thickness of edges, in pixels; should be at least 1.0.
+    ///
+    /// returns: `RgbImage` - image containing edges of mosaic cells.
+    ///
+    pub fn draw_wireframe<Color>(&self, color: Color, background: Color, thickness: f64) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>>,
+    {
+        let color: LinSrgb<f64> = color.into_color();
+        let background: LinSrgb<f64> = background.into_color();
+        let mut wireframe_image = RgbImage::from_pixel(
+            self.image_size.0,
+            self.image_size.1,
+            Rgb(background.into_format().into_raw()),
+        );
+        let pixel = Rgb(color.into_format().into_raw());
+        let vertices = self.voronoi.vertices();
+        let mut drawn_edges = HashSet::new();
+        for cell in self.voronoi.cells() {
+            for (index, start) in cell.iter().enumerate() {
+                let end = cell[(index + 1) % cell.len()];
+                if !drawn_edges.insert((*start.min(&end), *start.max(&end))) {
+                    continue;
+                }
+                let edge = Segment::new((&vertices[*start]).into(), (&vertices[end]).into());
+                rasterization::draw_segment(&mut wireframe_image, &edge, pixel, thickness);
+            }
+        }
+        wireframe_image
+    }
+
+    /// Builds adjacency graph of cells of mosaic (cells of Voronoi diagram).
+    ///
+    /// Two cells are adjacent if they share an edge of non-zero length, so cells which touch
+    /// each other only at single corner (like diagonal cells of grid) are not adjacent.
+    ///
+    /// returns: `Vec<Vec<usize>>` - sorted indices of neighbouring cells for every cell
+    /// of mosaic (indexed same as sites of Voronoi diagram).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::MosaicBuilder;
+    ///
+    /// let starry_mosaic = MosaicBuilder::default().build_star().unwrap();
+    /// let adjacency = starry_mosaic.adjacency();
+    ///
+    /// for (cell, neighbours) in adjacency.iter().enumerate() {
+    ///     for neighbour in neighbours {
+    ///         assert!(adjacency[*neighbour].contains(&cell));
+    ///     }
+    /// }
+    /// ```
+    pub fn adjacency(&self) -> Vec<Vec<usize>> {
+        let triangulation = self.voronoi.triangulation();
+        let vertices = self.voronoi.vertices();
+        let mut adjacency = vec![Vec::new(); self.voronoi.sites().len()];
+        for (halfedge, start) in triangulation.triangles.iter().enumerate() {
+            let next_halfedge = if halfedge % 3 == 2 {
+                halfedge - 2
+            } else {
+                halfedge + 1
+            };
+            let end = triangulation.triangles[next_halfedge];
+            let opposite_halfedge = triangulation.halfedges[halfedge];
+            if opposite_halfedge != usize::MAX {
+                let first_vertex: Vector = (&vertices[halfedge / 3]).into();
+                let second_vertex: Vector = (&vertices[opposite_halfedge / 3]).into();
+                if first_vertex == second_vertex {
+                    continue;
+                }
+            }
+            adjacency[*start].push(end);
+            adjacency[end].push(*start);
+        }
+        adjacency.iter_mut().for_each(|neighbours| {
+            neighbours.sort_unstable();
+            neighbours.dedup();
+        });
+        adjacency
+    }
+
+    /// Assigns palette index to every cell of mosaic, so that no two adjacent cells share
+    /// the same index (greedy graph coloring).
+    ///
+    /// Cells are colored in order of decreasing number of neighbours (Welsh-Powell algorithm);
+    /// every cell takes smallest index unused by its already colored neighbours.
+    ///
+    /// # Arguments
+    ///
+    /// * `palette`: colors which cells of mosaic are colored with.
+    ///
+    /// returns: `Option<Vec<usize>>` - palette index of every cell of mosaic (indexed same
+    /// as sites of Voronoi diagram), or `None` if palette has fewer colors than
+    /// greedy coloring needs.
+    ///
+    /// # See also
+    ///
+    /// * [`StarryMosaic::adjacency`].
+    ///
+    pub fn color_cells_graph<Color>(&self, palette: &[Color]) -> Option<Vec<usize>> {
+        let adjacency = self.adjacency();
+        let mut cells: Vec<usize> = (0..adjacency.len()).collect();
+        cells.sort_by(|left, right| adjacency[*right].len().cmp(&adjacency[*left].len()));
+        let mut color_indices = vec![usize::MAX; adjacency.len()];
+        for cell in cells {
+            let color_index = (0..palette.len()).find(|color_index| {
+                adjacency[cell]
+                    .iter()
+                    .all(|neighbour| color_indices[*neighbour] != *color_index)
+            })?;
+            color_indices[cell] = color_index;
+        }
+        Some(color_indices)
+    }
+
+    /// Lists indices of mosaic cells (sites of Voronoi diagram) in deterministic spatial order.
+    ///
+    /// Cells are sorted by position of their sites: from left to right and then from
+    /// top to bottom.
+    ///
+    /// returns: `Vec<usize>` - indices of mosaic cells sorted by position of their sites.
+    ///
+    pub fn cells_sorted(&self) -> Vec<usize> {
+        let sites = self.voronoi.sites();
+        let mut cells: Vec<usize> = (0..sites.len()).collect();
+        cells.sort_by(|left, right| {
+            let left_site: Vector = (&sites[*left]).into();
+            let right_site: Vector = (&sites[*right]).into();
+            left_site
+                .partial_cmp(&right_site)
+                .unwrap_or(Ordering::Equal)
+        });
+        cells
+    }
+
+    /// Positions of key points of mosaic (sites of Voronoi diagram).
+    ///
+    /// returns: `Vec<Vector>` - positions of sites in mosaic image, indexed same as cells
+    /// of mosaic.
+    ///
+    pub fn sites(&self) -> Vec<Vector> {
+        self.voronoi
+            .sites()
+            .iter()
+            .map(|site| site.into())
+            .collect()
+    }
+
+    /// Assigns every pixel of mosaic image to site (cell) it belongs to.
+    ///
+    /// Pixels are assigned exactly as while drawing painted mosaic images (including weights
+    /// of sites, if they are set), so site map shows partition of image independent of colors.
+    ///
+    /// returns: `Vec<usize>` - index of site of every pixel of mosaic image, listed row by row.
+    ///
+    /// # See also
+    ///
+    /// * [`StarryMosaic::sites`].
+    /// * [`StarryMosaic::draw_site_map`].
+    ///
+    pub fn site_map(&self) -> Vec<usize> {
+        let (width, height) = self.image_size;
+        let mut site_map = Vec::with_capacity((width * height) as usize);
+        let mut site_search = self.site_search();
+        let mut current_site = self.find_nearest_site(Vector::default());
+        for y in 0..height {
+            for x in 0..width {
+                let position = Vector::new(x as f64, y as f64);
+                current_site = self
+                    .find_pixel_site(&mut site_search, current_site, position)
+                    .0;
+                site_map.push(current_site);
+            }
+        }
+        site_map
+    }
+
+    /// Creates grayscale image of [site map][`StarryMosaic::site_map`] of mosaic.
+    ///
+    /// Value of every pixel equals index of its site modulo 256.
+    ///
+    /// returns: `GrayImage` - image of partition of mosaic into cells.
+    ///
+    pub fn draw_site_map(&self) -> GrayImage {
+        let site_map = self.site_map();
+        GrayImage::from_fn(self.image_size.0, self.image_size.1, |x, y| {
+            Luma([(site_map[(y * self.image_size.0 + x) as usize] % 256) as u8])
+        })
+    }
+
+    /// Draws mosaic image which cells are colored according to their areas.
+    ///
+    /// Image is drawn in two passes: first pass counts pixels of every cell (as in
+    /// [site map][`StarryMosaic::site_map`]), second one fills every cell with color of gradient
+    /// at area of cell divided by area of the largest cell. So the largest cell takes color
+    /// at position 1.0 of gradient, and cells which don't cover any pixel take color
+    /// at position 0.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `gradient`: list of colors or colors stops of gradient which cells are colored with.
+    ///
+    /// returns: `RgbImage` - mosaic image with cells colored by their areas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use palette::LinSrgb;
+    /// use starry_mosaic::{Mosaic, MosaicBuilder};
+    ///
+    /// let starry_mosaic = MosaicBuilder::default().build_star().unwrap();
+    /// let gradient = vec![
+    ///     (0.0, LinSrgb::new(0.0f64, 0.0, 0.0)),
+    ///     (1.0, LinSrgb::new(1.0f64, 1.0, 1.0)),
+    /// ];
+    ///
+    /// let mosaic_image = starry_mosaic.draw_by_area(gradient);
+    ///
+    /// assert_eq!(mosaic_image.dimensions(), starry_mosaic.image_size());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`StarryMosaic::site_map`].
+    ///
+    pub fn draw_by_area<Color, ColorGradient>(&self, gradient: ColorGradient) -> RgbImage
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Clone,
+        ColorGradient: Into<Gradient<Color>>,
+    {
+        let gradient = gradient.into();
+        let site_map = self.site_map();
+        let mut areas = vec![0usize; self.voronoi.sites().len()];
+        for site in &site_map {
+            areas[*site] += 1;
+        }
+        let maximum_area = areas.iter().copied().max().unwrap_or(0).max(1) as f64;
+        let cell_colors: Vec<Color> = areas
+            .iter()
+            .map(|area| gradient.get(*area as f64 / maximum_area))
+            .collect();
+        RgbImage::from_fn(self.image_size.0, self.image_size.1, |x, y| {
+            let site = site_map[(y * self.image_size.0 + x) as usize];
+            self.to_rgb8(cell_colors[site].clone(), x, y)
+        })
+    }
+
+    fn to_rgb8<Color>(&self, color: Color, x: u32, y: u32) -> Rgb<u8>
+    where
+        Color: IntoColor<LinSrgb<f64>>,
+    {
+        sampling::quantize_to_rgb8(color.into_color(), x, y, self.dithering)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_rows<Color, Method, Output, ToPixel, OnProgress>(
+        &self,
+        coloring_method: &Method,
+        origin: (u32, u32),
+        size: (u32, u32),
+        cancelled: Option<&AtomicBool>,
+        mut path_steps: Option<&mut u64>,
+        to_pixel: ToPixel,
+        mut on_progress: OnProgress,
+    ) -> Option<ImageBuffer<Output, Vec<u8>>>
+    where
+        Color: Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+        Output: image::Pixel<Subpixel = u8>,
+        ToPixel: Fn(Color, u32, u32) -> Output,
+        OnProgress: FnMut(f32),
+    {
+        let mut mosaic_image = ImageBuffer::new(size.0, size.1);
+        let cell_colors: Option<Vec<Color>> = coloring_method.is_uniform_per_cell().then(|| {
+            self.voronoi
+                .sites()
+                .iter()
+                .enumerate()
+                .map(|(site, site_position)| {
+                    let site_position: Vector = site_position.into();
+                    coloring_method.interpolate_indexed(site_position, site_position, site)
+                })
+                .collect()
+        });
+        let mut site_search = self.site_search();
+        let mut current_site =
+            self.find_nearest_site(Vector::new(origin.0 as f64, origin.1 as f64));
+        let mut current_site_position: Vector = (&self.voronoi.sites()[current_site]).into();
+        for (x, y, pixel) in mosaic_image.enumerate_pixels_mut() {
+            if x == 0
+                && cancelled.is_some_and(|cancelled| cancelled.load(atomic::Ordering::Relaxed))
+            {
+                return None;
+            }
+            let position = Vector::new((origin.0 + x) as f64, (origin.1 + y) as f64);
+            let (site, steps) = self.find_pixel_site(&mut site_search, current_site, position);
+            if let Some(path_steps) = path_steps.as_deref_mut() {
+                *path_steps += steps;
+            }
+            if current_site != site {
+                current_site = site;
+                current_site_position = (&self.voronoi.sites()[current_site]).into();
+            }
+            let color = match &cell_colors {
+                Some(cell_colors) => cell_colors[current_site].clone(),
+                None => coloring_method.interpolate_indexed(
+                    position,
+                    current_site_position,
+                    current_site,
+                ),
+            };
+            let color = match self.shading {
+                Shading::Flat => color,
+                Shading::Glow => {
+                    let distance = position.distance_to(current_site_position);
+                    let distance_ratio = distance / self.maximum_cell_distances[current_site];
+                    color.lighten(utility::calculate_lightness(
+                        distance_ratio,
+                        self.shading_exponent,
+                    ))
+                }
+            };
+            *pixel = to_pixel(color, origin.0 + x, origin.1 + y);
+            if x + 1 == size.0 {
+                on_progress((y + 1) as f32 / size.1 as f32);
+            }
+        }
+        Some(mosaic_image)
+    }
+
+    fn calculate_maximum_cell_distances(voronoi: &Voronoi) -> Vec<f64> {
+        let mut maximum_cell_distances = vec![0.0f64; voronoi.cells().len()];
+        voronoi.iter_cells().for_each(|cell| {
             let site = cell.site();
             let site_position: Vector = cell.site_position().into();
             cell.iter_vertices().for_each(|vertex| {
@@ -60,13 +1020,194 @@ impl StarryMosaic {
         maximum_cell_distances
     }
 
-    fn find_closest_site(&self, site: usize, vector: Vector) -> usize {
+    fn update_maximum_cell_distances(&mut self) {
+        self.maximum_cell_distances = Self::calculate_maximum_cell_distances(&self.voronoi);
+        if self.site_weights.is_some() || self.metric != DistanceMetric::Euclidean {
+            self.maximum_cell_distances = self.calculate_pixel_maximum_cell_distances();
+        }
+    }
+
+    // Cells drawn with weights or non-Euclidean metric differ from cells of Voronoi diagram,
+    // so their sizes are found by assigning every pixel to its site. Sizes of cells of
+    // Voronoi diagram are kept as lower bounds, since search of sites relies on them.
+    fn calculate_pixel_maximum_cell_distances(&self) -> Vec<f64> {
+        let mut maximum_cell_distances = self.maximum_cell_distances.clone();
+        let mut site_search = self.site_search();
+        let mut current_site = self.find_nearest_site(Vector::default());
+        for y in 0..self.image_size.1 {
+            for x in 0..self.image_size.0 {
+                let position = Vector::new(x as f64, y as f64);
+                current_site = self
+                    .find_pixel_site(&mut site_search, current_site, position)
+                    .0;
+                let distance = position.distance_to((&self.voronoi.sites()[current_site]).into());
+                if distance > maximum_cell_distances[current_site] {
+                    maximum_cell_distances[current_site] = distance;
+                }
+            }
+        }
+        maximum_cell_distances
+    }
+
+    fn walk_to_closest_site(&self, site: usize, vector: Vector) -> (usize, u64) {
         self.voronoi
             .cell(site)
             .iter_path(vector.into())
-            .last()
-            .unwrap_or(site)
+            .fold((site, 0), |(_, steps), next_site| (next_site, steps + 1))
+    }
+
+    fn find_nearest_site(&self, vector: Vector) -> usize {
+        self.voronoi
+            .sites()
+            .iter()
+            .map(|site| vector.squared_distance_to(site.into()))
+            .enumerate()
+            .min_by(|(_, left), (_, right)| left.total_cmp(right))
+            .map_or(0, |(site, _)| site)
+    }
+
+    fn find_pixel_site(
+        &self,
+        site_search: &mut SiteSearch,
+        site: usize,
+        vector: Vector,
+    ) -> (usize, u64) {
+        match (&self.site_weights, self.metric) {
+            (Some(site_weights), _) => (self.find_closest_weighted_site(site_weights, vector), 0),
+            (None, DistanceMetric::Euclidean) => self.walk_to_closest_site(site, vector),
+            (None, _) => {
+                let (closest_site, steps) = self.walk_to_closest_site(site, vector);
+                (
+                    self.find_closest_site(site_search, closest_site, vector),
+                    steps,
+                )
+            }
+        }
+    }
+
+    fn site_search(&self) -> SiteSearch {
+        SiteSearch::new(self.voronoi.sites().len())
+    }
+
+    fn find_closest_site(
+        &self,
+        site_search: &mut SiteSearch,
+        site: usize,
+        vector: Vector,
+    ) -> usize {
+        let sites = self.voronoi.sites();
+        let squared_distance =
+            |site: usize| self.metric.squared_distance(vector, (&sites[site]).into());
+        // Site which is the closest according to metric lies within this (Euclidean) radius,
+        // so only cells which can reach this circle are checked.
+        let search_radius = self.metric.euclidean_distance_factor() * squared_distance(site).sqrt();
+        let mut closest_site = (squared_distance(site), site);
+        site_search.start(site);
+        while let Some(current_site) = site_search.pending_sites.pop() {
+            for neighbour in TopologicalNeighborSiteIterator::new(&self.voronoi, current_site) {
+                if !site_search.visit(neighbour) {
+                    continue;
+                }
+                let neighbour_distance = vector.distance_to((&sites[neighbour]).into());
+                if neighbour_distance - self.maximum_cell_distances[neighbour] > search_radius {
+                    continue;
+                }
+                let candidate = (squared_distance(neighbour), neighbour);
+                if candidate.0 < closest_site.0
+                    || (candidate.0 == closest_site.0 && candidate.1 < closest_site.1)
+                {
+                    closest_site = candidate;
+                }
+                site_search.pending_sites.push(neighbour);
+            }
+        }
+        closest_site.1
+    }
+
+    fn find_closest_weighted_site(&self, site_weights: &[f64], vector: Vector) -> usize {
+        self.scan_closest_site(|site| site_weights[site], vector)
+    }
+
+    fn scan_closest_site<Weight>(&self, weight: Weight, vector: Vector) -> usize
+    where
+        Weight: Fn(usize) -> f64,
+    {
+        self.voronoi
+            .sites()
+            .iter()
+            .enumerate()
+            .map(|(site, position)| {
+                self.metric.squared_distance(vector, position.into()) - weight(site)
+            })
+            .enumerate()
+            .min_by(|(_, left), (_, right)| left.total_cmp(right))
+            .map_or(0, |(site, _)| site)
+    }
+}
+
+/// Buffers of search of the closest site according to non-Euclidean metric, which are reused
+/// while pixels are assigned to sites.
+///
+/// Sites are marked as visited with number of current search, so marks don't have to be
+/// cleared before every search.
+struct SiteSearch {
+    visit_marks: Vec<u32>,
+    search_number: u32,
+    pending_sites: Vec<usize>,
+}
+
+impl SiteSearch {
+    fn new(sites_count: usize) -> Self {
+        Self {
+            visit_marks: vec![0; sites_count],
+            search_number: 0,
+            pending_sites: Vec::new(),
+        }
+    }
+
+    fn start(&mut self, site: usize) {
+        self.search_number = self.search_number.wrapping_add(1);
+        if self.search_number == 0 {
+            self.visit_marks.fill(0);
+            self.search_number = 1;
+        }
+        self.pending_sites.clear();
+        self.visit(site);
+        self.pending_sites.push(site);
     }
+
+    fn visit(&mut self, site: usize) -> bool {
+        let is_visited = self.visit_marks[site] == self.search_number;
+        self.visit_marks[site] = self.search_number;
+        !is_visited
+    }
+}
+
+fn clip_polygon(polygon: Vec<Vector>, width: f64, height: f64) -> Vec<Vector> {
+    let boundaries: [(Vector, f64); 4] = [
+        (Vector::new(1.0, 0.0), 0.0),
+        (Vector::new(-1.0, 0.0), -width),
+        (Vector::new(0.0, 1.0), 0.0),
+        (Vector::new(0.0, -1.0), -height),
+    ];
+    boundaries
+        .iter()
+        .fold(polygon, |polygon, (normal, offset)| {
+            let is_inside = |point: &Vector| normal.dot(*point) >= *offset;
+            let mut clipped_polygon = Vec::with_capacity(polygon.len() + 1);
+            for (index, current) in polygon.iter().enumerate() {
+                let previous = &polygon[(index + polygon.len() - 1) % polygon.len()];
+                if is_inside(current) != is_inside(previous) {
+                    let factor =
+                        (offset - normal.dot(*previous)) / normal.dot(*current - *previous);
+                    clipped_polygon.push(previous.interpolate(*current, factor));
+                }
+                if is_inside(current) {
+                    clipped_polygon.push(*current);
+                }
+            }
+            clipped_polygon
+        })
 }
 
 impl Mosaic for StarryMosaic {
@@ -75,26 +1216,43 @@ impl Mosaic for StarryMosaic {
         Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
         Method: ColoringMethod<Color>,
     {
-        let maximum_cell_distances = self.calculate_maximum_cell_distances();
-        let mut mosaic_image = RgbImage::new(self.image_size.0, self.image_size.1);
-        let mut current_site = 0;
-        let mut current_site_position = Vector::default();
-        for (x, y, pixel) in mosaic_image.enumerate_pixels_mut() {
-            let position = Vector::new(x as f64, y as f64);
-            let site = self.find_closest_site(current_site, position);
-            if site == 0 || current_site != site {
-                current_site = site;
-                current_site_position = (&self.voronoi.sites()[current_site]).into();
-            }
-            let distance = position.distance_to(current_site_position);
-            let lightness = (1.0 - distance / maximum_cell_distances[current_site]).powi(2);
-            let color = coloring_method
-                .interpolate(position, current_site_position)
-                .lighten(lightness)
-                .into_color();
-            *pixel = Rgb(color.into_format().into_raw());
-        }
-        mosaic_image
+        self.draw_with_progress(coloring_method, |_| {})
+    }
+
+    fn draw_raw<Color, Method>(&self, coloring_method: Method) -> (u32, u32, Vec<u8>)
+    where
+        Color: IntoColor<LinSrgb<f64>> + Mix<Scalar = f64> + Shade<Scalar = f64> + Clone,
+        Method: ColoringMethod<Color>,
+    {
+        let (width, height) = self.image_size;
+        let mosaic_image = self
+            .draw_rows(
+                &coloring_method,
+                (0, 0),
+                self.image_size,
+                None,
+                None,
+                |color: Color, x, y| {
+                    let Rgb([red, green, blue]) = self.to_rgb8(color, x, y);
+                    Rgba([red, green, blue, u8::MAX])
+                },
+                |_| {},
+            )
+            .unwrap();
+        (width, height, mosaic_image.into_raw())
+    }
+
+    fn draw_heightmap(&self) -> GrayImage {
+        self.draw_rows(
+            &LinSrgb::new(0.0f64, 0.0, 0.0),
+            (0, 0),
+            self.image_size,
+            None,
+            None,
+            |color: LinSrgb<f64>, _, _| mosaic::lightened_black_to_height(color),
+            |_| {},
+        )
+        .unwrap()
     }
 
     fn image_size(&self) -> (u32, u32) {
@@ -108,11 +1266,760 @@ impl Mosaic for StarryMosaic {
     fn shape(&self) -> &Box<dyn MosaicShape> {
         &self.shape
     }
+
+    fn build_settings(&self) -> BuildSettings {
+        self.build_settings
+    }
+
+    fn contains(&self, point: Vector) -> bool {
+        let sites = self.voronoi.sites();
+        let hull: Vec<Vector> = self
+            .voronoi
+            .triangulation()
+            .hull
+            .iter()
+            .map(|site| (&sites[*site]).into())
+            .collect();
+        mosaic::convex_polygon_contains(&hull, point)
+    }
 }
 impl TryToTransform for StarryMosaic {
     fn try_to_transform(&self, transformation: &Transformation) -> Option<Self> {
         MosaicBuilder::from(self)
             .set_transformation(transformation)
             .build_star()
+            .map(|mut mosaic| {
+                mosaic.set_shading(self.shading);
+                mosaic.set_shading_exponent(self.shading_exponent);
+                mosaic.set_dithering(self.dithering);
+                mosaic.set_metric(self.metric);
+                mosaic
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::coloring_method::{FnColoring, RadialGradient},
+        *,
+    };
+
+    #[test]
+    fn voronoi() {
+        let builder = MosaicBuilder::default()
+            .set_image_size(400, 300)
+            .set_relative_center(0.5, 0.5)
+            .set_uniform_scale(0.4);
+        let mosaic = builder.clone().build_star().unwrap();
+        assert_eq!(mosaic.voronoi().sites().len(), builder.key_points().len());
+    }
+    #[test]
+    fn try_to_transform_with_build_settings() {
+        let builder = MosaicBuilder::default()
+            .set_image_size(400, 300)
+            .set_relative_center(0.5, 0.5)
+            .set_tileable(true)
+            .set_bounding_padding(0.1)
+            .set_epsilon(0.5)
+            .set_pixel_aspect(1.25)
+            .set_max_sites(40);
+        let mosaic = builder.clone().build_star().unwrap();
+        assert_eq!(mosaic.build_settings(), builder.build_settings());
+        let mut transformation = mosaic.transformation().clone();
+        transformation.rotation_angle += consts::FRAC_PI_6;
+        let transformed_mosaic = mosaic.try_to_transform(&transformation).unwrap();
+        assert_eq!(
+            transformed_mosaic.build_settings(),
+            builder.build_settings()
+        );
+        let expected_mosaic = builder
+            .set_transformation(&transformation)
+            .build_star()
+            .unwrap();
+        assert_eq!(transformed_mosaic.sites(), expected_mosaic.sites());
+    }
+    #[test]
+    fn cached_maximum_cell_distances() {
+        let mosaic = MosaicBuilder::default()
+            .set_polygonal_star_shape(7)
+            .build_star()
+            .unwrap();
+        let maximum_cell_distances =
+            StarryMosaic::calculate_maximum_cell_distances(&mosaic.voronoi);
+        assert_eq!(mosaic.maximum_cell_distances, maximum_cell_distances);
+        mosaic.draw(palette::LinSrgb::new(0.0f64, 0.0, 1.0));
+        assert_eq!(mosaic.maximum_cell_distances, maximum_cell_distances);
+    }
+    #[test]
+    fn from_voronoi() {
+        let voronoi = voronoice::VoronoiBuilder::default()
+            .set_bounding_box(voronoice::BoundingBox::new(
+                voronoice::Point { x: 50.0, y: 50.0 },
+                100.0,
+                100.0,
+            ))
+            .set_sites(vec![
+                voronoice::Point { x: 25.0, y: 25.0 },
+                voronoice::Point { x: 75.0, y: 25.0 },
+                voronoice::Point { x: 25.0, y: 75.0 },
+                voronoice::Point { x: 75.0, y: 75.0 },
+            ])
+            .build()
+            .unwrap();
+        let mosaic = StarryMosaic::from_voronoi(
+            voronoi,
+            (100, 100),
+            Transformation::default(),
+            Box::new(crate::mosaic_shape::Grid::new(2, 2)),
+        );
+        assert_eq!(mosaic.image_size(), (100, 100));
+        assert_eq!(mosaic.cells().count(), 4);
+        let mosaic_image = mosaic.draw(palette::LinSrgb::new(1.0f64, 0.5, 0.0));
+        assert_eq!(mosaic_image.dimensions(), (100, 100));
+        assert_eq!(
+            mosaic_image.get_pixel(25, 25),
+            mosaic_image.get_pixel(75, 75)
+        );
+        assert_ne!(
+            mosaic_image.get_pixel(25, 25),
+            mosaic_image.get_pixel(49, 25)
+        );
+    }
+    #[test]
+    fn site_map() {
+        let mosaic = MosaicBuilder::default()
+            .set_image_size(200, 100)
+            .set_relative_center(0.5, 0.5)
+            .build_star()
+            .unwrap();
+        let sites = mosaic.sites();
+        let site_map = mosaic.site_map();
+        assert_eq!(site_map.len(), 200 * 100);
+        assert!(site_map.iter().all(|site| *site < sites.len()));
+        let shared_sites_count = site_map
+            .chunks(200)
+            .flat_map(|row| row.windows(2))
+            .filter(|pair| pair[0] == pair[1])
+            .count();
+        assert!(shared_sites_count * 10 > 199 * 100 * 9);
+        let site_map_image = mosaic.draw_site_map();
+        assert_eq!(site_map_image.dimensions(), (200, 100));
+        assert_eq!(
+            site_map_image.get_pixel(120, 30)[0],
+            (site_map[30 * 200 + 120] % 256) as u8
+        );
+    }
+    #[test]
+    fn draw_by_area() {
+        let mosaic = MosaicBuilder::default()
+            .set_grid_shape(6, 6)
+            .set_image_size(400, 400)
+            .set_relative_center(0.5, 0.5)
+            .build_star()
+            .unwrap();
+        let mosaic_image = mosaic.draw_by_area(vec![
+            (0.0, LinSrgb::new(0.0f64, 0.0, 0.0)),
+            (1.0, LinSrgb::new(1.0f64, 1.0, 1.0)),
+        ]);
+        let sites = mosaic.sites();
+        let mut cell_values = vec![None; sites.len()];
+        for (site, pixel) in mosaic.site_map().iter().zip(mosaic_image.pixels()) {
+            assert_eq!(*cell_values[*site].get_or_insert(pixel[0]), pixel[0]);
+        }
+        let is_interior =
+            |site: &Vector| site.x > 1.0 && site.x < 399.0 && site.y > 1.0 && site.y < 399.0;
+        let (interior_values, edge_values): (Vec<_>, Vec<_>) = sites
+            .iter()
+            .zip(cell_values)
+            .partition(|(site, _)| is_interior(site));
+        let interior_values: Vec<u8> = interior_values
+            .into_iter()
+            .map(|(_, value)| value.unwrap())
+            .collect();
+        let minimum_interior_value = *interior_values.iter().min().unwrap();
+        assert!(*interior_values.iter().max().unwrap() - minimum_interior_value <= 10);
+        assert!(edge_values
+            .into_iter()
+            .all(|(_, value)| value.unwrap() + 40 < minimum_interior_value));
+    }
+    #[test]
+    fn find_pixel_site_with_metric() {
+        let mut mosaic = MosaicBuilder::default()
+            .set_image_size(200, 200)
+            .set_relative_center(0.5, 0.5)
+            .set_scattered_points_shape(60, 15.0, 7)
+            .build_star()
+            .unwrap();
+        for metric in [DistanceMetric::Manhattan, DistanceMetric::Chebyshev] {
+            mosaic.set_metric(metric);
+            let site_map = mosaic.site_map();
+            for (index, site) in site_map.into_iter().enumerate() {
+                let position = Vector::new((index % 200) as f64, (index / 200) as f64);
+                assert_eq!(site, mosaic.scan_closest_site(|_| 0.0, position));
+            }
+        }
+    }
+    #[test]
+    fn glow_ratio_with_metric() {
+        let mut mosaic = MosaicBuilder::default()
+            .set_image_size(200, 200)
+            .set_relative_center(0.5, 0.5)
+            .set_scattered_points_shape(60, 15.0, 7)
+            .build_star()
+            .unwrap();
+        let assert_glow_ratios = |mosaic: &StarryMosaic| {
+            let sites = mosaic.sites();
+            for (index, site) in mosaic.site_map().into_iter().enumerate() {
+                let position = Vector::new((index % 200) as f64, (index / 200) as f64);
+                let distance_ratio =
+                    position.distance_to(sites[site]) / mosaic.maximum_cell_distances[site];
+                assert!(distance_ratio <= 1.0);
+            }
+        };
+        mosaic.set_metric(DistanceMetric::Manhattan);
+        assert_glow_ratios(&mosaic);
+        mosaic.set_metric(DistanceMetric::Euclidean);
+        mosaic.set_site_weights(|site| if site.x < 100.0 { 150.0 } else { 0.0 });
+        mosaic.set_metric(DistanceMetric::Manhattan);
+        assert_glow_ratios(&mosaic);
+        mosaic.clear_site_weights();
+        assert_glow_ratios(&mosaic);
+        mosaic.set_metric(DistanceMetric::Euclidean);
+        assert_eq!(
+            mosaic.maximum_cell_distances,
+            StarryMosaic::calculate_maximum_cell_distances(&mosaic.voronoi)
+        );
+    }
+    #[test]
+    fn set_metric() {
+        let voronoi = voronoice::VoronoiBuilder::default()
+            .set_bounding_box(voronoice::BoundingBox::new(
+                voronoice::Point { x: 50.0, y: 100.0 },
+                100.0,
+                200.0,
+            ))
+            .set_sites(vec![
+                voronoice::Point { x: 30.0, y: 30.0 },
+                voronoice::Point { x: 70.0, y: 40.0 },
+                voronoice::Point { x: 50.0, y: 190.0 },
+            ])
+            .build()
+            .unwrap();
+        let mut mosaic = StarryMosaic::from_voronoi(
+            voronoi,
+            (100, 200),
+            Transformation::default(),
+            Box::new(crate::mosaic_shape::Grid::new(2, 2)),
+        );
+        assert_eq!(mosaic.metric(), DistanceMetric::Euclidean);
+        let find_boundaries = |mosaic: &StarryMosaic, rows: std::ops::Range<usize>| -> Vec<usize> {
+            let site_map = mosaic.site_map();
+            rows.map(|y| {
+                site_map[y * 100..(y + 1) * 100]
+                    .iter()
+                    .position(|site| *site != 0)
+                    .unwrap()
+            })
+            .collect()
+        };
+        let boundaries = find_boundaries(&mosaic, 45..70);
+        assert!(boundaries[0] > boundaries[boundaries.len() - 1]);
+        mosaic.set_metric(DistanceMetric::Manhattan);
+        assert_eq!(mosaic.metric(), DistanceMetric::Manhattan);
+        let boundaries = find_boundaries(&mosaic, 0..25);
+        assert!(boundaries.iter().all(|x| *x == boundaries[0]));
+        assert!((55..=56).contains(&boundaries[0]));
+        let boundaries = find_boundaries(&mosaic, 45..70);
+        assert!(boundaries.iter().all(|x| *x == boundaries[0]));
+        assert!((45..=46).contains(&boundaries[0]));
+        let mosaic_image = mosaic.draw(palette::LinSrgb::new(1.0f64, 0.5, 0.0));
+        assert_eq!(mosaic_image.dimensions(), (100, 200));
+    }
+    #[test]
+    fn draw_with_dithering() {
+        let mut mosaic = MosaicBuilder::default()
+            .set_image_size(200, 200)
+            .set_relative_center(0.5, 0.5)
+            .build_star()
+            .unwrap();
+        let color = palette::LinSrgb::new(1.0f64, 0.3, 0.0);
+        let image = mosaic.draw(color);
+        mosaic.set_dithering(true);
+        let dithered_image = mosaic.draw(color);
+        let differences: Vec<i32> = image
+            .pixels()
+            .zip(dithered_image.pixels())
+            .flat_map(|(pixel, dithered_pixel)| {
+                (0..3).map(|channel| pixel[channel] as i32 - dithered_pixel[channel] as i32)
+            })
+            .collect();
+        assert!(differences.iter().any(|difference| *difference != 0));
+        assert!(differences.iter().all(|difference| difference.abs() <= 1));
+        mosaic.set_shading(Shading::Flat);
+        let flat_color = palette::LinSrgb::new(1.0f64, 0.2, 0.0);
+        let dithered_image = mosaic.draw(flat_color);
+        mosaic.set_dithering(false);
+        assert_eq!(dithered_image, mosaic.draw(flat_color));
+    }
+    #[test]
+    fn draw_first_row_with_nearest_sites() {
+        struct SiteColoring;
+
+        impl ColoringMethod<LinSrgb<f64>> for SiteColoring {
+            fn interpolate(&self, _point: Vector, key_point: Vector) -> LinSrgb<f64> {
+                LinSrgb::new(key_point.x / 640.0, key_point.y / 640.0, 0.0)
+            }
+        }
+
+        let mut mosaic = MosaicBuilder::default()
+            .set_polygonal_star_shape(9)
+            .set_rotation_angle(0.4)
+            .set_uniform_scale(1.3)
+            .build_star()
+            .unwrap();
+        mosaic.set_shading(Shading::Flat);
+        let mosaic_image = mosaic.draw(SiteColoring);
+        let sites = mosaic.sites();
+        for x in 0..640 {
+            let position = Vector::new(x as f64, 0.0);
+            let nearest_distance = sites
+                .iter()
+                .map(|site| position.distance_to(*site))
+                .fold(f64::INFINITY, f64::min);
+            let pixel = *mosaic_image.get_pixel(x, 0);
+            assert!(sites
+                .iter()
+                .filter(|site| position.distance_to(**site) - nearest_distance < 1e-9)
+                .any(|site| {
+                    let color = SiteColoring.interpolate(position, *site);
+                    pixel == Rgb(color.into_format().into_raw())
+                }));
+        }
+    }
+    #[test]
+    fn draw_with_cell_indices() {
+        struct CellPalette([LinSrgb<f64>; 3]);
+
+        impl ColoringMethod<LinSrgb<f64>> for CellPalette {
+            fn interpolate(&self, _point: Vector, _key_point: Vector) -> LinSrgb<f64> {
+                unreachable!()
+            }
+            fn interpolate_indexed(
+                &self,
+                _point: Vector,
+                _key_point: Vector,
+                cell_index: usize,
+            ) -> LinSrgb<f64> {
+                self.0[cell_index % 3]
+            }
+        }
+
+        let palette = [
+            LinSrgb::new(1.0f64, 0.0, 0.0),
+            LinSrgb::new(0.0f64, 1.0, 0.0),
+            LinSrgb::new(0.0f64, 0.0, 1.0),
+        ];
+        let mut mosaic = MosaicBuilder::default().build_star().unwrap();
+        mosaic.set_shading(Shading::Flat);
+        let mosaic_image = mosaic.draw(CellPalette(palette));
+        let site_map = mosaic.site_map();
+        for (pixel, site) in mosaic_image.pixels().zip(site_map) {
+            assert_eq!(*pixel, Rgb(palette[site % 3].into_format().into_raw()));
+        }
+    }
+    #[test]
+    fn draw_raw() {
+        let mosaic = MosaicBuilder::default().build_star().unwrap();
+        let color = LinSrgb::new(1.0f64, 0.5, 0.0);
+        let mosaic_image = mosaic.draw(color);
+        let (width, height, bytes) = mosaic.draw_raw(color);
+        assert_eq!((width, height), mosaic.image_size());
+        assert_eq!(bytes.len(), (width * height * 4) as usize);
+        let Rgb([red, green, blue]) = *mosaic_image.get_pixel(0, 0);
+        assert_eq!(bytes[..4], [red, green, blue, u8::MAX]);
+        assert!(mosaic_image
+            .pixels()
+            .zip(bytes.chunks_exact(4))
+            .all(|(pixel, raw_pixel)| pixel.0 == raw_pixel[..3]));
+    }
+    #[test]
+    fn draw_with_uniform_cell_colors() {
+        struct PerPixel<Method>(Method);
+
+        impl<Method> ColoringMethod<LinSrgb<f64>> for PerPixel<Method>
+        where
+            Method: ColoringMethod<LinSrgb<f64>>,
+        {
+            fn interpolate(&self, point: Vector, key_point: Vector) -> LinSrgb<f64> {
+                self.0.interpolate(point, key_point)
+            }
+        }
+
+        let mosaic = MosaicBuilder::default().build_star().unwrap();
+        let gradient = vec![
+            (0.0, LinSrgb::new(1.0f64, 0.0, 0.0)),
+            (0.5, LinSrgb::new(0.0f64, 1.0, 0.0)),
+            (1.0, LinSrgb::new(0.0f64, 0.0, 1.0)),
+        ];
+        let step_gradient =
+            RadialGradient::new_simple_step(gradient, Vector::new(320.0, 320.0), 320.0);
+        assert!(step_gradient.is_uniform_per_cell());
+        assert!(!PerPixel(step_gradient.clone()).is_uniform_per_cell());
+        assert_eq!(
+            mosaic.draw(step_gradient.clone()),
+            mosaic.draw(PerPixel(step_gradient))
+        );
+    }
+    #[test]
+    fn draw_with_closure() {
+        let mosaic = MosaicBuilder::default().build_star().unwrap();
+        let color = LinSrgb::new(0.25f64, 0.5, 1.0);
+        let mosaic_image = mosaic.draw(FnColoring::new(|_point, _key_point| color));
+        assert_eq!(mosaic_image, mosaic.draw(color));
+    }
+    #[test]
+    fn draw_with_stats() {
+        let mosaic = MosaicBuilder::default().build_star().unwrap();
+        let color = LinSrgb::new(1.0f64, 0.5, 0.0);
+        let (mosaic_image, stats) = mosaic.draw_with_stats(color);
+        assert_eq!(mosaic_image, mosaic.draw(color));
+        let (width, height) = mosaic.image_size();
+        assert_eq!(stats.pixels_processed, (width * height) as u64);
+        assert_eq!(stats.site_count, mosaic.sites().len());
+        assert!(stats.average_path_steps >= 1.0);
+    }
+    #[test]
+    fn draw_rgba() {
+        let mosaic = MosaicBuilder::default().build_star().unwrap();
+        let gradient = palette::Gradient::new(vec![
+            LinSrgba::new(1.0f64, 0.0, 0.0, 0.5),
+            LinSrgba::new(0.0f64, 0.0, 1.0, 0.5),
+        ]);
+        let coloring_method = crate::coloring_method::RadialGradient::new_simple_smooth(
+            gradient,
+            mosaic.center(),
+            320.0,
+        );
+        let mosaic_image = mosaic.draw_rgba(coloring_method);
+        assert_eq!(mosaic_image.dimensions(), mosaic.image_size());
+        assert!(mosaic_image.pixels().all(|pixel| pixel[3] == 128));
+        let opaque_image = mosaic.draw_rgba(LinSrgb::new(1.0f64, 0.5, 0.0));
+        assert!(opaque_image.pixels().all(|pixel| pixel[3] == 255));
+        let rgb_image = mosaic.draw(LinSrgb::new(1.0f64, 0.5, 0.0));
+        assert!(opaque_image
+            .pixels()
+            .zip(rgb_image.pixels())
+            .all(|(rgba_pixel, rgb_pixel)| rgba_pixel.0[..3] == rgb_pixel.0));
+    }
+    #[test]
+    fn draw_wireframe() {
+        let mosaic = MosaicBuilder::default().build_star().unwrap();
+        let color = palette::LinSrgb::new(1.0f64, 1.0, 1.0);
+        let background = palette::LinSrgb::new(0.0f64, 0.0, 0.0);
+        let wireframe_image = mosaic.draw_wireframe(color, background, 1.0);
+        assert_eq!(wireframe_image.dimensions(), mosaic.image_size());
+        assert_eq!(*wireframe_image.get_pixel(320, 320), Rgb([0, 0, 0]));
+        let cell = &mosaic.voronoi.cells()[0];
+        let start: Vector = (&mosaic.voronoi.vertices()[cell[0]]).into();
+        let end: Vector = (&mosaic.voronoi.vertices()[cell[1]]).into();
+        let middle = start.interpolate(end, 0.5);
+        let (x, y) = (middle.x.round() as u32, middle.y.round() as u32);
+        assert_eq!(*wireframe_image.get_pixel(x, y), Rgb([255, 255, 255]));
+    }
+    #[test]
+    fn cells() {
+        let mosaic = MosaicBuilder::default()
+            .set_polygonal_star_shape(9)
+            .set_uniform_scale(1.5)
+            .build_star()
+            .unwrap();
+        assert_eq!(mosaic.cells().count(), mosaic.voronoi.sites().len());
+        for (site, polygon) in mosaic.cells() {
+            assert!(polygon.len() >= 3);
+            assert!(mosaic.voronoi.sites().contains(&site.into()));
+            for vertex in polygon {
+                assert!(vertex.x >= 0.0 && vertex.x <= 640.0);
+                assert!(vertex.y >= 0.0 && vertex.y <= 640.0);
+            }
+        }
+    }
+    #[test]
+    fn clip_polygon_to_bounds() {
+        let polygon = vec![
+            Vector::new(-10.0, 50.0),
+            Vector::new(50.0, -10.0),
+            Vector::new(110.0, 50.0),
+            Vector::new(50.0, 110.0),
+        ];
+        let clipped_polygon = clip_polygon(polygon, 100.0, 100.0);
+        assert_eq!(clipped_polygon.len(), 8);
+        assert!(clipped_polygon.contains(&Vector::new(0.0, 40.0)));
+        assert!(clipped_polygon.contains(&Vector::new(100.0, 60.0)));
+    }
+    #[test]
+    fn cells_sorted() {
+        let builder = MosaicBuilder::default()
+            .set_regular_polygon_shape(9)
+            .set_rotation_angle(0.3);
+        let first_mosaic = builder.clone().build_star().unwrap();
+        let second_mosaic = builder.build_star().unwrap();
+        let cells = first_mosaic.cells_sorted();
+        assert_eq!(cells.len(), first_mosaic.voronoi.sites().len());
+        assert_eq!(cells, second_mosaic.cells_sorted());
+        let sites = first_mosaic.voronoi.sites();
+        for pair in cells.windows(2) {
+            let left: Vector = (&sites[pair[0]]).into();
+            let right: Vector = (&sites[pair[1]]).into();
+            assert!(left <= right);
+        }
+    }
+    #[test]
+    fn draw_with_flat_shading() {
+        let mut mosaic = MosaicBuilder::default().build_star().unwrap();
+        assert_eq!(mosaic.shading(), Shading::Glow);
+        mosaic.set_shading(Shading::Flat);
+        let mosaic_image = mosaic.draw(palette::LinSrgb::new(0.0f64, 0.5, 1.0));
+        let first_pixel = *mosaic_image.get_pixel(0, 0);
+        assert!(mosaic_image.pixels().all(|pixel| *pixel == first_pixel));
+        mosaic.set_shading(Shading::Glow);
+        let mosaic_image = mosaic.draw(palette::LinSrgb::new(0.0f64, 0.5, 1.0));
+        assert!(mosaic_image.pixels().any(|pixel| *pixel != first_pixel));
+    }
+    #[test]
+    fn draw_with_shading_exponent() {
+        let mut mosaic = MosaicBuilder::default().build_star().unwrap();
+        assert_eq!(mosaic.shading_exponent(), 2.0);
+        let black = palette::LinSrgb::new(0.0f64, 0.0, 0.0);
+        let default_image = mosaic.draw(black);
+        mosaic.set_shading_exponent(1.0);
+        let linear_image = mosaic.draw(black);
+        let site =
+            mosaic.find_closest_site(&mut mosaic.site_search(), 0, Vector::new(320.0, 320.0));
+        let site_position: Vector = (&mosaic.voronoi.sites()[site]).into();
+        let maximum_distance = mosaic.maximum_cell_distances[site];
+        let (x, y) = (
+            site_position.x.round() as u32,
+            site_position.y.round() as u32,
+        );
+        for shift in 0..16 {
+            let position = Vector::new((x + shift) as f64, y as f64);
+            if mosaic.find_closest_site(&mut mosaic.site_search(), site, position) != site {
+                break;
+            }
+            let distance_ratio = position.distance_to(site_position) / maximum_distance;
+            let linear_value = linear_image.get_pixel(x + shift, y)[0] as f64;
+            assert!((linear_value - 255.0 * (1.0 - distance_ratio)).abs() <= 1.0);
+            let quadratic_value = default_image.get_pixel(x + shift, y)[0] as f64;
+            assert!((quadratic_value - 255.0 * (1.0 - distance_ratio).powi(2)).abs() <= 1.0);
+        }
+        let site_map = mosaic.site_map();
+        let sites = mosaic.sites();
+        for (x, y, pixel) in default_image.enumerate_pixels() {
+            let site = site_map[(y * default_image.width() + x) as usize];
+            let position = Vector::new(x as f64, y as f64);
+            let distance_ratio =
+                position.distance_to(sites[site]) / mosaic.maximum_cell_distances[site];
+            let expected_color = black.lighten((1.0 - distance_ratio).powi(2));
+            assert_eq!(*pixel, mosaic.to_rgb8(expected_color, x, y));
+        }
+    }
+    #[test]
+    fn contains() {
+        let mosaic = MosaicBuilder::default()
+            .set_regular_polygon_shape(5)
+            .set_image_size(400, 300)
+            .set_center(Vector::new(200.0, 150.0))
+            .build_star()
+            .unwrap();
+        assert!(mosaic.contains(Vector::new(200.0, 150.0)));
+        assert!(!mosaic.contains(Vector::new(0.0, 0.0)));
+        assert!(!mosaic.contains(Vector::new(399.0, 299.0)));
+    }
+    #[test]
+    fn adjacency() {
+        let mosaic = MosaicBuilder::default()
+            .set_grid_shape(4, 4)
+            .set_image_size(400, 400)
+            .set_center(Vector::new(200.0, 200.0))
+            .build_star()
+            .unwrap();
+        let adjacency = mosaic.adjacency();
+        let sites = mosaic.voronoi.sites();
+        assert_eq!(adjacency.len(), 25);
+        for (cell, neighbours) in adjacency.iter().enumerate() {
+            let site: Vector = (&sites[cell]).into();
+            let is_border_x = site.x < 1.0 || site.x > 399.0;
+            let is_border_y = site.y < 1.0 || site.y > 399.0;
+            let expected_count = match (is_border_x, is_border_y) {
+                (true, true) => 2,
+                (true, false) | (false, true) => 3,
+                (false, false) => 4,
+            };
+            assert_eq!(neighbours.len(), expected_count);
+            for neighbour in neighbours {
+                let neighbour_site: Vector = (&sites[*neighbour]).into();
+                assert!((site.distance_to(neighbour_site) - 100.0).abs() < 1e-6);
+            }
+        }
+    }
+    #[test]
+    fn color_cells_graph() {
+        let mosaic = MosaicBuilder::default()
+            .set_grid_shape(6, 6)
+            .build_star()
+            .unwrap();
+        let palette = [0xff0000, 0x00ff00, 0x0000ff, 0xffffff];
+        let color_indices = mosaic.color_cells_graph(&palette).unwrap();
+        for (cell, neighbours) in mosaic.adjacency().iter().enumerate() {
+            assert!(color_indices[cell] < palette.len());
+            for neighbour in neighbours {
+                assert_ne!(color_indices[cell], color_indices[*neighbour]);
+            }
+        }
+        assert!(mosaic.color_cells_graph(&palette[..1]).is_none());
+    }
+    #[test]
+    fn draw_heightmap() {
+        let mosaic = MosaicBuilder::default().build_star().unwrap();
+        let heightmap = mosaic.draw_heightmap();
+        let site =
+            mosaic.find_closest_site(&mut mosaic.site_search(), 0, Vector::new(320.0, 320.0));
+        let site_position: Vector = (&mosaic.voronoi.sites()[site]).into();
+        let (x, y) = (
+            site_position.x.round() as u32,
+            site_position.y.round() as u32,
+        );
+        assert!(heightmap.get_pixel(x, y)[0] >= 250);
+        let farthest_vertex = mosaic.voronoi.cells()[site]
+            .iter()
+            .map(|vertex| Vector::from(&mosaic.voronoi.vertices()[*vertex]))
+            .max_by(|left, right| {
+                left.distance_to(site_position)
+                    .total_cmp(&right.distance_to(site_position))
+            })
+            .unwrap();
+        let edge_position = farthest_vertex.interpolate(site_position, 0.02);
+        let (x, y) = (
+            edge_position.x.round() as u32,
+            edge_position.y.round() as u32,
+        );
+        assert!(heightmap.get_pixel(x, y)[0] <= 5);
+    }
+    #[test]
+    fn draw_heightmap_with_dithering() {
+        let mut mosaic = MosaicBuilder::default().build_star().unwrap();
+        let heightmap = mosaic.draw_heightmap();
+        mosaic.set_dithering(true);
+        assert_eq!(mosaic.draw_heightmap(), heightmap);
+        let site_map = mosaic.site_map();
+        for (x, y, height) in heightmap.enumerate_pixels().step_by(97) {
+            let site = site_map[(y * 640 + x) as usize];
+            let site_position: Vector = (&mosaic.voronoi.sites()[site]).into();
+            let distance = Vector::new(x as f64, y as f64).distance_to(site_position);
+            let lightness = (1.0 - distance / mosaic.maximum_cell_distances[site]).powi(2);
+            assert_eq!(height[0], (lightness * 255.0).round() as u8);
+        }
+    }
+    #[test]
+    fn draw_with_progress() {
+        let mosaic = MosaicBuilder::default()
+            .set_image_size(64, 48)
+            .set_relative_center(0.5, 0.5)
+            .set_uniform_scale(0.1)
+            .build_star()
+            .unwrap();
+        let mut progress = Vec::new();
+        let image = mosaic.draw_with_progress(LinSrgb::new(1.0f64, 0.5, 0.0), |fraction| {
+            progress.push(fraction)
+        });
+        assert_eq!(progress.len(), 48);
+        assert!(progress.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(*progress.last().unwrap(), 1.0);
+        assert_eq!(image, mosaic.draw(LinSrgb::new(1.0f64, 0.5, 0.0)));
+    }
+    #[test]
+    fn draw_cancellable() {
+        let mosaic = MosaicBuilder::default()
+            .set_image_size(64, 48)
+            .set_relative_center(0.5, 0.5)
+            .set_uniform_scale(0.1)
+            .build_star()
+            .unwrap();
+        let color = LinSrgb::new(0.0f64, 0.5, 1.0);
+        let cancelled = AtomicBool::new(true);
+        assert!(mosaic.draw_cancellable(color, &cancelled).is_none());
+        cancelled.store(false, atomic::Ordering::Relaxed);
+        assert_eq!(
+            mosaic.draw_cancellable(color, &cancelled),
+            Some(mosaic.draw(color))
+        );
+    }
+    #[test]
+    fn draw_region() {
+        let mosaic = MosaicBuilder::default()
+            .set_image_size(90, 70)
+            .set_relative_center(0.5, 0.5)
+            .set_uniform_scale(0.15)
+            .build_star()
+            .unwrap();
+        let color = LinSrgb::new(0.0f64, 0.5, 1.0);
+        let image = mosaic.draw(color);
+        let mut assembled_image = RgbImage::new(90, 70);
+        for (x, y, width, height) in [
+            (0, 0, 45, 35),
+            (45, 0, 45, 35),
+            (0, 35, 45, 35),
+            (45, 35, 45, 35),
+        ] {
+            let tile = mosaic.draw_region(color, x, y, width, height);
+            assert_eq!(tile.dimensions(), (width, height));
+            image::imageops::replace(&mut assembled_image, &tile, x as i64, y as i64);
+        }
+        assert_eq!(assembled_image, image);
+        let tile = mosaic.draw_region(color, 80, 60, 50, 50);
+        assert_eq!(tile.dimensions(), (10, 10));
+    }
+    #[test]
+    fn draw_with_site_weights() {
+        let mut mosaic = MosaicBuilder::default()
+            .set_image_size(100, 100)
+            .set_relative_center(0.5, 0.5)
+            .set_grid_shape(4, 4)
+            .set_uniform_scale(0.8)
+            .build_star()
+            .unwrap();
+        let color = LinSrgb::new(0.0f64, 0.5, 1.0);
+        let image = mosaic.draw(color);
+        mosaic.set_site_weights(|_| 100.0);
+        assert_eq!(mosaic.draw(color), image);
+        let center = Vector::new(50.0, 50.0);
+        let center_site = mosaic.find_closest_site(&mut mosaic.site_search(), 0, center);
+        let center_position: Vector = (&mosaic.voronoi.sites()[center_site]).into();
+        mosaic.set_site_weights(|site| if site == center_position { 400.0 } else { 0.0 });
+        let site_weights = mosaic.site_weights().unwrap().to_vec();
+        let (mut pixels_count, mut weighted_pixels_count) = (0, 0);
+        for (x, y) in (0..100).flat_map(|x| (0..100).map(move |y| (x, y))) {
+            let position = Vector::new(x as f64, y as f64);
+            if mosaic.find_closest_site(&mut mosaic.site_search(), center_site, position)
+                == center_site
+            {
+                pixels_count += 1;
+            }
+            let weighted_site = mosaic.find_closest_weighted_site(&site_weights, position);
+            if weighted_site == center_site {
+                weighted_pixels_count += 1;
+            }
+            let weighted_site_position: Vector = (&mosaic.voronoi.sites()[weighted_site]).into();
+            assert!(
+                position.distance_to(weighted_site_position)
+                    <= mosaic.maximum_cell_distances[weighted_site]
+            );
+        }
+        assert!(weighted_pixels_count > pixels_count);
+        assert_ne!(mosaic.draw(color), image);
+        mosaic.clear_site_weights();
+        assert!(mosaic.site_weights().is_none());
+        assert_eq!(mosaic.draw(color), image);
     }
 }
@@ -82,6 +82,16 @@ pub struct Transformation {
 
     /// Rotation angle in radians.
     pub rotation_angle: f64,
+
+    /// Whether shear is applied after rotation instead of before scale and rotation.
+    ///
+    /// By default (`false`) geometry is sheared first, then scaled, rotated and translated.
+    /// When set, geometry is scaled and rotated first, then sheared and translated.
+    ///
+    /// **_Note_**: this field was added in version 0.5.0, so struct literals of
+    /// [`Transformation`] written for earlier versions have to set it explicitly
+    /// or be completed with `..Default::default()`.
+    pub shear_after_rotation: bool,
 }
 
 impl Transformation {
@@ -107,9 +117,10 @@ impl Transformation {
     where
         VectorLike: Into<Vector>,
     {
-        let mut transformation = Transformation::default();
-        transformation.translation = translation.into();
-        transformation
+        Transformation {
+            translation: translation.into(),
+            ..Default::default()
+        }
     }
 
     /// Constructs transformation based on rotation.
@@ -132,9 +143,10 @@ impl Transformation {
     /// assert_eq!(transformation.rotation_angle, consts::FRAC_PI_6);
     /// ```
     pub fn from_rotation(rotation_angle: f64) -> Self {
-        let mut transformation = Transformation::default();
-        transformation.rotation_angle = rotation_angle;
-        transformation
+        Transformation {
+            rotation_angle,
+            ..Default::default()
+        }
     }
 
     /// Constructs transformation based on scale.
@@ -159,9 +171,10 @@ impl Transformation {
     where
         ScaleLike: Into<Scale>,
     {
-        let mut transformation = Transformation::default();
-        transformation.scale = scale.into();
-        transformation
+        Transformation {
+            scale: scale.into(),
+            ..Default::default()
+        }
     }
 
     /// Constructs transformation based on shear.
@@ -186,9 +199,10 @@ impl Transformation {
     where
         VectorLike: Into<Vector>,
     {
-        let mut transformation = Transformation::default();
-        transformation.shear = shear.into();
-        transformation
+        Transformation {
+            shear: shear.into(),
+            ..Default::default()
+        }
     }
 
     /// Attempts to perform 2D transformation with geometry.
@@ -232,6 +246,67 @@ impl Transformation {
     {
         transformable.transform(self)
     }
+
+    /// Composes current transformation with another one as affine maps.
+    ///
+    /// Applying composed transformation is equivalent to applying current transformation first,
+    /// then `transformation`. Unlike [`Add`], which just sums (or multiplies) corresponding
+    /// components of transformations, this method takes into account how second transformation
+    /// affects first one: e.g. translation of current transformation is rotated and scaled
+    /// by `transformation`.
+    ///
+    /// Composed transformation always shears geometry before scale and rotation (its
+    /// [`Transformation::shear_after_rotation`] is `false`) and has only horizontal shear.
+    ///
+    /// **_Note_**: if composition collapses geometry onto line or point (e.g. one of scales is
+    /// zero), resulting transformation is approximation of actual affine map.
+    ///
+    /// # Arguments
+    ///
+    /// * `transformation`: transformation which is applied after current one.
+    ///
+    /// returns: [`Transformation`] - composition of current transformation and `transformation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts;
+    ///
+    /// use starry_mosaic::{transform::Transformation, Vector};
+    ///
+    /// let translation = Transformation::from_translation(Vector::new(100.0, 0.0));
+    /// let rotation = Transformation::from_rotation(consts::FRAC_PI_2);
+    /// let transformation = translation.then(&rotation);
+    ///
+    /// assert_eq!(transformation.apply(&Vector::new(0.0, 0.0)), Vector::new(0.0, 100.0));
+    /// assert_eq!(
+    ///     (translation + rotation).apply(&Vector::new(0.0, 0.0)),
+    ///     Vector::new(100.0, 0.0)
+    /// );
+    /// ```
+    pub fn then(&self, transformation: &Self) -> Self {
+        let transform_direction = |direction: Vector| {
+            transformation.apply(&(self.apply(&direction) - self.translation))
+                - transformation.translation
+        };
+        let first_column = transform_direction(Vector::new(1.0, 0.0));
+        let second_column = transform_direction(Vector::new(0.0, 1.0));
+        let rotation_angle = first_column.y.atan2(first_column.x);
+        let horizontal_scale = first_column.length();
+        let second_column = second_column.rotate(-rotation_angle);
+        let horizontal_shear = if horizontal_scale > 0.0 {
+            second_column.x / horizontal_scale
+        } else {
+            0.0
+        };
+        Transformation {
+            translation: transformation.apply(&self.translation),
+            scale: Scale::new(horizontal_scale, second_column.y),
+            shear: Vector::new(horizontal_shear, 0.0),
+            rotation_angle,
+            shear_after_rotation: false,
+        }
+    }
 }
 
 impl PartialEq for Transformation {
@@ -240,6 +315,7 @@ impl PartialEq for Transformation {
             && utility::approx_eq(self.rotation_angle, transformation.rotation_angle)
             && self.scale == transformation.scale
             && self.shear == transformation.shear
+            && self.shear_after_rotation == transformation.shear_after_rotation
     }
 }
 
@@ -251,6 +327,7 @@ impl Add for Transformation {
             rotation_angle: self.rotation_angle + transformation.rotation_angle,
             scale: self.scale * transformation.scale,
             shear: self.shear + transformation.shear,
+            shear_after_rotation: self.shear_after_rotation,
         }
     }
 }
@@ -262,6 +339,7 @@ impl Sub for Transformation {
             rotation_angle: self.rotation_angle - transformation.rotation_angle,
             scale: self.scale / transformation.scale,
             shear: self.shear - transformation.shear,
+            shear_after_rotation: self.shear_after_rotation,
         }
     }
 }
@@ -274,6 +352,7 @@ impl Neg for Transformation {
             rotation_angle: -self.rotation_angle,
             scale: -self.scale,
             shear: -self.shear,
+            shear_after_rotation: self.shear_after_rotation,
         }
     }
 }
@@ -324,7 +403,7 @@ impl Scale {
     ///
     /// * `minimum_scale`: minimum scale along horizontal and vertical axes; should be positive.
     /// * `maximum_scale`: maximum scale along horizontal and vertical axes; should be greater than
-    /// `minimum_scale`.
+    ///   `minimum_scale`.
     ///
     /// returns: [`Scale`] - scale with absolute value restricted to given limits.
     ///
@@ -345,6 +424,38 @@ impl Scale {
             y: self.y.signum() * self.y.abs().clamp(minimum_scale, maximum_scale),
         }
     }
+
+    /// Checks whether scale is (approximately) equal along both axes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::transform::Scale;
+    ///
+    /// assert!(Scale::new_uniform(2.5).is_uniform());
+    /// assert!(!Scale::new(2.5, 1.0).is_uniform());
+    /// ```
+    #[inline(always)]
+    pub fn is_uniform(&self) -> bool {
+        utility::approx_eq(self.x, self.y)
+    }
+
+    /// Calculates aspect ratio of scale, i.e. ratio of horizontal scale to vertical one.
+    ///
+    /// returns: `f64` - aspect ratio of scale; it's infinite (or NaN) if vertical scale is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::transform::Scale;
+    ///
+    /// assert_eq!(Scale::new(3.0, 1.5).aspect(), 2.0);
+    /// assert_eq!(Scale::new_uniform(0.5).aspect(), 1.0);
+    /// ```
+    #[inline(always)]
+    pub fn aspect(&self) -> f64 {
+        self.x / self.y
+    }
 }
 
 impl Default for Scale {
@@ -421,6 +532,70 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn compose_rotation_and_translation() {
+        let first = Transformation {
+            translation: Vector::new(100.0, -50.0),
+            rotation_angle: consts::FRAC_PI_3,
+            ..Default::default()
+        };
+        let second = Transformation {
+            translation: Vector::new(-20.0, 30.0),
+            rotation_angle: -consts::FRAC_PI_4,
+            ..Default::default()
+        };
+        let composition = first.then(&second);
+        for point in [
+            Vector::new(0.0, 0.0),
+            Vector::new(25.0, -75.0),
+            Vector::new(-40.0, 10.0),
+        ] {
+            assert_eq!(
+                composition.apply(&point),
+                second.apply(&first.apply(&point))
+            );
+        }
+        assert_eq!(
+            composition,
+            Transformation {
+                translation: Vector::new(100.0, -50.0).rotate(-consts::FRAC_PI_4)
+                    + Vector::new(-20.0, 30.0),
+                rotation_angle: consts::FRAC_PI_3 - consts::FRAC_PI_4,
+                ..Default::default()
+            }
+        );
+    }
+    #[test]
+    fn compose_scale_and_shear() {
+        let first = Transformation {
+            translation: Vector::new(10.0, 20.0),
+            rotation_angle: consts::FRAC_PI_6,
+            scale: Scale::new(2.0, -0.5),
+            shear: Vector::new(0.5, -0.25),
+            ..Default::default()
+        };
+        let second = Transformation {
+            translation: Vector::new(-5.0, 15.0),
+            rotation_angle: 2.0,
+            scale: Scale::new(1.5, 3.0),
+            shear: Vector::new(-1.0, 0.75),
+            shear_after_rotation: true,
+        };
+        let composition = first.then(&second);
+        for point in [Vector::new(3.0, -7.0), Vector::new(-12.5, 4.0)] {
+            assert_eq!(
+                composition.apply(&point),
+                second.apply(&first.apply(&point))
+            );
+        }
+        assert!(!composition.shear_after_rotation);
+        assert_eq!(
+            Transformation::default()
+                .then(&first)
+                .apply(&Vector::new(1.0, 1.0)),
+            first.apply(&Vector::new(1.0, 1.0))
+        );
+    }
     #[test]
     fn add_transformation() {
         let first = Transformation {
@@ -428,12 +603,14 @@ mod tests {
             rotation_angle: consts::FRAC_PI_6,
             scale: Scale::new(0.5, 0.75),
             shear: Vector::new(0.5, -0.5),
+            ..Default::default()
         };
         let second = Transformation {
             translation: Vector::new(150.0, -50.0),
             rotation_angle: consts::FRAC_PI_3,
             scale: Scale::new(1.5, 2.0),
             shear: Vector::new(-0.25, 1.0),
+            ..Default::default()
         };
         let sum = first + second;
         assert_eq!(
@@ -442,7 +619,8 @@ mod tests {
                 translation: Vector::new(250.0, 50.0),
                 rotation_angle: consts::FRAC_PI_2,
                 scale: Scale::new(0.75, 1.5),
-                shear: Vector::new(0.25, 0.5)
+                shear: Vector::new(0.25, 0.5),
+                ..Default::default()
             }
         );
     }
@@ -453,12 +631,14 @@ mod tests {
             rotation_angle: consts::FRAC_PI_2,
             scale: Scale::new(1.5, 2.5),
             shear: Vector::new(1.0, 0.5),
+            ..Default::default()
         };
         let second = Transformation {
             translation: Vector::new(-150.0, 225.0),
             rotation_angle: consts::FRAC_PI_4,
             scale: Scale::new(2.0, 1.0),
             shear: Vector::new(0.5, 1.0),
+            ..Default::default()
         };
         let difference = first - second;
         assert_eq!(
@@ -467,7 +647,8 @@ mod tests {
                 translation: Vector::new(350.0, -350.0),
                 rotation_angle: consts::FRAC_PI_4,
                 scale: Scale::new(0.75, 2.5),
-                shear: Vector::new(0.5, -0.5)
+                shear: Vector::new(0.5, -0.5),
+                ..Default::default()
             }
         );
     }
@@ -478,6 +659,7 @@ mod tests {
             rotation_angle: -consts::FRAC_PI_2,
             scale: Scale::default(),
             shear: Vector::new(0.3, -0.6),
+            ..Default::default()
         };
         assert_eq!(
             -transformation,
@@ -485,7 +667,8 @@ mod tests {
                 translation: Vector::new(-75.0, 85.0),
                 rotation_angle: consts::FRAC_PI_2,
                 scale: Scale::new(-1.0, -1.0),
-                shear: Vector::new(-0.3, 0.6)
+                shear: Vector::new(-0.3, 0.6),
+                ..Default::default()
             }
         );
     }
@@ -496,12 +679,14 @@ mod tests {
             rotation_angle: consts::FRAC_PI_3,
             scale: Scale::new(1.5, 2.0),
             shear: Vector::new(-0.5, -0.5),
+            ..Default::default()
         };
         transformation += Transformation {
             translation: Vector::new(150.0, 0.0),
             rotation_angle: consts::FRAC_PI_6,
             scale: Scale::new(1.5, 2.0),
             shear: Vector::new(-0.75, 1.0),
+            ..Default::default()
         };
         assert_eq!(
             transformation,
@@ -509,7 +694,8 @@ mod tests {
                 translation: Vector::new(150.0, 200.0),
                 rotation_angle: consts::FRAC_PI_2,
                 scale: Scale::new(2.25, 4.0),
-                shear: Vector::new(-1.25, 0.5)
+                shear: Vector::new(-1.25, 0.5),
+                ..Default::default()
             }
         );
     }
@@ -520,12 +706,14 @@ mod tests {
             rotation_angle: consts::FRAC_PI_2,
             scale: Scale::new(2.5, 2.0),
             shear: Vector::new(0.3, 0.5),
+            ..Default::default()
         };
         transformation -= Transformation {
             translation: Vector::new(150.0, -225.0),
             rotation_angle: consts::FRAC_PI_4,
             scale: Scale::new(2.0, 2.0),
             shear: Vector::new(0.6, -0.5),
+            ..Default::default()
         };
         assert_eq!(
             transformation,
@@ -533,7 +721,8 @@ mod tests {
                 translation: Vector::new(-200.0, 300.0),
                 rotation_angle: consts::FRAC_PI_4,
                 scale: Scale::new(1.25, 1.0),
-                shear: Vector::new(-0.3, 1.0)
+                shear: Vector::new(-0.3, 1.0),
+                ..Default::default()
             }
         );
     }
@@ -545,6 +734,22 @@ mod tests {
         assert_eq!(clamped_scale.y, -1000.0);
     }
     #[test]
+    fn uniform_scale() {
+        let scale = Scale::new_uniform(-1.5);
+        assert!(scale.is_uniform());
+        assert_eq!(scale.aspect(), 1.0);
+        assert!(Scale::new(0.3, 0.1 + 0.2).is_uniform());
+    }
+    #[test]
+    fn non_uniform_scale() {
+        let scale = Scale::new(2.0, 0.5);
+        assert!(!scale.is_uniform());
+        assert_eq!(scale.aspect(), 4.0);
+        let scale = Scale::new(1.0, -1.0);
+        assert!(!scale.is_uniform());
+        assert_eq!(scale.aspect(), -1.0);
+    }
+    #[test]
     fn mul_scale() {
         let first = Scale::new(0.6, 3.0);
         let second = Scale::new(7.0, 0.5);
@@ -5,10 +5,59 @@ const ONE_OVER_EPSILON: f64 = 1.0 / EPSILON;
 
 #[inline(always)]
 pub fn approx_eq(left: f64, right: f64) -> bool {
-    left.approx_eq(right, (EPSILON, 4))
+    approx_eq_eps(left, right, EPSILON)
+}
+
+#[inline(always)]
+pub fn approx_eq_eps(left: f64, right: f64, epsilon: f64) -> bool {
+    left.approx_eq(right, (epsilon, 4))
 }
 
 #[inline(always)]
 pub fn round_to_epsilon(number: f64) -> f64 {
     (number * ONE_OVER_EPSILON).round() * EPSILON
 }
+
+#[inline(always)]
+pub fn quantize_to_epsilon(number: f64) -> i64 {
+    (number * ONE_OVER_EPSILON).round() as i64
+}
+
+#[inline(always)]
+pub fn quantize(number: f64, epsilon: f64) -> i64 {
+    (number / epsilon).round() as i64
+}
+
+#[inline(always)]
+pub fn calculate_lightness(distance_ratio: f64, exponent: f64) -> f64 {
+    (1.0 - distance_ratio).abs().powf(exponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approx_eq_with_custom_epsilon() {
+        assert!(!approx_eq(1.0, 1.0 + 1e-4));
+        assert!(approx_eq_eps(1.0, 1.0 + 1e-4, 1e-3));
+        assert!(!approx_eq_eps(1.0, 1.0 + 1e-2, 1e-3));
+        assert!(approx_eq_eps(1.0, 1.0, 0.0));
+    }
+    #[test]
+    fn calculate_lightness_with_linear_falloff() {
+        for step in 0..=10 {
+            let distance_ratio = step as f64 / 10.0;
+            let lightness = calculate_lightness(distance_ratio, 1.0);
+            assert!(approx_eq(lightness, 1.0 - distance_ratio));
+        }
+    }
+    #[test]
+    fn calculate_lightness_with_quadratic_falloff() {
+        for step in 0..=15 {
+            let distance_ratio = step as f64 / 10.0;
+            let lightness = calculate_lightness(distance_ratio, 2.0);
+            assert_eq!(lightness, (1.0 - distance_ratio).powi(2));
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use robust::Coord;
+#[cfg(feature = "rendering")]
 use voronoice::Point;
 
 use super::{
@@ -220,7 +221,38 @@ impl Vector {
     /// assert_eq!(interpolated_point, Vector::new(1.6, 1.4));
     /// ```
     pub fn interpolate(&self, vector: Self, factor: f64) -> Self {
-        let factor = factor.clamp(0.0, 1.0);
+        self.lerp_unclamped(vector, factor.clamp(0.0, 1.0))
+    }
+
+    /// Calculates linear interpolation (or extrapolation) between two vectors or points
+    /// without clamping interpolation factor.
+    ///
+    /// Factors outside of range from 0.0 to 1.0 extend line through both points beyond them.
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: point (or vector) with which current point (vector) is interpolated.
+    /// * `factor`: interpolation factor; 0.0 gives current point, 1.0 gives other point.
+    ///
+    /// returns: [`Vector`] - result of linear interpolation (extrapolation) between two points
+    /// or vectors.
+    ///
+    /// # See also
+    ///
+    /// * [`Vector::interpolate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::Vector;
+    ///
+    /// let start_point = Vector::new(-2.0, 3.0);
+    /// let end_point = Vector::new(7.0, -1.0);
+    /// let extrapolated_point = start_point.lerp_unclamped(end_point, 2.0);
+    ///
+    /// assert_eq!(extrapolated_point, Vector::new(16.0, -5.0));
+    /// ```
+    pub fn lerp_unclamped(&self, vector: Self, factor: f64) -> Self {
         Self {
             x: self.x + (vector.x - self.x) * factor,
             y: self.y + (vector.y - self.y) * factor,
@@ -361,6 +393,161 @@ impl Vector {
         }
     }
 
+    /// Converts vector to array of its coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::Vector;
+    ///
+    /// let vector = Vector::new(3.5, -1.0);
+    ///
+    /// assert_eq!(vector.to_array(), [3.5, -1.0]);
+    /// assert_eq!(Vector::from(vector.to_array()), vector);
+    /// ```
+    #[inline(always)]
+    pub fn to_array(&self) -> [f64; 2] {
+        [self.x, self.y]
+    }
+
+    /// Rounds coordinates of vector to nearest multiples of grid step.
+    ///
+    /// # Arguments
+    ///
+    /// * `step`: distance between lines of grid; should be positive.
+    ///
+    /// returns: [`Vector`] - vector snapped to nearest node of grid; vector isn't changed
+    /// if `step` isn't positive finite number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::Vector;
+    ///
+    /// let point = Vector::new(12.3, 47.8);
+    ///
+    /// assert_eq!(point.snap_to_grid(5.0), Vector::new(10.0, 50.0));
+    /// assert_eq!(point.snap_to_grid(0.0), point);
+    /// ```
+    pub fn snap_to_grid(&self, step: f64) -> Self {
+        if step <= 0.0 || !step.is_finite() {
+            return *self;
+        }
+        Self {
+            x: (self.x / step).round() * step,
+            y: (self.y / step).round() * step,
+        }
+    }
+
+    /// Calculates component-wise minimum of two vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: vector to compare with.
+    ///
+    /// returns: [`Vector`] - vector which coordinates are minima of corresponding coordinates
+    /// of both vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::Vector;
+    ///
+    /// let first = Vector::new(3.0, -2.0);
+    /// let second = Vector::new(-1.0, 5.0);
+    ///
+    /// assert_eq!(first.min(second), Vector::new(-1.0, -2.0));
+    /// ```
+    #[inline(always)]
+    pub fn min(&self, vector: Self) -> Self {
+        Self {
+            x: self.x.min(vector.x),
+            y: self.y.min(vector.y),
+        }
+    }
+
+    /// Calculates component-wise maximum of two vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: vector to compare with.
+    ///
+    /// returns: [`Vector`] - vector which coordinates are maxima of corresponding coordinates
+    /// of both vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::Vector;
+    ///
+    /// let first = Vector::new(3.0, -2.0);
+    /// let second = Vector::new(-1.0, 5.0);
+    ///
+    /// assert_eq!(first.max(second), Vector::new(3.0, 5.0));
+    /// ```
+    #[inline(always)]
+    pub fn max(&self, vector: Self) -> Self {
+        Self {
+            x: self.x.max(vector.x),
+            y: self.y.max(vector.y),
+        }
+    }
+
+    /// Smallest coordinate of vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::Vector;
+    ///
+    /// assert_eq!(Vector::new(3.0, -2.0).min_component(), -2.0);
+    /// ```
+    #[inline(always)]
+    pub fn min_component(&self) -> f64 {
+        self.x.min(self.y)
+    }
+
+    /// Largest coordinate of vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::Vector;
+    ///
+    /// assert_eq!(Vector::new(3.0, -2.0).max_component(), 3.0);
+    /// ```
+    #[inline(always)]
+    pub fn max_component(&self) -> f64 {
+        self.x.max(self.y)
+    }
+
+    /// Key of vector which coordinates are quantized (rounded) to epsilon
+    /// used by comparison of vectors.
+    ///
+    /// returns: [`VectorKey`] - hashable key of vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use starry_mosaic::Vector;
+    ///
+    /// let vector = Vector::new(5.0, 2.5);
+    /// let similar_vector = Vector::new(5.0 + f64::EPSILON * 4.0, 2.5 - f64::EPSILON * 2.0);
+    ///
+    /// assert_eq!(vector.key(), similar_vector.key());
+    /// assert_ne!(vector.key(), Vector::new(2.5, 5.0).key());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`VectorKey`].
+    ///
+    #[inline(always)]
+    pub fn key(&self) -> VectorKey {
+        VectorKey::from(*self)
+    }
+
+    #[cfg_attr(not(feature = "rendering"), allow(dead_code))]
     pub(crate) fn round_to_epsilon(&self) -> Self {
         Self {
             x: utility::round_to_epsilon(self.x),
@@ -369,6 +556,35 @@ impl Vector {
     }
 }
 
+/// Hashable key of [`Vector`] which coordinates are quantized (rounded) to epsilon.
+///
+/// Comparison of vectors is approximate and so isn't transitive, hence `Vector` implements
+/// neither [`Eq`] nor [`Hash`]. Keys are equal exactly when quantized coordinates are equal,
+/// so `VectorKey` can be used as key of [`HashSet`][`std::collections::HashSet`] or
+/// [`HashMap`][`std::collections::HashMap`] to find near duplicates of vectors.
+///
+/// **_Note_**: approximately equal vectors which coordinates lie on different sides
+/// of boundary of quantization have different keys.
+///
+/// # See also
+///
+/// * [`Vector::key`].
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VectorKey {
+    x: i64,
+    y: i64,
+}
+
+impl From<Vector> for VectorKey {
+    fn from(vector: Vector) -> Self {
+        Self {
+            x: utility::quantize_to_epsilon(vector.x),
+            y: utility::quantize_to_epsilon(vector.y),
+        }
+    }
+}
+
 impl Debug for Vector {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
         formatter.write_str("(")?;
@@ -396,6 +612,14 @@ impl From<(f64, f64)> for Vector {
         }
     }
 }
+impl From<[f64; 2]> for Vector {
+    fn from(array: [f64; 2]) -> Self {
+        Self {
+            x: array[0],
+            y: array[1],
+        }
+    }
+}
 impl From<Coord<f64>> for Vector {
     fn from(coord: Coord<f64>) -> Self {
         Self {
@@ -404,6 +628,7 @@ impl From<Coord<f64>> for Vector {
         }
     }
 }
+#[cfg(feature = "rendering")]
 impl From<&Point> for Vector {
     fn from(point: &Point) -> Self {
         Self {
@@ -412,6 +637,11 @@ impl From<&Point> for Vector {
         }
     }
 }
+impl From<Vector> for [f64; 2] {
+    fn from(vector: Vector) -> Self {
+        vector.to_array()
+    }
+}
 impl From<Vector> for Coord<f64> {
     fn from(vector: Vector) -> Self {
         Self {
@@ -420,6 +650,7 @@ impl From<Vector> for Coord<f64> {
         }
     }
 }
+#[cfg(feature = "rendering")]
 impl From<Vector> for Point {
     fn from(vector: Vector) -> Self {
         Self {
@@ -587,9 +818,17 @@ where
 
 impl Transform for Vector {
     fn transform(&self, transformation: &Transformation) -> Self {
-        (self.shear(transformation.shear.x, transformation.shear.y) * transformation.scale)
-            .rotate(transformation.rotation_angle)
-            + transformation.translation
+        let shear = transformation.shear;
+        if transformation.shear_after_rotation {
+            (*self * transformation.scale)
+                .rotate(transformation.rotation_angle)
+                .shear(shear.x, shear.y)
+                + transformation.translation
+        } else {
+            (self.shear(shear.x, shear.y) * transformation.scale)
+                .rotate(transformation.rotation_angle)
+                + transformation.translation
+        }
     }
 }
 
@@ -638,12 +877,34 @@ mod tests {
         assert_eq!(first.cross(second), 2.0);
     }
     #[test]
+    fn convert_to_array() {
+        let vector = Vector::new(-2.5, 7.25);
+        let array: [f64; 2] = vector.into();
+        assert_eq!(array, [-2.5, 7.25]);
+        assert_eq!(vector.to_array(), array);
+        assert_eq!(Vector::from(array), vector);
+        let points: Vec<Vector> = vec![[0.0, 1.0], [2.0, 3.0]]
+            .into_iter()
+            .map(Vector::from)
+            .collect();
+        assert_eq!(points, vec![Vector::new(0.0, 1.0), Vector::new(2.0, 3.0)]);
+    }
+    #[test]
     fn interpolate() {
         let first = Vector::new(5.0, 6.0);
         let second = Vector::new(1.0, -2.0);
         let interpolation = first.interpolate(second, 0.25);
         assert_eq!(interpolation.x, 4.0);
         assert_eq!(interpolation.y, 4.0);
+        assert_eq!(first.interpolate(second, 2.0), second);
+    }
+    #[test]
+    fn lerp_unclamped() {
+        let first = Vector::new(5.0, 6.0);
+        let second = Vector::new(1.0, -2.0);
+        assert_eq!(first.lerp_unclamped(second, 0.25), Vector::new(4.0, 4.0));
+        assert_eq!(first.lerp_unclamped(second, -1.0), Vector::new(9.0, 14.0));
+        assert_eq!(first.lerp_unclamped(second, 2.0), Vector::new(-3.0, -10.0));
     }
     #[test]
     fn translate() {
@@ -694,6 +955,38 @@ mod tests {
         assert_eq!(sheared_point, Vector::new(-4.0, 6.5));
     }
     #[test]
+    fn snap_to_grid() {
+        let vector = Vector::new(12.3, 47.8);
+        assert_eq!(vector.snap_to_grid(5.0), Vector::new(10.0, 50.0));
+        assert_eq!(vector.snap_to_grid(0.5), Vector::new(12.5, 48.0));
+        assert_eq!(
+            Vector::new(-7.4, 2.6).snap_to_grid(1.0),
+            Vector::new(-7.0, 3.0)
+        );
+        assert_eq!(vector.snap_to_grid(0.0), vector);
+        assert_eq!(vector.snap_to_grid(-5.0), vector);
+        assert_eq!(vector.snap_to_grid(f64::NAN), vector);
+    }
+    #[test]
+    fn min_max() {
+        let first = Vector::new(-4.5, 2.0);
+        let second = Vector::new(1.5, -3.0);
+        assert_eq!(first.min(second), Vector::new(-4.5, -3.0));
+        assert_eq!(first.max(second), Vector::new(1.5, 2.0));
+        assert_eq!(second.min(first), first.min(second));
+        assert_eq!(second.max(first), first.max(second));
+        assert_eq!(first.min(first), first);
+    }
+    #[test]
+    fn min_max_component() {
+        let vector = Vector::new(-4.5, 2.0);
+        assert_eq!(vector.min_component(), -4.5);
+        assert_eq!(vector.max_component(), 2.0);
+        let vector = Vector::new(0.5, -0.25);
+        assert_eq!(vector.min_component(), -0.25);
+        assert_eq!(vector.max_component(), 0.5);
+    }
+    #[test]
     fn round_to_epsilon() {
         let vector = Vector::new(5.0 - f64::EPSILON * 2.0, -2.0 + f64::EPSILON * 4.0);
         let rounded_vector = vector.round_to_epsilon();
@@ -701,6 +994,16 @@ mod tests {
         assert_eq!(rounded_vector.y, -2.0);
     }
     #[test]
+    fn hash_near_duplicates() {
+        let mut points = std::collections::HashSet::new();
+        points.insert(Vector::new(3.0, -7.5).key());
+        points.insert(Vector::new(3.0 + f64::EPSILON * 4.0, -7.5 - f64::EPSILON * 2.0).key());
+        points.insert(Vector::new(3.0 - 1e-12, -7.5 + 1e-12).key());
+        assert_eq!(points.len(), 1);
+        points.insert(Vector::new(3.0, 7.5).key());
+        assert_eq!(points.len(), 2);
+    }
+    #[test]
     fn add() {
         let first = Vector::new(4.0, 5.0);
         let second = Vector::new(2.0, 3.0);
@@ -816,6 +1119,7 @@ mod tests {
             rotation_angle: consts::FRAC_PI_4,
             scale: Scale::default(),
             shear: Vector::default(),
+            ..Default::default()
         };
         let vector = Vector::new(100.0, 0.0);
         let transformed_vector = vector.transform(&transformation);
@@ -831,6 +1135,7 @@ mod tests {
             rotation_angle: consts::FRAC_PI_4,
             scale: Scale::new(2.0, 3.0),
             shear: Vector::default(),
+            ..Default::default()
         };
         let vector = Vector::new(100.0, 50.0);
         let transformed_vector = vector.transform(&transformation);
@@ -846,6 +1151,7 @@ mod tests {
             rotation_angle: 0.0,
             scale: Scale::new(3.0, -2.0),
             shear: Vector::new(0.5, 1.0),
+            ..Default::default()
         };
         let vector = Vector::new(50.0, 200.0);
         let transformed_vector = vector.transform(&transformation);
@@ -858,9 +1164,32 @@ mod tests {
             rotation_angle: consts::FRAC_PI_2,
             scale: Scale::new(-1.5, 2.0),
             shear: Vector::new(0.25, 0.75),
+            ..Default::default()
         };
         let vector = Vector::new(100.0, 100.0);
         let transformed_vector = vector.transform(&transformation);
         assert_eq!(transformed_vector, Vector::new(-500.0, -137.5));
     }
+    #[test]
+    fn transform_shear_order() {
+        let mut transformation = Transformation {
+            translation: Vector::new(10.0, -20.0),
+            rotation_angle: consts::FRAC_PI_2,
+            scale: Scale::new(2.0, 2.0),
+            shear: Vector::new(1.0, 0.0),
+            ..Default::default()
+        };
+        let vector = Vector::new(50.0, 0.0);
+        let shear_before_rotation = vector.transform(&transformation);
+        assert_eq!(shear_before_rotation, Vector::new(10.0, 80.0));
+        assert_eq!(
+            shear_before_rotation,
+            (vector.shear(1.0, 0.0) * Scale::new(2.0, 2.0)).rotate(consts::FRAC_PI_2)
+                + Vector::new(10.0, -20.0)
+        );
+        transformation.shear_after_rotation = true;
+        let shear_after_rotation = vector.transform(&transformation);
+        assert_eq!(shear_after_rotation, Vector::new(110.0, 80.0));
+        assert_ne!(shear_before_rotation, shear_after_rotation);
+    }
 }
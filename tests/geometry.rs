@@ -0,0 +1,51 @@
+use std::f64::consts;
+
+use starry_mosaic::{
+    mosaic_shape::{MosaicShape, PolygonalStar, RegularPolygon},
+    transform::{Scale, Transform, Transformation},
+    Aabb, Segment, Vector,
+};
+
+#[test]
+fn vector_math() {
+    let vector = Vector::new(3.0, 4.0);
+    assert_eq!(vector.length(), 5.0);
+    assert_eq!(vector.dot(Vector::new(-4.0, 3.0)), 0.0);
+    assert_eq!(vector.cross(Vector::new(6.0, 8.0)), 0.0);
+    assert_eq!(vector.rotate(consts::FRAC_PI_2), Vector::new(-4.0, 3.0));
+    assert_eq!(vector * Scale::new(2.0, 0.5), Vector::new(6.0, 2.0));
+}
+
+#[test]
+fn segment_math() {
+    let first_segment = Segment::new(Vector::new(-2.0, 0.0), Vector::new(2.0, 0.0));
+    let second_segment = Segment::new(Vector::new(0.0, -2.0), Vector::new(0.0, 2.0));
+    assert_eq!(
+        first_segment.intersect(&second_segment),
+        Some(Vector::new(0.0, 0.0))
+    );
+    assert!(!first_segment.is_parallel_to(&second_segment));
+    assert_eq!(first_segment.distance_to(Vector::new(1.0, 3.0)), 3.0);
+}
+
+#[test]
+fn transformation() {
+    let transformation = Transformation {
+        translation: Vector::new(100.0, 50.0),
+        rotation_angle: consts::PI,
+        scale: Scale::new_uniform(2.0),
+        ..Default::default()
+    };
+    let point = Vector::new(10.0, 5.0).transform(&transformation);
+    assert_eq!(point, Vector::new(80.0, 40.0));
+}
+
+#[test]
+fn shape_geometry() {
+    let shape = PolygonalStar::new(5);
+    let points = shape.set_up_points(400, 400);
+    let segments = shape.connect_points(&points);
+    assert!(!shape.intersect_segments(&segments).is_empty());
+    let bounding_box = Aabb::from_points(&RegularPolygon::new(6).set_up_points(400, 400)).unwrap();
+    assert_eq!(bounding_box.center(), Vector::new(0.0, 0.0));
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "rendering")]
+
 use std::f64::consts;
 
 use palette::{IntoColor, LinSrgb, Mix, Shade};
@@ -7,44 +9,48 @@ use starry_mosaic::{
     coloring_method::*, mosaic_shape::*, transform::Scale, Mosaic, MosaicBuilder, Vector,
 };
 
-#[template]
-#[rstest]
-#[case(RegularPolygon::new(5), "regular_polygon_5")]
-#[case(RegularPolygon::new(6), "regular_polygon_6")]
-#[case(RegularPolygon::new(7), "regular_polygon_7")]
-#[case(RegularPolygon::new(8), "regular_polygon_8")]
-#[case(RegularPolygon::new(12), "regular_polygon_12")]
-#[case(RegularPolygon::new(13), "regular_polygon_13")]
-#[case(RegularPolygon::new(15), "regular_polygon_15")]
-#[case(RegularPolygon::new(16), "regular_polygon_16")]
-#[case(RegularPolygon::new(23), "regular_polygon_23")]
-#[case(RegularPolygon::new(24), "regular_polygon_24")]
-#[case(PolygonalStar::new(5), "polygonal_star_5")]
-#[case(PolygonalStar::new(6), "polygonal_star_6")]
-#[case(PolygonalStar::new(7), "polygonal_star_7")]
-#[case(PolygonalStar::new(8), "polygonal_star_8")]
-#[case(PolygonalStar::new(12), "polygonal_star_12")]
-#[case(PolygonalStar::new(13), "polygonal_star_13")]
-#[case(PolygonalStar::new(15), "polygonal_star_15")]
-#[case(PolygonalStar::new(16), "polygonal_star_16")]
-#[case(PolygonalStar::new(23), "polygonal_star_23")]
-#[case(PolygonalStar::new(24), "polygonal_star_24")]
-#[case(Grid::new(3, 3), "grid_3_3")]
-#[case(Grid::new(4, 4), "grid_4_4")]
-#[case(Grid::new(5, 5), "grid_5_5")]
-#[case(Grid::new(8, 8), "grid_8_8")]
-#[case(Grid::new(12, 12), "grid_12_12")]
-#[case(Grid::new(15, 15), "grid_15_15")]
-#[case(Grid::new(16, 16), "grid_16_16")]
-#[case(Grid::new(24, 24), "grid_24_24")]
-#[case(Grid::new(6, 9), "grid_6_9")]
-#[case(Grid::new(11, 7), "grid_11_7")]
-#[case(Grid::new(13, 23), "grid_13_23")]
-#[case(Grid::new(21, 14), "grid_21_14")]
-fn mosaic_creation_test<Shape>(#[case] shape: Shape, #[case] name: &str)
-where
-    Shape: 'static + MosaicShape,
-{
+mod mosaic_creation_cases {
+    use super::*;
+
+    #[template]
+    #[rstest]
+    #[case(RegularPolygon::new(5), "regular_polygon_5")]
+    #[case(RegularPolygon::new(6), "regular_polygon_6")]
+    #[case(RegularPolygon::new(7), "regular_polygon_7")]
+    #[case(RegularPolygon::new(8), "regular_polygon_8")]
+    #[case(RegularPolygon::new(12), "regular_polygon_12")]
+    #[case(RegularPolygon::new(13), "regular_polygon_13")]
+    #[case(RegularPolygon::new(15), "regular_polygon_15")]
+    #[case(RegularPolygon::new(16), "regular_polygon_16")]
+    #[case(RegularPolygon::new(23), "regular_polygon_23")]
+    #[case(RegularPolygon::new(24), "regular_polygon_24")]
+    #[case(PolygonalStar::new(5), "polygonal_star_5")]
+    #[case(PolygonalStar::new(6), "polygonal_star_6")]
+    #[case(PolygonalStar::new(7), "polygonal_star_7")]
+    #[case(PolygonalStar::new(8), "polygonal_star_8")]
+    #[case(PolygonalStar::new(12), "polygonal_star_12")]
+    #[case(PolygonalStar::new(13), "polygonal_star_13")]
+    #[case(PolygonalStar::new(15), "polygonal_star_15")]
+    #[case(PolygonalStar::new(16), "polygonal_star_16")]
+    #[case(PolygonalStar::new(23), "polygonal_star_23")]
+    #[case(PolygonalStar::new(24), "polygonal_star_24")]
+    #[case(Grid::new(3, 3), "grid_3_3")]
+    #[case(Grid::new(4, 4), "grid_4_4")]
+    #[case(Grid::new(5, 5), "grid_5_5")]
+    #[case(Grid::new(8, 8), "grid_8_8")]
+    #[case(Grid::new(12, 12), "grid_12_12")]
+    #[case(Grid::new(15, 15), "grid_15_15")]
+    #[case(Grid::new(16, 16), "grid_16_16")]
+    #[case(Grid::new(24, 24), "grid_24_24")]
+    #[case(Grid::new(6, 9), "grid_6_9")]
+    #[case(Grid::new(11, 7), "grid_11_7")]
+    #[case(Grid::new(13, 23), "grid_13_23")]
+    #[case(Grid::new(21, 14), "grid_21_14")]
+    fn mosaic_creation_test<Shape>(#[case] shape: Shape, #[case] name: &str)
+    where
+        Shape: 'static + MosaicShape,
+    {
+    }
 }
 
 fn create_gradient() -> Vec<(f64, LinSrgb<f64>)> {
@@ -94,7 +100,7 @@ fn create_conic_gradient() -> ConicGradient<LinSrgb<f64>> {
 }
 
 mod starry_mosaic_tests {
-    use super::*;
+    use super::{mosaic_creation_cases::mosaic_creation_test, *};
 
     fn create_starry_mosaic<Color, Method, Shape>(
         shape: Shape,
@@ -114,7 +120,7 @@ mod starry_mosaic_tests {
         let mosaic = MosaicBuilder::default()
             .set_shape(shape)
             .set_image_size(image_size.0, image_size.1)
-            .set_center(center.clone())
+            .set_center(center)
             .set_rotation_angle(rotation_angle)
             .set_uniform_scale(scale)
             .build_star();
@@ -164,9 +170,9 @@ mod starry_mosaic_tests {
     }
 }
 mod polygonal_mosaic_tests {
-    use super::*;
+    use super::{mosaic_creation_cases::mosaic_creation_test, *};
 
-    fn create_polygonal_mosaic<Color, Method, Shape: 'static>(
+    fn create_polygonal_mosaic<Color, Method, Shape>(
         shape: Shape,
         coloring_method: Method,
         group: &str,
@@ -184,7 +190,7 @@ mod polygonal_mosaic_tests {
         let mosaic = MosaicBuilder::default()
             .set_shape(shape)
             .set_image_size(image_size.0, image_size.1)
-            .set_center(center.clone())
+            .set_center(center)
             .set_rotation_angle(rotation_angle)
             .set_uniform_scale(scale)
             .build_polygon();